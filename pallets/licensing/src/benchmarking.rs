@@ -0,0 +1,104 @@
+//! Benchmarking setup for pallet-licensing
+
+use super::*;
+use frame::{ deps::frame_benchmarking::v2::*, prelude::* };
+use frame::deps::sp_core::H256;
+use frame::traits::{ fungible::{ Inspect, Mutate }, Saturating };
+use pallet_news::AnchorInspect;
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+    use crate::pallet::Pallet as Licensing;
+    use frame_system::RawOrigin;
+
+    /// Records a benchmark anchor published by a funded account and returns both.
+    fn setup_anchor<T: Config>() -> (T::AccountId, H256) {
+        let anchor = H256::repeat_byte(42);
+        let publisher: T::AccountId = account("publisher", 0, 0);
+        T::Currency::set_balance(&publisher, T::Currency::minimum_balance());
+        T::Articles::insert_anchor(anchor, publisher.clone());
+        (publisher, anchor)
+    }
+
+    /// The most territories an offer may list.
+    fn territories<T: Config>() -> BoundedVec<Territory, T::MaxTerritories> {
+        let territories = (0..T::MaxTerritories::get())
+            .map(|i| [b'A' + ((i / 26) % 26) as u8, b'A' + (i % 26) as u8])
+            .collect::<alloc::vec::Vec<_>>();
+        BoundedVec::try_from(territories).unwrap()
+    }
+
+    /// Fills the offers of `anchor` up to one below `MaxOffersPerAnchor`.
+    fn fill_offers<T: Config>(publisher: &T::AccountId, anchor: H256) {
+        for _ in 1..T::MaxOffersPerAnchor::get() {
+            Licensing::<T>
+                ::offer_license(
+                    RawOrigin::Signed(publisher.clone()).into(),
+                    anchor,
+                    0u32.into(),
+                    None,
+                    None,
+                    territories::<T>()
+                )
+                .unwrap();
+        }
+    }
+
+    #[benchmark]
+    fn offer_license() {
+        let (publisher, anchor) = setup_anchor::<T>();
+        fill_offers::<T>(&publisher, anchor);
+
+        #[extrinsic_call]
+        offer_license(
+            RawOrigin::Signed(publisher),
+            anchor,
+            T::Currency::minimum_balance(),
+            None,
+            Some(100u32.into()),
+            territories::<T>()
+        );
+
+        assert_eq!(Offers::<T>::decode_len(anchor), Some(T::MaxOffersPerAnchor::get() as usize));
+    }
+
+    #[benchmark]
+    fn withdraw_offer() {
+        let (publisher, anchor) = setup_anchor::<T>();
+        fill_offers::<T>(&publisher, anchor);
+        let offer_id = NextOfferId::<T>::get(anchor) - 1;
+
+        #[extrinsic_call]
+        withdraw_offer(RawOrigin::Signed(publisher), anchor, offer_id);
+
+        assert!(Offers::<T>::get(anchor).iter().all(|offer| offer.id != offer_id));
+    }
+
+    #[benchmark]
+    fn acquire_license() {
+        let (publisher, anchor) = setup_anchor::<T>();
+        fill_offers::<T>(&publisher, anchor);
+        let price = T::Currency::minimum_balance();
+        Licensing::<T>
+            ::offer_license(
+                RawOrigin::Signed(publisher).into(),
+                anchor,
+                price,
+                None,
+                Some(100u32.into()),
+                territories::<T>()
+            )
+            .unwrap();
+        let offer_id = NextOfferId::<T>::get(anchor) - 1;
+        let licensee: T::AccountId = whitelisted_caller();
+        T::Currency::set_balance(&licensee, price.saturating_mul(10u32.into()));
+
+        #[extrinsic_call]
+        acquire_license(RawOrigin::Signed(licensee.clone()), anchor, offer_id);
+
+        assert!(Licensing::<T>::has_license(&anchor, &licensee));
+    }
+
+    impl_benchmark_test_suite!(Licensing, crate::mock::new_test_ext(), crate::mock::Test);
+}
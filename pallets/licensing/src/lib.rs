@@ -0,0 +1,428 @@
+//! # Pallet Licensing
+//!
+//! Rights management for articles anchored in `pallet_news`: publishers offer licenses to reuse
+//! an article lineage, and licensees acquire them, paying the publisher in the native currency or
+//! in one of the `Assets`, such as a stablecoin.
+//!
+//! ## Offers
+//! The publisher of an anchor declares any number of offers, up to `MaxOffersPerAnchor`, each with
+//! - a `price`, transferred to the publisher on acquisition; zero for a free license,
+//! - an optional `asset` the price is paid in; `None` for the native `Currency`,
+//! - an optional `duration` in blocks, after which a license expires; `None` for perpetual,
+//! - the `territories` it covers, as ISO 3166-1 alpha-2 codes; empty for worldwide.
+//!
+//! Offers never change once declared. A withdrawn offer can no longer be acquired, while the
+//! licenses already granted under it stay valid.
+//!
+//! ## Storage
+//! - `Offers`: Anchor hash -> bounded list of open license offers
+//! - `NextOfferId`: Anchor hash -> id of the next offer declared for it
+//! - `Licenses`: (anchor hash, licensee) -> terms and expiry of the license granted to the licensee
+//!
+//! ## Calls
+//! - `offer_license`: Declare a license offer for one of the caller's anchors.
+//! - `withdraw_offer`: Stop offering a license. Only the publisher can withdraw.
+//! - `acquire_license`: Purchase, or accept if free, a license under an open offer.
+//!
+//! ## Events
+//! - LicenseOffered, OfferWithdrawn, LicenseAcquired
+//!
+//! ## Verification
+//! `has_license` (also exposed through `runtime_api::LicensingApi`) tells whether an account may
+//! reuse a lineage: its publisher always may, others while they hold an unexpired license.
+//! `has_license_in` also checks the license covers a territory. The pallet implements
+//! `pallet_news::SyndicationPolicy`, so that `record_syndication` can require a license.
+//!
+//! ## Removal
+//! The pallet implements `pallet_news::OnArticleRemoved`: once a lineage is removed, its offers
+//! and licenses are cleared along with the indices of `pallet_news`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use self::pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod runtime_api;
+pub mod weights;
+
+// FRAME test scaffolding
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[frame::pallet]
+pub mod pallet {
+    use crate::weights::WeightInfo;
+    use frame::{
+        prelude::*,
+        traits::{
+            fungible::{ Inspect, Mutate },
+            fungibles,
+            tokens::Preservation,
+            Saturating,
+            Zero,
+        },
+    };
+    use pallet_news::{ AnchorInspect, ContentHash, OnArticleRemoved, SyndicationPolicy };
+
+    /// Identifier of a license offer, unique per anchor.
+    pub type OfferId = u32;
+    /// ISO 3166-1 alpha-2 country code, in upper case.
+    pub type Territory = [u8; 2];
+    /// Balance of the currency licenses are paid in.
+    pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<
+        <T as frame_system::Config>::AccountId
+    >>::Balance;
+    /// Asset id of the configured `Assets`.
+    pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+        <T as frame_system::Config>::AccountId
+    >>::AssetId;
+
+    /// Terms under which the publisher of an anchor licenses its reuse.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct LicenseOffer<T: Config> {
+        /// Id of the offer.
+        pub id: OfferId,
+        /// Price paid to the publisher; zero for a free license.
+        pub price: BalanceOf<T>,
+        /// Asset the price is paid in; `None` for `Currency`.
+        pub asset: Option<AssetIdOf<T>>,
+        /// Blocks a license lasts from its acquisition; `None` for perpetual.
+        pub duration: Option<BlockNumberFor<T>>,
+        /// Territories the license covers; empty for worldwide.
+        pub territories: BoundedVec<Territory, T::MaxTerritories>,
+    }
+
+    /// A license granted to a licensee.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct License<T: Config> {
+        /// Offer the license was acquired under.
+        pub offer_id: OfferId,
+        /// Price paid for it.
+        pub price: BalanceOf<T>,
+        /// Asset the price was paid in; `None` for `Currency`.
+        pub asset: Option<AssetIdOf<T>>,
+        /// Territories it covers; empty for worldwide.
+        pub territories: BoundedVec<Territory, T::MaxTerritories>,
+        /// Block it was acquired at.
+        pub granted_at: BlockNumberFor<T>,
+        /// First block it is no longer valid at; `None` for perpetual.
+        pub expires_at: Option<BlockNumberFor<T>>,
+    }
+
+    impl<T: Config> License<T> {
+        /// Whether the license is valid at block `now`.
+        pub fn is_active_at(&self, now: BlockNumberFor<T>) -> bool {
+            self.expires_at.is_none_or(|expires_at| now < expires_at)
+        }
+
+        /// Whether the license covers `territory`.
+        pub fn covers(&self, territory: &Territory) -> bool {
+            self.territories.is_empty() || self.territories.contains(territory)
+        }
+    }
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overall runtime event type.
+        #[allow(deprecated)]
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Currency licenses are paid in by default.
+        type Currency: Mutate<Self::AccountId>;
+        /// Assets offers may be priced in instead, counted in the same unit as `Currency`.
+        type Assets: fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self>>;
+        /// Anchors and their publishers, usually `pallet_news`.
+        type Articles: AnchorInspect<Self::AccountId>;
+        /// Maximum number of open offers per anchor.
+        #[pallet::constant]
+        type MaxOffersPerAnchor: Get<u32>;
+        /// Maximum number of territories an offer may list.
+        #[pallet::constant]
+        type MaxTerritories: Get<u32>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Open license offers per anchor.
+    #[pallet::storage]
+    pub type Offers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // anchor hash
+        BoundedVec<LicenseOffer<T>, T::MaxOffersPerAnchor>,
+        ValueQuery
+    >;
+
+    /// Id of the next offer declared for an anchor.
+    #[pallet::storage]
+    pub type NextOfferId<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // anchor hash
+        OfferId,
+        ValueQuery
+    >;
+
+    /// Licenses granted per anchor and licensee. An expired license is replaced when the
+    /// licensee acquires a new one.
+    #[pallet::storage]
+    pub type Licenses<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // anchor hash
+        Blake2_128Concat,
+        T::AccountId, // licensee
+        License<T>,
+        OptionQuery
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The publisher of `anchor` declared a license offer.
+        LicenseOffered {
+            anchor: ContentHash,
+            offer_id: OfferId,
+            price: BalanceOf<T>,
+            asset: Option<AssetIdOf<T>>,
+            duration: Option<BlockNumberFor<T>>,
+        },
+        /// A license offer was withdrawn.
+        OfferWithdrawn {
+            anchor: ContentHash,
+            offer_id: OfferId,
+        },
+        /// `licensee` acquired a license to reuse `anchor`.
+        LicenseAcquired {
+            anchor: ContentHash,
+            licensee: T::AccountId,
+            offer_id: OfferId,
+            price: BalanceOf<T>,
+            asset: Option<AssetIdOf<T>>,
+            expires_at: Option<BlockNumberFor<T>>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// No article is anchored at this hash.
+        AnchorNotFound,
+        /// Only the publisher of the anchor can do this.
+        NotPublisher,
+        /// The anchor already has `MaxOffersPerAnchor` open offers.
+        TooManyOffers,
+        /// A license cannot last zero blocks.
+        ZeroDuration,
+        /// A territory is not an upper-case two-letter country code.
+        InvalidTerritory,
+        /// No open offer with this id.
+        UnknownOffer,
+        /// Publishers need no license for their own articles.
+        OwnArticle,
+        /// The caller already holds an unexpired license for this anchor.
+        AlreadyLicensed,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Offer licenses to reuse the caller's lineage `anchor` for `price` of `asset` (`None`:
+        /// `Currency`), lasting `duration` blocks (`None`: perpetual) in `territories` (empty:
+        /// worldwide).
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::offer_license())]
+        pub fn offer_license(
+            origin: OriginFor<T>,
+            anchor: ContentHash,
+            price: BalanceOf<T>,
+            asset: Option<AssetIdOf<T>>,
+            duration: Option<BlockNumberFor<T>>,
+            territories: BoundedVec<Territory, T::MaxTerritories>
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_publisher(&anchor, &who)?;
+            ensure!(duration.is_none_or(|duration| !duration.is_zero()), Error::<T>::ZeroDuration);
+            ensure!(
+                territories.iter().all(|territory| territory.iter().all(u8::is_ascii_uppercase)),
+                Error::<T>::InvalidTerritory
+            );
+
+            let offer_id = NextOfferId::<T>::get(&anchor);
+            Offers::<T>::try_mutate(&anchor, |offers| {
+                offers
+                    .try_push(LicenseOffer {
+                        id: offer_id,
+                        price,
+                        asset: asset.clone(),
+                        duration,
+                        territories,
+                    })
+                    .map_err(|_| Error::<T>::TooManyOffers)
+            })?;
+            NextOfferId::<T>::insert(&anchor, offer_id.saturating_add(1));
+
+            Self::deposit_event(Event::LicenseOffered { anchor, offer_id, price, asset, duration });
+
+            Ok(())
+        }
+
+        /// Withdraw offer `offer_id` of the caller's lineage `anchor`. Licenses already acquired
+        /// under it stay valid.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::withdraw_offer())]
+        pub fn withdraw_offer(
+            origin: OriginFor<T>,
+            anchor: ContentHash,
+            offer_id: OfferId
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_publisher(&anchor, &who)?;
+
+            Offers::<T>::try_mutate(&anchor, |offers| {
+                let index = offers
+                    .iter()
+                    .position(|offer| offer.id == offer_id)
+                    .ok_or(Error::<T>::UnknownOffer)?;
+                offers.remove(index);
+                Ok::<_, Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::OfferWithdrawn { anchor, offer_id });
+
+            Ok(())
+        }
+
+        /// Acquire a license to reuse `anchor` under its open offer `offer_id`, paying the
+        /// offer's price to the publisher in the offer's asset.
+        ///
+        /// - An expired license of the caller is replaced; an unexpired one must run out first.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::acquire_license())]
+        pub fn acquire_license(
+            origin: OriginFor<T>,
+            anchor: ContentHash,
+            offer_id: OfferId
+        ) -> DispatchResult {
+            let licensee = ensure_signed(origin)?;
+            let publisher = T::Articles::anchor_publisher(&anchor).ok_or(Error::<T>::AnchorNotFound)?;
+            ensure!(publisher != licensee, Error::<T>::OwnArticle);
+            let offer = Offers::<T>
+                ::get(&anchor)
+                .into_iter()
+                .find(|offer| offer.id == offer_id)
+                .ok_or(Error::<T>::UnknownOffer)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                !Licenses::<T>::get(&anchor, &licensee).is_some_and(|license| license.is_active_at(now)),
+                Error::<T>::AlreadyLicensed
+            );
+
+            if !offer.price.is_zero() {
+                match &offer.asset {
+                    Some(asset) => {
+                        <T::Assets as fungibles::Mutate<_>>::transfer(
+                            asset.clone(),
+                            &licensee,
+                            &publisher,
+                            offer.price,
+                            Preservation::Preserve
+                        )?;
+                    }
+                    None => {
+                        T::Currency::transfer(&licensee, &publisher, offer.price, Preservation::Preserve)?;
+                    }
+                }
+            }
+            let expires_at = offer.duration.map(|duration| now.saturating_add(duration));
+            Licenses::<T>::insert(&anchor, &licensee, License {
+                offer_id,
+                price: offer.price,
+                asset: offer.asset.clone(),
+                territories: offer.territories,
+                granted_at: now,
+                expires_at,
+            });
+
+            Self::deposit_event(Event::LicenseAcquired {
+                anchor,
+                licensee,
+                offer_id,
+                price: offer.price,
+                asset: offer.asset,
+                expires_at,
+            });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Whether `who` may reuse the lineage `anchor`: its publisher, or an account holding an
+        /// unexpired license.
+        pub fn has_license(anchor: &ContentHash, who: &T::AccountId) -> bool {
+            Self::license_holds(anchor, who, |_| true)
+        }
+
+        /// Whether `who` may reuse the lineage `anchor` in `territory`.
+        pub fn has_license_in(anchor: &ContentHash, who: &T::AccountId, territory: &Territory) -> bool {
+            Self::license_holds(anchor, who, |license| license.covers(territory))
+        }
+
+        fn license_holds(
+            anchor: &ContentHash,
+            who: &T::AccountId,
+            covers: impl FnOnce(&License<T>) -> bool
+        ) -> bool {
+            if T::Articles::anchor_publisher(anchor).as_ref() == Some(who) {
+                return true;
+            }
+            let now = <frame_system::Pallet<T>>::block_number();
+            Licenses::<T>
+                ::get(anchor, who)
+                .is_some_and(|license| license.is_active_at(now) && covers(&license))
+        }
+
+        /// Ensures `who` published the lineage `anchor`.
+        fn ensure_publisher(anchor: &ContentHash, who: &T::AccountId) -> DispatchResult {
+            let publisher = T::Articles::anchor_publisher(anchor).ok_or(Error::<T>::AnchorNotFound)?;
+            ensure!(&publisher == who, Error::<T>::NotPublisher);
+            Ok(())
+        }
+    }
+
+    /// Syndication needs a license, in any territory, unless the republisher is the publisher.
+    impl<T: Config> SyndicationPolicy<T::AccountId> for Pallet<T> {
+        fn can_syndicate(
+            original_anchor: &ContentHash,
+            original_publisher: &T::AccountId,
+            republisher: &T::AccountId
+        ) -> bool {
+            original_publisher == republisher || Self::has_license(original_anchor, republisher)
+        }
+    }
+
+    /// Offers of a removed lineage count as one entry, each license as another.
+    impl<T: Config> OnArticleRemoved for Pallet<T> {
+        fn on_article_removed(anchor: &ContentHash, limit: u32) -> u32 {
+            let mut cleared = 0;
+            if limit > 0 && NextOfferId::<T>::contains_key(anchor) {
+                Offers::<T>::remove(anchor);
+                NextOfferId::<T>::remove(anchor);
+                cleared += 1;
+            }
+            let mut licenses = Licenses::<T>::drain_prefix(anchor);
+            while cleared < limit && licenses.next().is_some() {
+                cleared += 1;
+            }
+            cleared
+        }
+    }
+}
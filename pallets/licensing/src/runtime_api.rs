@@ -0,0 +1,17 @@
+//! Runtime API for verifying article licenses.
+
+use crate::Territory;
+use codec::Codec;
+use pallet_news::ContentHash;
+
+frame::deps::sp_api::decl_runtime_apis! {
+    /// Read-only queries over `pallet_licensing` state.
+    pub trait LicensingApi<AccountId> where AccountId: Codec {
+        /// Whether `account` may reuse the lineage `anchor`: it is the publisher or holds an
+        /// unexpired license.
+        fn has_license(anchor: ContentHash, account: AccountId) -> bool;
+        /// Whether `account` may reuse the lineage `anchor` in `territory`, an ISO 3166-1 alpha-2
+        /// code.
+        fn has_license_in(anchor: ContentHash, account: AccountId, territory: Territory) -> bool;
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{ mock::*, Error, Licenses, NextOfferId, Offers, Territory };
+use frame::testing_prelude::*;
+use frame::deps::sp_core::H256;
+use frame::traits::{ fungible::{ Inspect, Mutate }, fungibles };
+use pallet_news::{ OnArticleRemoved, SyndicationPolicy };
+
+const PUBLISHER: u64 = 1;
+const LICENSEE: u64 = 2;
+
+fn anchor() -> H256 {
+    H256::repeat_byte(42)
+}
+
+/// Records `anchor()` as published by `PUBLISHER` and funds the licensee.
+fn setup() {
+    Anchors::set(vec![(anchor(), PUBLISHER)]);
+    <Balances as Mutate<u64>>::set_balance(&LICENSEE, 1_000);
+}
+
+fn territories(codes: &[&[u8; 2]]) -> BoundedVec<Territory, <Test as crate::Config>::MaxTerritories> {
+    BoundedVec::try_from(codes.iter().map(|code| **code).collect::<Vec<_>>()).unwrap()
+}
+
+fn offer(price: u64, duration: Option<u64>, codes: &[&[u8; 2]]) -> DispatchResult {
+    Licensing::offer_license(RuntimeOrigin::signed(PUBLISHER), anchor(), price, None, duration, territories(codes))
+}
+
+#[test]
+fn offers_are_declared_and_withdrawn_by_the_publisher() {
+    new_test_ext().execute_with(|| {
+        setup();
+
+        assert_noop!(
+            Licensing::offer_license(RuntimeOrigin::signed(LICENSEE), anchor(), 0, None, None, territories(&[])),
+            Error::<Test>::NotPublisher
+        );
+        assert_noop!(
+            Licensing::offer_license(RuntimeOrigin::signed(PUBLISHER), H256::repeat_byte(7), 0, None, None, territories(&[])),
+            Error::<Test>::AnchorNotFound
+        );
+        assert_noop!(offer(0, Some(0), &[]), Error::<Test>::ZeroDuration);
+        assert_noop!(offer(0, None, &[b"de"]), Error::<Test>::InvalidTerritory);
+
+        assert_ok!(offer(0, None, &[]));
+        System::assert_last_event(
+            crate::Event::LicenseOffered { anchor: anchor(), offer_id: 0, price: 0, asset: None, duration: None }.into()
+        );
+        assert_ok!(offer(100, Some(10), &[b"DE", b"AT"]));
+        assert_eq!(Offers::<Test>::get(anchor())[1].territories, territories(&[b"DE", b"AT"]));
+
+        // `MaxOffersPerAnchor` is 4
+        assert_ok!(offer(1, None, &[]));
+        assert_ok!(offer(2, None, &[]));
+        assert_noop!(offer(3, None, &[]), Error::<Test>::TooManyOffers);
+
+        assert_noop!(
+            Licensing::withdraw_offer(RuntimeOrigin::signed(LICENSEE), anchor(), 1),
+            Error::<Test>::NotPublisher
+        );
+        assert_ok!(Licensing::withdraw_offer(RuntimeOrigin::signed(PUBLISHER), anchor(), 1));
+        System::assert_last_event(crate::Event::OfferWithdrawn { anchor: anchor(), offer_id: 1 }.into());
+        assert_noop!(
+            Licensing::withdraw_offer(RuntimeOrigin::signed(PUBLISHER), anchor(), 1),
+            Error::<Test>::UnknownOffer
+        );
+
+        // Ids are never reused
+        assert_ok!(offer(3, None, &[]));
+        assert_eq!(NextOfferId::<Test>::get(anchor()), 5);
+        let ids: Vec<_> = Offers::<Test>::get(anchor()).iter().map(|offer| offer.id).collect();
+        assert_eq!(ids, vec![0, 2, 3, 4]);
+    });
+}
+
+#[test]
+fn free_licenses_are_accepted_and_paid_ones_purchased() {
+    new_test_ext().execute_with(|| {
+        setup();
+        assert_ok!(offer(0, None, &[]));
+        assert_ok!(offer(300, None, &[]));
+
+        assert!(!Licensing::has_license(&anchor(), &LICENSEE));
+        assert!(Licensing::has_license(&anchor(), &PUBLISHER));
+        assert_noop!(
+            Licensing::acquire_license(RuntimeOrigin::signed(PUBLISHER), anchor(), 0),
+            Error::<Test>::OwnArticle
+        );
+        assert_noop!(
+            Licensing::acquire_license(RuntimeOrigin::signed(LICENSEE), anchor(), 9),
+            Error::<Test>::UnknownOffer
+        );
+
+        assert_ok!(Licensing::acquire_license(RuntimeOrigin::signed(LICENSEE), anchor(), 0));
+        assert!(Licensing::has_license(&anchor(), &LICENSEE));
+        assert_eq!(Balances::balance(&LICENSEE), 1_000);
+        assert_noop!(
+            Licensing::acquire_license(RuntimeOrigin::signed(LICENSEE), anchor(), 1),
+            Error::<Test>::AlreadyLicensed
+        );
+
+        // A paid license moves the price to the publisher
+        let buyer = 3;
+        <Balances as Mutate<u64>>::set_balance(&buyer, 1_000);
+        assert_ok!(Licensing::acquire_license(RuntimeOrigin::signed(buyer), anchor(), 1));
+        System::assert_last_event(
+            crate::Event::LicenseAcquired {
+                anchor: anchor(),
+                licensee: buyer,
+                offer_id: 1,
+                price: 300,
+                asset: None,
+                expires_at: None,
+            }.into()
+        );
+        assert_eq!(Balances::balance(&buyer), 700);
+        assert_eq!(Balances::balance(&PUBLISHER), 300);
+        assert_eq!(Licenses::<Test>::get(anchor(), buyer).unwrap().price, 300);
+
+        // Unfunded licensees cannot pay
+        assert!(Licensing::acquire_license(RuntimeOrigin::signed(4), anchor(), 1).is_err());
+        assert!(!Licensing::has_license(&anchor(), &4));
+    });
+}
+
+#[test]
+fn licenses_priced_in_an_asset_are_paid_in_it() {
+    new_test_ext().execute_with(|| {
+        setup();
+        let usdc = 7;
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), usdc, PUBLISHER, true, 1));
+        assert_ok!(<Assets as fungibles::Mutate<u64>>::mint_into(usdc, &LICENSEE, 1_000));
+        assert_ok!(
+            Licensing::offer_license(RuntimeOrigin::signed(PUBLISHER), anchor(), 300, Some(usdc), None, territories(&[]))
+        );
+        System::assert_last_event(
+            crate::Event::LicenseOffered { anchor: anchor(), offer_id: 0, price: 300, asset: Some(usdc), duration: None }.into()
+        );
+
+        assert_ok!(Licensing::acquire_license(RuntimeOrigin::signed(LICENSEE), anchor(), 0));
+        System::assert_last_event(
+            crate::Event::LicenseAcquired {
+                anchor: anchor(),
+                licensee: LICENSEE,
+                offer_id: 0,
+                price: 300,
+                asset: Some(usdc),
+                expires_at: None,
+            }.into()
+        );
+        assert_eq!(Assets::balance(usdc, &LICENSEE), 700);
+        assert_eq!(Assets::balance(usdc, &PUBLISHER), 300);
+        assert_eq!(Balances::balance(&LICENSEE), 1_000);
+        assert_eq!(Licenses::<Test>::get(anchor(), LICENSEE).unwrap().asset, Some(usdc));
+
+        // Holding the native currency does not help without the asset
+        let buyer = 3;
+        <Balances as Mutate<u64>>::set_balance(&buyer, 1_000);
+        assert!(Licensing::acquire_license(RuntimeOrigin::signed(buyer), anchor(), 0).is_err());
+        assert!(!Licensing::has_license(&anchor(), &buyer));
+    });
+}
+
+#[test]
+fn licenses_expire_and_cover_their_territories() {
+    new_test_ext().execute_with(|| {
+        setup();
+        assert_ok!(offer(10, Some(5), &[b"DE", b"AT"]));
+        assert_ok!(Licensing::acquire_license(RuntimeOrigin::signed(LICENSEE), anchor(), 0));
+        assert_eq!(Licenses::<Test>::get(anchor(), LICENSEE).unwrap().expires_at, Some(6));
+
+        assert!(Licensing::has_license_in(&anchor(), &LICENSEE, b"DE"));
+        assert!(!Licensing::has_license_in(&anchor(), &LICENSEE, b"FR"));
+        assert!(Licensing::has_license_in(&anchor(), &PUBLISHER, b"FR"));
+
+        // A withdrawn offer keeps its licenses, until they expire
+        assert_ok!(Licensing::withdraw_offer(RuntimeOrigin::signed(PUBLISHER), anchor(), 0));
+        System::set_block_number(5);
+        assert!(Licensing::has_license(&anchor(), &LICENSEE));
+        System::set_block_number(6);
+        assert!(!Licensing::has_license(&anchor(), &LICENSEE));
+
+        // An expired license is replaced by a new one
+        assert_ok!(offer(10, Some(5), &[]));
+        assert_ok!(Licensing::acquire_license(RuntimeOrigin::signed(LICENSEE), anchor(), 1));
+        let license = Licenses::<Test>::get(anchor(), LICENSEE).unwrap();
+        assert_eq!((license.offer_id, license.expires_at), (1, Some(11)));
+        assert!(Licensing::has_license_in(&anchor(), &LICENSEE, b"FR"));
+        assert_eq!(Balances::balance(&LICENSEE), 980);
+    });
+}
+
+#[test]
+fn syndication_requires_a_license() {
+    new_test_ext().execute_with(|| {
+        setup();
+        assert!(Licensing::can_syndicate(&anchor(), &PUBLISHER, &PUBLISHER));
+        assert!(!Licensing::can_syndicate(&anchor(), &PUBLISHER, &LICENSEE));
+
+        assert_ok!(offer(0, None, &[b"DE"]));
+        assert_ok!(Licensing::acquire_license(RuntimeOrigin::signed(LICENSEE), anchor(), 0));
+        assert!(Licensing::can_syndicate(&anchor(), &PUBLISHER, &LICENSEE));
+    });
+}
+
+#[test]
+fn removed_lineages_lose_their_offers_and_licenses() {
+    new_test_ext().execute_with(|| {
+        setup();
+        assert_ok!(offer(0, None, &[]));
+        for licensee in 2..=4 {
+            assert_ok!(Licensing::acquire_license(RuntimeOrigin::signed(licensee), anchor(), 0));
+        }
+        Anchors::take();
+
+        // Offers count as one entry, then one per license
+        assert_eq!(Licensing::on_article_removed(&anchor(), 2), 2);
+        assert!(Offers::<Test>::get(anchor()).is_empty());
+        assert!(!NextOfferId::<Test>::contains_key(anchor()));
+        assert_eq!(Licenses::<Test>::iter_prefix(anchor()).count(), 2);
+
+        assert_eq!(Licensing::on_article_removed(&anchor(), 2), 2);
+        assert_eq!(Licensing::on_article_removed(&anchor(), 2), 0);
+        assert_eq!(Licenses::<Test>::iter_prefix(anchor()).count(), 0);
+    });
+}
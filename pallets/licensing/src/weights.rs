@@ -0,0 +1,146 @@
+//! Placeholder weights for `pallet_licensing`
+//!
+//! NOT BENCHMARKED: these are hand-written estimates laid out like benchmark CLI output, so that
+//! the pallet can be wired into a runtime. Regenerate this file before production use.
+
+// Command to generate this file:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// ./target/release/wbuild/educhain-runtime/educhain_runtime.compact.compressed.wasm
+// --pallet
+// pallet_licensing
+// --extrinsic
+//
+// --template
+// ../frame-weight-template.hbs
+// --output
+// weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame::{ deps::frame_support::weights::constants::RocksDbWeight, prelude::* };
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_licensing`.
+pub trait WeightInfo {
+    fn offer_license() -> Weight;
+    fn withdraw_offer() -> Weight;
+    fn acquire_license() -> Weight;
+}
+
+/// Weights for `pallet_licensing` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `Licensing::NextOfferId` (r:1 w:1)
+    /// Proof: `Licensing::NextOfferId` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Licensing::Offers` (r:1 w:1)
+    /// Proof: `Licensing::Offers` (`max_values`: None, `max_size`: Some(899), added: 3374, mode: `MaxEncodedLen`)
+    fn offer_license() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1108`
+        //  Estimated: `4364`
+        // Minimum execution time: 24_000_000 picoseconds.
+        Weight::from_parts(25_000_000, 4364)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `Licensing::Offers` (r:1 w:1)
+    /// Proof: `Licensing::Offers` (`max_values`: None, `max_size`: Some(899), added: 3374, mode: `MaxEncodedLen`)
+    fn withdraw_offer() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1271`
+        //  Estimated: `4364`
+        // Minimum execution time: 21_000_000 picoseconds.
+        Weight::from_parts(22_000_000, 4364)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `Licensing::Offers` (r:1 w:0)
+    /// Proof: `Licensing::Offers` (`max_values`: None, `max_size`: Some(899), added: 3374, mode: `MaxEncodedLen`)
+    /// Storage: `Licensing::Licenses` (r:1 w:1)
+    /// Proof: `Licensing::Licenses` (`max_values`: None, `max_size`: Some(155), added: 2630, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn acquire_license() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1474`
+        //  Estimated: `6196`
+        // Minimum execution time: 58_000_000 picoseconds.
+        Weight::from_parts(60_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `Licensing::NextOfferId` (r:1 w:1)
+    /// Proof: `Licensing::NextOfferId` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Licensing::Offers` (r:1 w:1)
+    /// Proof: `Licensing::Offers` (`max_values`: None, `max_size`: Some(899), added: 3374, mode: `MaxEncodedLen`)
+    fn offer_license() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1108`
+        //  Estimated: `4364`
+        // Minimum execution time: 24_000_000 picoseconds.
+        Weight::from_parts(25_000_000, 4364)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `Licensing::Offers` (r:1 w:1)
+    /// Proof: `Licensing::Offers` (`max_values`: None, `max_size`: Some(899), added: 3374, mode: `MaxEncodedLen`)
+    fn withdraw_offer() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1271`
+        //  Estimated: `4364`
+        // Minimum execution time: 21_000_000 picoseconds.
+        Weight::from_parts(22_000_000, 4364)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `Licensing::Offers` (r:1 w:0)
+    /// Proof: `Licensing::Offers` (`max_values`: None, `max_size`: Some(899), added: 3374, mode: `MaxEncodedLen`)
+    /// Storage: `Licensing::Licenses` (r:1 w:1)
+    /// Proof: `Licensing::Licenses` (`max_values`: None, `max_size`: Some(155), added: 2630, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn acquire_license() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1474`
+        //  Estimated: `6196`
+        // Minimum execution time: 58_000_000 picoseconds.
+        Weight::from_parts(60_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+}
@@ -0,0 +1,79 @@
+//! Merkle trees over batch leaves anchored with `anchor_batch`.
+//!
+//! Leaves are hashed as `blake2_256(0x00 ++ SCALE(BatchLeaf))` and inner nodes as
+//! `blake2_256(0x01 ++ min(left, right) ++ max(left, right))`. Sorting each pair means a proof is
+//! just the list of sibling hashes, without left/right positions; the prefixes keep a leaf from
+//! being passed off as an inner node.
+
+use crate::pallet::*;
+use alloc::{ vec, vec::Vec };
+use frame::deps::sp_io::hashing::blake2_256;
+use frame::prelude::*;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+impl<AccountId: Encode> BatchLeaf<AccountId> {
+    /// Hash of the leaf in a batch Merkle tree.
+    pub fn hash(&self) -> H256 {
+        let mut data = vec![LEAF_PREFIX];
+        data.extend(self.encode());
+        H256(blake2_256(&data))
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Whether `leaf` is part of the batch its publisher anchored as `merkle_root`, given the
+    /// sibling hashes from the leaf up to the root.
+    pub fn verify_inclusion(merkle_root: H256, leaf: &BatchLeafOf<T>, proof: &[H256]) -> bool {
+        Batches::<T>::contains_key(&leaf.publisher, merkle_root) &&
+            root_from_proof(leaf.hash(), proof) == merkle_root
+    }
+}
+
+/// Root of the batch Merkle tree over `leaves` (leaf hashes, in any order). A node without a
+/// sibling is carried up unchanged. `H256::zero()` for an empty batch.
+pub fn batch_merkle_root(leaves: &[H256]) -> H256 {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.first().copied().unwrap_or_default()
+}
+
+/// Proof for the leaf at `index` of the tree built by `batch_merkle_root`.
+pub fn batch_merkle_proof(leaves: &[H256], mut index: usize) -> Vec<H256> {
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    proof
+}
+
+/// Root reached by hashing `leaf` with each sibling in `proof`.
+pub(crate) fn root_from_proof(leaf: H256, proof: &[H256]) -> H256 {
+    proof.iter().fold(leaf, |node, sibling| hash_node(node, *sibling))
+}
+
+fn next_level(level: &[H256]) -> Vec<H256> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => hash_node(*left, *right),
+            _ => pair[0],
+        })
+        .collect()
+}
+
+fn hash_node(a: H256, b: H256) -> H256 {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = vec![NODE_PREFIX];
+    data.extend_from_slice(low.as_bytes());
+    data.extend_from_slice(high.as_bytes());
+    H256(blake2_256(&data))
+}
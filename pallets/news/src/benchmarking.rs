@@ -2,33 +2,44 @@
 
 use super::*;
 use frame::{ deps::frame_benchmarking::v2::*, prelude::* };
-use frame::deps::sp_core::H256;
+use frame::deps::sp_core::{ H160, H256 };
 use frame::deps::sp_keyring::Sr25519Keyring;
-use frame::deps::sp_runtime::{ MultiSignature, MultiSigner, traits::IdentifyAccount, AccountId32 };
-use frame::deps::sp_io::crypto::{ sr25519_generate, sr25519_sign };
+use frame::deps::sp_runtime::{
+    MultiSignature,
+    MultiSigner,
+    traits::{ IdentifyAccount, ValidateUnsigned },
+    transaction_validity::TransactionSource,
+    AccountId32,
+    Perbill,
+};
+use frame::deps::frame_support::traits::UnfilteredDispatchable;
+use frame::deps::frame_system::offchain::{ AppCrypto, SignedPayload, SigningTypes };
+use frame::deps::sp_runtime::RuntimeAppPublic;
+use frame::deps::sp_io::crypto::{ ecdsa_generate, ecdsa_sign_prehashed, sr25519_generate, sr25519_sign };
+use frame::traits::fungible::{ Inspect, Mutate };
+use alloc::{ format, vec, vec::Vec };
 
 /// Benchmark helper trait for signature creation
 pub trait BenchmarkHelper<Signature, AccountId> {
-    fn create_signature(content_hash: &H256) -> (Signature, AccountId);
+    /// Signs `message` with the key of benchmark publisher `index`, the same key on every call.
+    fn create_signature(index: u32, message: &[u8]) -> (Signature, AccountId);
 }
 
 impl BenchmarkHelper<MultiSignature, AccountId32> for () {
-    fn create_signature(content_hash: &H256) -> (MultiSignature, AccountId32) {
-        // Wrap content_hash in <Bytes></Bytes> tags for signature verification
-        let mut wrapped_msg = b"<Bytes>".to_vec();
-        wrapped_msg.extend_from_slice(content_hash.as_bytes());
-        wrapped_msg.extend_from_slice(b"</Bytes>");
-        
-        let public = sr25519_generate(0.into(), None);
+    fn create_signature(index: u32, message: &[u8]) -> (MultiSignature, AccountId32) {
+        let seed = format!("//Publisher{}", index).into_bytes();
+        let public = sr25519_generate(0.into(), Some(seed));
         let who_account: AccountId32 = MultiSigner::Sr25519(public).into_account().into();
-        let signature = MultiSignature::Sr25519(sr25519_sign(0.into(), &public, &wrapped_msg).unwrap());
+        let signature = MultiSignature::Sr25519(sr25519_sign(0.into(), &public, message).unwrap());
         (signature, who_account)
     }
 }
 
+type ReporterCrypto<T> = <T as Config>::IntegrityAuthorityId;
+type ReporterSignature<T> = <T as SigningTypes>::Signature;
+
 #[benchmarks(where
     T: Config + Send + Sync,
-    T::AccountId: From<AccountId32>,
 )]
 mod benchmarks {
     use super::*;
@@ -47,9 +58,8 @@ mod benchmarks {
             ::try_from(b"https://benchmark.com".to_vec())
             .unwrap();
 
-        let (signature, signer_account32) = T::BenchmarkHelper::create_signature(&content_hash);
-        let caller: T::AccountId = signer_account32.into();
         let word_count = 123u32;
+        let (signature, caller) = sign_article::<T>(0, content_hash, None, &title, &canonical_url, word_count);
 
         #[extrinsic_call]
         record_article(
@@ -61,7 +71,8 @@ mod benchmarks {
             canonical_url.clone(),
             signature,
             HashAlgo::Blake2b256,
-            word_count
+            word_count,
+            None
         );
         
         assert!(ArticleByHash::<T>::get(content_hash).is_some());
@@ -80,11 +91,17 @@ mod benchmarks {
             ::try_from(b"https://benchmark.com".to_vec())
             .unwrap();
 
-        let (signature, signer_account32) = T::BenchmarkHelper::create_signature(&content_hash);
-        let (new_signature, _) = T::BenchmarkHelper::create_signature(&new_hash);
-        let caller: T::AccountId = signer_account32.into();
         let word_count = 123u32;
         let new_word_count = 456u32;
+        let (signature, caller) = sign_article::<T>(0, content_hash, None, &title, &canonical_url, word_count);
+        let (new_signature, _) = sign_article::<T>(
+            0,
+            new_hash,
+            Some(content_hash),
+            &title,
+            &canonical_url,
+            new_word_count
+        );
 
         // First, record the initial article
         let _ = News::<T>::record_article(
@@ -96,8 +113,19 @@ mod benchmarks {
             canonical_url.clone(),
             signature,
             HashAlgo::Blake2b256,
-            word_count
+            word_count,
+            None
         );
+        // A full history page ending with the updated version, archived by the update
+        let mut history = HistoryByAnchor::<T>::get(content_hash);
+        for i in 1..T::MaxHistoryLen::get() {
+            history.try_insert(0, H256::from_low_u64_be(i.into())).unwrap();
+        }
+        HistoryByAnchor::<T>::insert(content_hash, history);
+
+        let summary = BoundedVec::try_from(
+            vec![b'x'; T::MaxChangeSummaryLen::get() as usize]
+        ).unwrap();
 
         // Now, benchmark the update
         #[extrinsic_call]
@@ -109,10 +137,802 @@ mod benchmarks {
             canonical_url.clone(),
             new_signature,
             HashAlgo::Blake2b256,
-            new_word_count
+            new_word_count,
+            ChangeKind::Correction,
+            Some(ChangeSummary::Text(summary))
         );
 
         assert!(ArticleByHash::<T>::get(new_hash).is_some());
+        assert!(CorrectionsOf::<T>::contains_key(content_hash, new_hash));
+        assert_eq!(HistoryPageCount::<T>::get(content_hash), 1);
+    }
+
+    /// Signs a version with collection `1`, item `2` and `HashAlgo::Blake2b256` as benchmark
+    /// publisher `index`.
+    fn sign_article<T: Config>(
+        index: u32,
+        content_hash: H256,
+        previous: Option<H256>,
+        title: &[u8],
+        canonical_url: &[u8],
+        word_count: u32
+    ) -> (PublisherSignatureOf<T>, T::AccountId) {
+        let payload = News::<T>::article_payload(
+            content_hash,
+            previous,
+            1u128,
+            2u128,
+            title,
+            canonical_url,
+            HashAlgo::Blake2b256,
+            word_count
+        );
+        let (signature, publisher) = T::BenchmarkHelper::create_signature(
+            index,
+            &payload.signing_message()
+        );
+        (signature.into(), publisher)
+    }
+
+    /// Records a benchmark article and returns its publisher and content hash.
+    fn setup_article<T: Config>() -> (T::AccountId, H256) {
+        let content_hash = H256::repeat_byte(42);
+        let title = BoundedVec::<u8, T::MaxTitleLen>
+            ::try_from(b"Benchmark Title".to_vec())
+            .unwrap();
+        let canonical_url = BoundedVec::<u8, T::MaxUrlLen>
+            ::try_from(b"https://benchmark.com".to_vec())
+            .unwrap();
+        let (signature, publisher) = sign_article::<T>(0, content_hash, None, &title, &canonical_url, 123u32);
+
+        News::<T>
+            ::record_article(
+                RawOrigin::Signed(publisher.clone()).into(),
+                content_hash,
+                1u128,
+                2u128,
+                title,
+                canonical_url,
+                signature,
+                HashAlgo::Blake2b256,
+                123u32,
+                None
+            )
+            .unwrap();
+
+        (publisher, content_hash)
+    }
+
+    /// Funds `who` well above every deposit the pallet may hold.
+    fn fund<T: Config>(who: &T::AccountId) {
+        let amount = T::Currency::minimum_balance()
+            .saturating_add(T::EndorsementDeposit::get())
+            .saturating_add(T::DraftDeposit::get())
+            .saturating_mul(100u32.into());
+        T::Currency::set_balance(who, amount);
+    }
+
+    /// Returns a funded account that is not the benchmark publisher.
+    fn funded_reader<T: Config>() -> T::AccountId {
+        let reader: T::AccountId = account("reader", 0, 0);
+        fund::<T>(&reader);
+        reader
+    }
+
+    #[benchmark]
+    fn endorse_article() {
+        let (_, content_hash) = setup_article::<T>();
+        let reader = funded_reader::<T>();
+
+        #[extrinsic_call]
+        endorse_article(RawOrigin::Signed(reader.clone()), content_hash, Sentiment::Endorse);
+
+        assert!(Endorsements::<T>::contains_key(content_hash, reader));
+    }
+
+    #[benchmark]
+    fn withdraw_endorsement() {
+        let (_, content_hash) = setup_article::<T>();
+        let reader = funded_reader::<T>();
+        News::<T>
+            ::endorse_article(
+                RawOrigin::Signed(reader.clone()).into(),
+                content_hash,
+                Sentiment::Flag
+            )
+            .unwrap();
+
+        #[extrinsic_call]
+        withdraw_endorsement(RawOrigin::Signed(reader.clone()), content_hash);
+
+        assert!(!Endorsements::<T>::contains_key(content_hash, reader));
+    }
+
+    #[benchmark]
+    fn submit_fact_check() -> Result<(), BenchmarkError> {
+        let (_, content_hash) = setup_article::<T>();
+        let origin = T::FactCheckOrigin::try_successful_origin().map_err(
+            |_| BenchmarkError::Weightless
+        )?;
+
+        #[extrinsic_call]
+        submit_fact_check(origin as T::RuntimeOrigin, content_hash, FactCheckVerdict::False);
+
+        assert_eq!(
+            FactCheckOf::<T>::get(content_hash).map(|check| check.verdict),
+            Some(FactCheckVerdict::False)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn retract_article() {
+        let (publisher, content_hash) = setup_article::<T>();
+
+        #[extrinsic_call]
+        retract_article(RawOrigin::Signed(publisher), content_hash);
+
+        assert!(RetractedAt::<T>::contains_key(content_hash));
+    }
+
+    #[benchmark]
+    fn record_syndication() {
+        let (_, original_anchor) = setup_article::<T>();
+        let new_hash = H256::repeat_byte(44);
+        let title = BoundedVec::<u8, T::MaxTitleLen>
+            ::try_from(b"Benchmark Title".to_vec())
+            .unwrap();
+        let canonical_url = BoundedVec::<u8, T::MaxUrlLen>
+            ::try_from(b"https://republisher.com".to_vec())
+            .unwrap();
+        let payload = News::<T>::article_payload(
+            new_hash,
+            Some(original_anchor),
+            3u128,
+            4u128,
+            &title,
+            &canonical_url,
+            HashAlgo::Blake2b256,
+            123u32
+        );
+        let (signature, republisher) = T::BenchmarkHelper::create_signature(
+            1,
+            &payload.signing_message()
+        );
+
+        #[extrinsic_call]
+        record_syndication(
+            RawOrigin::Signed(republisher),
+            original_anchor,
+            new_hash,
+            3u128,
+            4u128,
+            title,
+            canonical_url,
+            signature.into(),
+            HashAlgo::Blake2b256,
+            123u32
+        );
+
+        assert_eq!(SyndicatedFrom::<T>::get(new_hash), Some(original_anchor));
+    }
+
+    #[benchmark]
+    fn declare_citations(c: Linear<0, { T::MaxCitations::get() }>) {
+        let (publisher, content_hash) = setup_article::<T>();
+        let record = ArticleByHash::<T>::get(content_hash).unwrap();
+        let citations: Vec<H256> = (0..c)
+            .map(|i| {
+                let cited = H256::from_low_u64_be(1_000 + i as u64);
+                ArticleByHash::<T>::insert(cited, ArticleRecord::<T> {
+                    content_hash: cited,
+                    ..record.clone()
+                });
+                cited
+            })
+            .collect();
+        let citations = BoundedVec::<H256, T::MaxCitations>::try_from(citations).unwrap();
+
+        #[extrinsic_call]
+        declare_citations(RawOrigin::Signed(publisher), content_hash, citations);
+
+        assert_eq!(CitationsOf::<T>::get(content_hash).map(|c| c.len() as u32), Some(c));
+    }
+
+    #[benchmark]
+    fn reveal_article() {
+        let content_hash = H256::repeat_byte(42);
+        let title = BoundedVec::<u8, T::MaxTitleLen>
+            ::try_from(vec![b'T'; T::MaxTitleLen::get() as usize])
+            .unwrap();
+        let canonical_url = BoundedVec::<u8, T::MaxUrlLen>
+            ::try_from(vec![b'u'; T::MaxUrlLen::get() as usize])
+            .unwrap();
+        let salt = H256::repeat_byte(7);
+        let release_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        let (signature, publisher) = sign_article::<T>(0, content_hash, None, &[], &[], 123u32);
+
+        News::<T>
+            ::record_article(
+                RawOrigin::Signed(publisher.clone()).into(),
+                content_hash,
+                1u128,
+                2u128,
+                BoundedVec::default(),
+                BoundedVec::default(),
+                signature,
+                HashAlgo::Blake2b256,
+                123u32,
+                Some(EmbargoTerms {
+                    release_at,
+                    metadata_commitment: News::<T>::metadata_commitment(&title, &canonical_url, &salt),
+                })
+            )
+            .unwrap();
+        frame_system::Pallet::<T>::set_block_number(release_at);
+        let (signature, _) = sign_article::<T>(0, content_hash, None, &title, &canonical_url, 123u32);
+
+        #[extrinsic_call]
+        reveal_article(
+            RawOrigin::Signed(publisher),
+            content_hash,
+            title.clone(),
+            canonical_url,
+            salt,
+            signature
+        );
+
+        assert_eq!(ArticleByHash::<T>::get(content_hash).unwrap().title, title);
+    }
+
+    #[benchmark]
+    fn lift_embargoes(n: Linear<0, { T::MaxReleasesPerBlock::get() }>) {
+        let release_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        let queue: Vec<H256> = (0..n)
+            .map(|i| {
+                let content_hash = H256::from_low_u64_be(i as u64);
+                Embargoes::<T>::insert(content_hash, Embargo::<T> {
+                    terms: EmbargoTerms { release_at, metadata_commitment: H256::zero() },
+                    lifted: false,
+                });
+                content_hash
+            })
+            .collect();
+        ReleaseQueue::<T>::insert(release_at, BoundedVec::try_from(queue).unwrap());
+
+        #[block]
+        {
+            News::<T>::on_initialize(release_at);
+        }
+
+        assert!(ReleaseQueue::<T>::get(release_at).is_empty());
+    }
+
+    #[benchmark]
+    fn commit_draft() {
+        let owner = funded_reader::<T>();
+        let commitment = News::<T>::draft_commitment(
+            &owner,
+            &H256::repeat_byte(42),
+            &H256::repeat_byte(7)
+        );
+
+        #[extrinsic_call]
+        commit_draft(RawOrigin::Signed(owner.clone()), commitment);
+
+        assert!(DraftCommitments::<T>::contains_key(&owner, commitment));
+    }
+
+    #[benchmark]
+    fn reveal_draft() {
+        let (publisher, content_hash) = setup_article::<T>();
+        fund::<T>(&publisher);
+        let salt = H256::repeat_byte(7);
+        let commitment = News::<T>::draft_commitment(&publisher, &content_hash, &salt);
+        News::<T>::commit_draft(RawOrigin::Signed(publisher.clone()).into(), commitment).unwrap();
+
+        #[extrinsic_call]
+        reveal_draft(RawOrigin::Signed(publisher), commitment, salt, content_hash);
+
+        assert!(PriorityOf::<T>::contains_key(content_hash));
+    }
+
+    #[benchmark]
+    fn remove_expired_draft() {
+        let owner = funded_reader::<T>();
+        let commitment = News::<T>::draft_commitment(
+            &owner,
+            &H256::repeat_byte(42),
+            &H256::repeat_byte(7)
+        );
+        News::<T>::commit_draft(RawOrigin::Signed(owner.clone()).into(), commitment).unwrap();
+        let expired_at = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::DraftLifetime::get())
+            .saturating_add(1u32.into());
+        frame_system::Pallet::<T>::set_block_number(expired_at);
+
+        #[extrinsic_call]
+        remove_expired_draft(RawOrigin::Signed(owner.clone()), owner.clone(), commitment);
+
+        assert!(!DraftCommitments::<T>::contains_key(&owner, commitment));
+    }
+
+    #[benchmark]
+    fn submit_integrity_report() -> Result<(), BenchmarkError> {
+        let (_, content_hash) = setup_article::<T>();
+        let key = <ReporterCrypto<T> as AppCrypto<T::Public, ReporterSignature<T>>>::RuntimeAppPublic
+            ::generate_pair(None);
+        let reporter: T::Public = <ReporterCrypto<T> as AppCrypto<
+            T::Public,
+            ReporterSignature<T>
+        >>::GenericPublic::from(key).into();
+        IntegrityWatchers::<T>::insert(reporter.clone().into_account(), ());
+
+        let report = IntegrityReport {
+            content_hash,
+            observation: IntegrityObservation::Changed { observed: H256::repeat_byte(1) },
+            checked_at: frame_system::Pallet::<T>::block_number(),
+            public: reporter,
+        };
+        let signature = SignedPayload::<T>::sign::<T::IntegrityAuthorityId>(&report).unwrap();
+        let call = Call::<T>::submit_integrity_report { report, signature };
+
+        #[block]
+        {
+            News::<T>::validate_unsigned(TransactionSource::InBlock, &call).map_err(<&str>::from)?;
+            call.dispatch_bypass_filter(RawOrigin::None.into())?;
+        }
+
+        assert!(IntegrityStatusOf::<T>::get(content_hash).last_checked.is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_integrity_watcher() -> Result<(), BenchmarkError> {
+        let watcher: T::AccountId = account("watcher", 0, 0);
+        let origin = T::IntegrityWatcherOrigin::try_successful_origin().map_err(
+            |_| BenchmarkError::Weightless
+        )?;
+
+        #[extrinsic_call]
+        add_integrity_watcher(origin as T::RuntimeOrigin, watcher.clone());
+
+        assert!(IntegrityWatchers::<T>::contains_key(watcher));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_integrity_watcher() -> Result<(), BenchmarkError> {
+        let watcher: T::AccountId = account("watcher", 0, 0);
+        IntegrityWatchers::<T>::insert(&watcher, ());
+        let origin = T::IntegrityWatcherOrigin::try_successful_origin().map_err(
+            |_| BenchmarkError::Weightless
+        )?;
+
+        #[extrinsic_call]
+        remove_integrity_watcher(origin as T::RuntimeOrigin, watcher.clone());
+
+        assert!(!IntegrityWatchers::<T>::contains_key(watcher));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn record_article_unsigned() -> Result<(), BenchmarkError> {
+        let content_hash = H256::repeat_byte(42);
+        let title = BoundedVec::<u8, T::MaxTitleLen>
+            ::try_from(b"Benchmark Title".to_vec())
+            .unwrap();
+        let canonical_url = BoundedVec::<u8, T::MaxUrlLen>
+            ::try_from(b"https://benchmark.com".to_vec())
+            .unwrap();
+        let payload = ArticlePayload {
+            nonce: Some(0),
+            ..News::<T>::article_payload(
+                content_hash,
+                None,
+                1u128,
+                2u128,
+                &title,
+                &canonical_url,
+                HashAlgo::Blake2b256,
+                123u32
+            )
+        };
+        let (signature, publisher) = T::BenchmarkHelper::create_signature(
+            0,
+            &payload.signing_message()
+        );
+        FeelessPublishers::<T>::insert(&publisher, ());
+
+        let call = Call::<T>::record_article_unsigned {
+            publisher: publisher.clone(),
+            content_hash,
+            collection_id: 1u128,
+            item_id: 2u128,
+            title,
+            canonical_url,
+            signature: signature.into(),
+            hash_algo: HashAlgo::Blake2b256,
+            word_count: 123u32,
+            nonce: 0,
+        };
+
+        #[block]
+        {
+            News::<T>::validate_unsigned(TransactionSource::InBlock, &call).map_err(<&str>::from)?;
+            call.dispatch_bypass_filter(RawOrigin::None.into())?;
+        }
+
+        assert!(ArticleByHash::<T>::get(content_hash).is_some());
+        assert_eq!(PublisherNonces::<T>::get(&publisher), 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn approve_feeless_publisher() -> Result<(), BenchmarkError> {
+        let publisher: T::AccountId = account("publisher", 0, 0);
+        let origin = T::FeelessApprovalOrigin::try_successful_origin().map_err(
+            |_| BenchmarkError::Weightless
+        )?;
+
+        #[extrinsic_call]
+        approve_feeless_publisher(origin as T::RuntimeOrigin, publisher.clone());
+
+        assert!(FeelessPublishers::<T>::contains_key(publisher));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_feeless_publisher() -> Result<(), BenchmarkError> {
+        let publisher: T::AccountId = account("publisher", 0, 0);
+        FeelessPublishers::<T>::insert(&publisher, ());
+        FeelessAnchorsInEra::<T>::insert(&publisher, (0, 1));
+        let origin = T::FeelessApprovalOrigin::try_successful_origin().map_err(
+            |_| BenchmarkError::Weightless
+        )?;
+
+        #[extrinsic_call]
+        revoke_feeless_publisher(origin as T::RuntimeOrigin, publisher.clone());
+
+        assert!(!FeelessPublishers::<T>::contains_key(publisher));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_sponsorship_budget() {
+        let sponsor: T::AccountId = account("sponsor", 0, 0);
+        let budget = T::Currency::minimum_balance();
+
+        #[extrinsic_call]
+        set_sponsorship_budget(RawOrigin::Signed(sponsor.clone()), budget);
+
+        assert_eq!(SponsorBudgets::<T>::get(sponsor), budget);
+    }
+
+    #[benchmark]
+    fn sponsor_journalist() {
+        let sponsor: T::AccountId = account("sponsor", 0, 0);
+        let journalist: T::AccountId = account("journalist", 0, 0);
+        let quota = T::Currency::minimum_balance();
+
+        #[extrinsic_call]
+        sponsor_journalist(RawOrigin::Signed(sponsor.clone()), journalist.clone(), quota);
+
+        assert_eq!(SponsoredJournalists::<T>::get(journalist).map(|s| s.sponsor), Some(sponsor));
+    }
+
+    #[benchmark]
+    fn revoke_sponsorship() {
+        let sponsor: T::AccountId = account("sponsor", 0, 0);
+        let journalist: T::AccountId = account("journalist", 0, 0);
+        SponsoredJournalists::<T>::insert(&journalist, Sponsorship::<T> {
+            sponsor: sponsor.clone(),
+            quota: T::Currency::minimum_balance(),
+        });
+
+        #[extrinsic_call]
+        revoke_sponsorship(RawOrigin::Signed(sponsor), journalist.clone());
+
+        assert!(!SponsoredJournalists::<T>::contains_key(journalist));
+    }
+
+    #[benchmark]
+    fn register_ethereum_address() {
+        let caller: T::AccountId = whitelisted_caller();
+        // Worst case: replaces a previously registered address.
+        let previous = H160::repeat_byte(1);
+        EthereumAddressOf::<T>::insert(&caller, previous);
+        PublisherOfEthereumAddress::<T>::insert(previous, &caller);
+
+        let digest = News::<T>::eip191_registration_digest(&caller);
+        let public = ecdsa_generate(0.into(), None);
+        let proof: EcdsaSignature = ecdsa_sign_prehashed(0.into(), &public, &digest).unwrap().into();
+        let address = crate::ethereum::recover_address(&proof, &digest).unwrap();
+
+        #[extrinsic_call]
+        register_ethereum_address(RawOrigin::Signed(caller.clone()), address, proof);
+
+        assert_eq!(EthereumAddressOf::<T>::get(&caller), Some(address));
+        assert!(!PublisherOfEthereumAddress::<T>::contains_key(previous));
+    }
+
+    #[benchmark]
+    fn unregister_ethereum_address() {
+        let caller: T::AccountId = whitelisted_caller();
+        let address = H160::repeat_byte(1);
+        EthereumAddressOf::<T>::insert(&caller, address);
+        PublisherOfEthereumAddress::<T>::insert(address, &caller);
+
+        #[extrinsic_call]
+        unregister_ethereum_address(RawOrigin::Signed(caller.clone()));
+
+        assert!(!EthereumAddressOf::<T>::contains_key(&caller));
+    }
+
+    #[benchmark]
+    fn record_article_for() {
+        let content_hash = H256::repeat_byte(42);
+        let title = BoundedVec::<u8, T::MaxTitleLen>
+            ::try_from(b"Benchmark Title".to_vec())
+            .unwrap();
+        let canonical_url = BoundedVec::<u8, T::MaxUrlLen>
+            ::try_from(b"https://benchmark.com".to_vec())
+            .unwrap();
+        let valid_until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        let payload = ArticlePayload {
+            valid_until: Some(valid_until),
+            nonce: Some(0),
+            ..News::<T>::article_payload(
+                content_hash,
+                None,
+                1u128,
+                2u128,
+                &title,
+                &canonical_url,
+                HashAlgo::Blake2b256,
+                123u32
+            )
+        };
+        let (signature, publisher) = T::BenchmarkHelper::create_signature(
+            0,
+            &payload.signing_message()
+        );
+        let relayer: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        record_article_for(
+            RawOrigin::Signed(relayer),
+            publisher.clone(),
+            content_hash,
+            1u128,
+            2u128,
+            title,
+            canonical_url,
+            signature.into(),
+            HashAlgo::Blake2b256,
+            123u32,
+            valid_until,
+            0
+        );
+
+        assert_eq!(ArticleByHash::<T>::get(content_hash).map(|record| record.publisher), Some(publisher));
+    }
+
+    #[benchmark]
+    fn add_signing_key() {
+        let caller: T::AccountId = whitelisted_caller();
+        let (proof, key) = T::BenchmarkHelper::create_signature(
+            0,
+            &News::<T>::signing_key_message(&caller)
+        );
+        let valid_from = frame_system::Pallet::<T>::block_number();
+
+        #[extrinsic_call]
+        add_signing_key(RawOrigin::Signed(caller.clone()), key.clone(), valid_from, None, proof);
+
+        assert!(SigningKeys::<T>::contains_key(&caller, &key));
+    }
+
+    #[benchmark]
+    fn rotate_signing_key() {
+        let caller: T::AccountId = whitelisted_caller();
+        let old: T::AccountId = account("key", 0, 0);
+        SigningKeys::<T>::insert(&caller, &old, SigningKeyInfo {
+            valid_from: 0u32.into(),
+            expires_at: None,
+            revoked_from: None,
+        });
+        let (proof, new) = T::BenchmarkHelper::create_signature(
+            0,
+            &News::<T>::signing_key_message(&caller)
+        );
+
+        #[extrinsic_call]
+        rotate_signing_key(RawOrigin::Signed(caller.clone()), old.clone(), new.clone(), proof);
+
+        assert!(SigningKeys::<T>::get(&caller, &old).unwrap().expires_at.is_some());
+        assert!(SigningKeys::<T>::contains_key(&caller, &new));
+    }
+
+    #[benchmark]
+    fn revoke_signing_key() {
+        let caller: T::AccountId = whitelisted_caller();
+        let key: T::AccountId = account("key", 0, 0);
+        SigningKeys::<T>::insert(&caller, &key, SigningKeyInfo {
+            valid_from: 0u32.into(),
+            expires_at: None,
+            revoked_from: None,
+        });
+
+        #[extrinsic_call]
+        revoke_signing_key(RawOrigin::Signed(caller.clone()), key.clone(), 0u32.into());
+
+        assert!(SigningKeys::<T>::get(&caller, &key).unwrap().revoked_from.is_some());
+    }
+
+    #[benchmark]
+    fn anchor_batch() {
+        let merkle_root = H256::repeat_byte(7);
+        let (_, publisher) = T::BenchmarkHelper::create_signature(0, &[]);
+        let (signature, _) = T::BenchmarkHelper::create_signature(
+            0,
+            &News::<T>::batch_payload(publisher.clone(), merkle_root, 10_000).signing_message()
+        );
+
+        #[extrinsic_call]
+        anchor_batch(RawOrigin::Signed(publisher.clone()), merkle_root, 10_000, signature.into());
+
+        assert!(Batches::<T>::contains_key(&publisher, merkle_root));
+    }
+
+    #[benchmark]
+    fn promote_batch_leaf(p: Linear<1, { T::MaxBatchProofLen::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+        let content_hash = H256::repeat_byte(42);
+        let title = BoundedVec::<u8, T::MaxTitleLen>
+            ::try_from(b"Benchmark Title".to_vec())
+            .unwrap();
+        let canonical_url = BoundedVec::<u8, T::MaxUrlLen>
+            ::try_from(b"https://benchmark.com".to_vec())
+            .unwrap();
+        let leaf = BatchLeaf {
+            publisher: caller.clone(),
+            content_hash,
+            collection_id: 1u128,
+            item_id: 2u128,
+            title: title.to_vec(),
+            canonical_url: canonical_url.to_vec(),
+            hash_algo: HashAlgo::Blake2b256,
+            word_count: 123u32,
+        };
+        let proof: Vec<H256> = (0..p).map(|i| H256::repeat_byte(i as u8)).collect();
+        let merkle_root = crate::batch::root_from_proof(leaf.hash(), &proof);
+        let (signature, _) = T::BenchmarkHelper::create_signature(0, &[]);
+        Batches::<T>::insert(&caller, merkle_root, BatchRecord {
+            count: 1u32 << p.min(31),
+            signature: signature.into(),
+            anchored_at: 0u32.into(),
+        });
+
+        #[extrinsic_call]
+        promote_batch_leaf(
+            RawOrigin::Signed(caller),
+            merkle_root,
+            content_hash,
+            1u128,
+            2u128,
+            title,
+            canonical_url,
+            HashAlgo::Blake2b256,
+            123u32,
+            BoundedVec::try_from(proof).unwrap()
+        );
+
+        assert_eq!(BatchOf::<T>::get(content_hash), Some(merkle_root));
+    }
+
+    #[benchmark]
+    fn remove_article() {
+        let (publisher, anchor) = setup_article::<T>();
+        let record = ArticleByHash::<T>::get(anchor).unwrap();
+        let mut history = HistoryByAnchor::<T>::get(anchor);
+        for i in 1..T::MaxHistoryLen::get() {
+            let version = H256::from_low_u64_be(i.into());
+            ArticleByHash::<T>::insert(version, ArticleRecord {
+                content_hash: version,
+                updates: i,
+                ..record.clone()
+            });
+            AnchorOf::<T>::insert(version, anchor);
+            history.try_push(version).unwrap();
+        }
+        HistoryByAnchor::<T>::insert(anchor, history);
+
+        #[extrinsic_call]
+        remove_article(RawOrigin::Signed(publisher), anchor);
+
+        assert!(Tombstones::<T>::contains_key(anchor));
+        assert!(!PendingRemovals::<T>::contains_key(anchor));
+    }
+
+    #[benchmark]
+    fn continue_removal(n: Linear<1, { T::MaxRemovalSteps::get() }>) {
+        // An archived history page left to unlink, whose latest version has `n` endorsements
+        let (publisher, anchor) = setup_article::<T>();
+        let record = ArticleByHash::<T>::get(anchor).unwrap();
+        let mut page = HistoryByAnchor::<T>::take(anchor);
+        for i in 1..T::MaxHistoryLen::get() {
+            let version = H256::from_low_u64_be(i.into());
+            ArticleByHash::<T>::insert(version, ArticleRecord {
+                content_hash: version,
+                updates: i,
+                ..record.clone()
+            });
+            AnchorOf::<T>::insert(version, anchor);
+            page.try_push(version).unwrap();
+        }
+        let latest = *page.last().unwrap();
+        for i in 0..n {
+            let reader: T::AccountId = account("reader", i, 0);
+            fund::<T>(&reader);
+            News::<T>
+                ::endorse_article(RawOrigin::Signed(reader).into(), latest, Sentiment::Endorse)
+                .unwrap();
+        }
+        HistoryPages::<T>::insert(anchor, 0, page);
+        HistoryPageCount::<T>::insert(anchor, 1);
+        Tombstones::<T>::insert(anchor, Tombstone {
+            publisher,
+            anchor,
+            reason: RemovalReason::Publisher,
+            removed_at: 0u32.into(),
+        });
+        PendingRemovals::<T>::insert(anchor, BoundedVec::new());
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        continue_removal(RawOrigin::Signed(caller), anchor);
+
+        assert!(ArticleByHash::<T>::get(latest).is_none());
+        assert_eq!(HistoryPageCount::<T>::get(anchor), 0);
+    }
+
+    /// `c` co-authors sharing tips equally with the publisher.
+    fn co_authors<T: Config>(c: u32) -> BoundedVec<CoAuthor<T>, T::MaxCoAuthors> {
+        let share = Perbill::from_rational(1, c + 1);
+        (0..c)
+            .map(|i| CoAuthor { account: account("co_author", i, 0), share })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[benchmark]
+    fn set_bylines(c: Linear<0, { T::MaxCoAuthors::get() }>) {
+        let (publisher, anchor) = setup_article::<T>();
+        let co_authors = co_authors::<T>(c);
+
+        #[extrinsic_call]
+        set_bylines(RawOrigin::Signed(publisher), anchor, co_authors);
+
+        assert_eq!(Bylines::<T>::decode_len(anchor).unwrap_or(0) as u32, c);
+    }
+
+    #[benchmark]
+    fn tip_article(c: Linear<0, { T::MaxCoAuthors::get() }>) {
+        let (publisher, anchor) = setup_article::<T>();
+        News::<T>
+            ::set_bylines(RawOrigin::Signed(publisher).into(), anchor, co_authors::<T>(c))
+            .unwrap();
+        // Every share reaches the existential deposit of a new account
+        let amount = T::Currency::minimum_balance().saturating_mul((10 * (c + 1)).into());
+        let reader: T::AccountId = account("reader", 0, 0);
+        T::Currency::set_balance(&reader, amount.saturating_mul(2u32.into()));
+
+        #[extrinsic_call]
+        tip_article(RawOrigin::Signed(reader), anchor, amount, None);
+
+        assert_eq!(TipsByAnchor::<T>::get(anchor).total, amount);
     }
 
     impl_benchmark_test_suite!(News, crate::mock::new_test_ext(), crate::mock::Test);
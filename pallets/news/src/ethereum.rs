@@ -0,0 +1,225 @@
+//! Messages Ethereum wallets sign for publishers, and recovery of the signing address.
+
+use crate::pallet::*;
+use alloc::{ format, string::String };
+use frame::deps::{
+    sp_core::H160,
+    sp_io::{ crypto::secp256k1_ecdsa_recover, hashing::keccak_256 },
+};
+use frame::{ prelude::*, traits::SaturatedConversion };
+
+/// EIP-712 type of the signing domain.
+const DOMAIN_TYPE: &[u8] = b"EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)";
+/// EIP-712 type of a signed article.
+const ARTICLE_TYPE: &[u8] =
+    b"Article(bytes32 contentHash,bytes32 previous,uint128 collectionId,uint128 itemId,string title,string url,uint8 hashAlgo,uint32 wordCount,uint64 validUntil,uint64 nonce)";
+/// EIP-712 type of a signed batch.
+const BATCH_TYPE: &[u8] = b"Batch(bytes publisher,bytes32 merkleRoot,uint32 count)";
+
+impl<T: Config> Pallet<T> {
+    /// Hash a wallet signs with `personal_sign` to authorize an article:
+    ///
+    /// ```text
+    /// Anchor news article
+    /// version: <version>
+    /// chain: 0x<genesis_hash>
+    /// pallet: <pallet_name>
+    /// content hash: 0x<content_hash>
+    /// previous: 0x<previous>, or none
+    /// collection: <collection_id>
+    /// item: <item_id>
+    /// title: <title>
+    /// url: <canonical_url>
+    /// hash algo: sha256 | blake2b-256
+    /// word count: <word_count>
+    /// valid until: <valid_until>, or none
+    /// nonce: <nonce>, or none
+    /// ```
+    pub fn eip191_article_digest(payload: &ArticlePayloadOf<T>) -> [u8; 32] {
+        let previous = match payload.previous {
+            Some(previous) => format!("0x{}", hex(previous.as_bytes())),
+            None => "none".into(),
+        };
+        let valid_until = match payload.valid_until {
+            Some(valid_until) => format!("{}", valid_until.saturated_into::<u128>()),
+            None => "none".into(),
+        };
+        let nonce = match payload.nonce {
+            Some(nonce) => format!("{}", nonce),
+            None => "none".into(),
+        };
+        let hash_algo = match payload.hash_algo {
+            HashAlgo::Sha256 => "sha256",
+            HashAlgo::Blake2b256 => "blake2b-256",
+        };
+
+        let mut message = format!(
+            "Anchor news article\nversion: {}\nchain: 0x{}\npallet: ",
+            payload.version,
+            hex(payload.genesis_hash.as_ref())
+        ).into_bytes();
+        message.extend_from_slice(&payload.pallet_name);
+        message.extend(
+            format!(
+                "\ncontent hash: 0x{}\nprevious: {}\ncollection: {}\nitem: {}\ntitle: ",
+                hex(payload.content_hash.as_bytes()),
+                previous,
+                payload.collection_id,
+                payload.item_id
+            ).into_bytes()
+        );
+        message.extend_from_slice(&payload.title);
+        message.extend_from_slice(b"\nurl: ");
+        message.extend_from_slice(&payload.canonical_url);
+        message.extend(
+            format!(
+                "\nhash algo: {}\nword count: {}\nvalid until: {}\nnonce: {}",
+                hash_algo,
+                payload.word_count,
+                valid_until,
+                nonce
+            ).into_bytes()
+        );
+        personal_sign_digest(&message)
+    }
+
+    /// Hash a wallet signs with `eth_signTypedData` to authorize an article: the EIP-712 hash of
+    /// the `Article` in the domain named after the pallet, with the payload version as version,
+    /// `EthereumChainId` as chain id and the genesis hash as salt. A missing `previous`,
+    /// `valid_until` or `nonce` is encoded as zero.
+    pub fn eip712_article_digest(payload: &ArticlePayloadOf<T>) -> [u8; 32] {
+        let domain_separator = Self::eip712_domain_separator(
+            payload.version,
+            payload.genesis_hash.as_ref(),
+            &payload.pallet_name
+        );
+        let article = keccak_256(
+            &[
+                keccak_256(ARTICLE_TYPE),
+                payload.content_hash.0,
+                payload.previous.unwrap_or_default().0,
+                uint(payload.collection_id),
+                uint(payload.item_id),
+                keccak_256(&payload.title),
+                keccak_256(&payload.canonical_url),
+                uint(payload.hash_algo as u128),
+                uint(payload.word_count.into()),
+                uint(payload.valid_until.map_or(0, |block| block.saturated_into())),
+                uint(payload.nonce.unwrap_or_default().into()),
+            ].concat()
+        );
+
+        typed_data_digest(&domain_separator, &article)
+    }
+
+    /// Hash a wallet signs with `personal_sign` to authorize a batch:
+    ///
+    /// ```text
+    /// Anchor news batch
+    /// version: <version>
+    /// chain: 0x<genesis_hash>
+    /// pallet: <pallet_name>
+    /// publisher: 0x<SCALE encoding of the publisher account>
+    /// merkle root: 0x<merkle_root>
+    /// count: <count>
+    /// ```
+    pub fn eip191_batch_digest(payload: &BatchPayloadOf<T>) -> [u8; 32] {
+        let mut message = format!(
+            "Anchor news batch\nversion: {}\nchain: 0x{}\npallet: ",
+            payload.version,
+            hex(payload.genesis_hash.as_ref())
+        ).into_bytes();
+        message.extend_from_slice(&payload.pallet_name);
+        message.extend(
+            format!(
+                "\npublisher: 0x{}\nmerkle root: 0x{}\ncount: {}",
+                hex(&payload.publisher.encode()),
+                hex(payload.merkle_root.as_bytes()),
+                payload.count
+            ).into_bytes()
+        );
+        personal_sign_digest(&message)
+    }
+
+    /// Hash a wallet signs with `eth_signTypedData` to authorize a batch: the EIP-712 hash of
+    /// the `Batch` in the same domain as articles, with the SCALE encoding of the publisher
+    /// account as `publisher`.
+    pub fn eip712_batch_digest(payload: &BatchPayloadOf<T>) -> [u8; 32] {
+        let domain_separator = Self::eip712_domain_separator(
+            payload.version,
+            payload.genesis_hash.as_ref(),
+            &payload.pallet_name
+        );
+        let batch = keccak_256(
+            &[
+                keccak_256(BATCH_TYPE),
+                keccak_256(&payload.publisher.encode()),
+                payload.merkle_root.0,
+                uint(payload.count.into()),
+            ].concat()
+        );
+        typed_data_digest(&domain_separator, &batch)
+    }
+
+    /// Separator of the EIP-712 domain named after the pallet, with the payload version as
+    /// version, `EthereumChainId` as chain id and the genesis hash as salt.
+    fn eip712_domain_separator(version: u8, genesis_hash: &[u8], pallet_name: &[u8]) -> [u8; 32] {
+        let mut salt = [0u8; 32];
+        let len = genesis_hash.len().min(32);
+        salt[..len].copy_from_slice(&genesis_hash[..len]);
+        keccak_256(
+            &[
+                keccak_256(DOMAIN_TYPE),
+                keccak_256(pallet_name),
+                keccak_256(format!("{}", version).as_bytes()),
+                uint(T::EthereumChainId::get().into()),
+                salt,
+            ].concat()
+        )
+    }
+
+    /// Hash a wallet signs with `personal_sign` to prove it controls the address `publisher`
+    /// registers: `Register news publisher 0x<SCALE-encoded publisher>`.
+    pub fn eip191_registration_digest(publisher: &T::AccountId) -> [u8; 32] {
+        let mut message = b"Register news publisher 0x".to_vec();
+        message.extend_from_slice(hex(&publisher.encode()).as_bytes());
+        personal_sign_digest(&message)
+    }
+}
+
+/// Ethereum address of the key that produced `signature` of `digest`.
+pub(crate) fn recover_address(signature: &EcdsaSignature, digest: &[u8; 32]) -> Option<H160> {
+    let public = secp256k1_ecdsa_recover(signature, digest).ok()?;
+    Some(H160::from_slice(&keccak_256(&public)[12..]))
+}
+
+/// EIP-191 version `0x45` hash of `message`, as computed by `personal_sign`.
+fn personal_sign_digest(message: &[u8]) -> [u8; 32] {
+    let mut data = b"\x19Ethereum Signed Message:\n".to_vec();
+    data.extend_from_slice(format!("{}", message.len()).as_bytes());
+    data.extend_from_slice(message);
+    keccak_256(&data)
+}
+
+/// EIP-712 hash of a struct with hash `struct_hash` in the domain `domain_separator`.
+fn typed_data_digest(domain_separator: &[u8; 32], struct_hash: &[u8; 32]) -> [u8; 32] {
+    let mut message = b"\x19\x01".to_vec();
+    message.extend_from_slice(domain_separator);
+    message.extend_from_slice(struct_hash);
+    keccak_256(&message)
+}
+
+/// ABI encoding of an unsigned integer as a 32-byte word.
+fn uint(value: u128) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Lowercase hex encoding of `bytes`, without prefix.
+fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
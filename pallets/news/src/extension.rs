@@ -0,0 +1,93 @@
+//! Transaction extension that keeps ordinary `Normal` extrinsics out of the block space reserved
+//! for provenance calls.
+
+use crate::pallet::*;
+use core::marker::PhantomData;
+use frame::deps::{
+    frame_support::{ dispatch::DispatchInfo, traits::IsSubType },
+    sp_runtime::{
+        impl_tx_ext_default,
+        traits::{ DispatchInfoOf, DispatchOriginOf, Dispatchable, TransactionExtension },
+        transaction_validity::{ InvalidTransaction, TransactionValidityError },
+    },
+};
+use frame::prelude::*;
+
+/// Rejects a `Normal` extrinsic that is not a provenance call (see `Pallet::is_provenance_call`)
+/// if it would take the block's `Normal` weight into the last `ProvenanceReservedWeight` of it.
+/// Provenance calls stay `Normal`, with the usual fees and priority, and can use all of it.
+///
+/// The extrinsic is counted as `CheckWeight` counts it: its dispatch weight, the `Normal` class's
+/// `base_extrinsic` and its encoded length as proof size.
+///
+/// Like `CheckWeight`, the block is only looked at when the extrinsic is applied, so it must come
+/// before `CheckWeight` in the runtime's extensions.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckProvenanceSpace<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckProvenanceSpace<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> Default for CheckProvenanceSpace<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config + Send + Sync> core::fmt::Debug for CheckProvenanceSpace<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CheckProvenanceSpace")
+    }
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall>
+    for CheckProvenanceSpace<T>
+    where T::RuntimeCall: Dispatchable<Info = DispatchInfo> + IsSubType<Call<T>>
+{
+    const IDENTIFIER: &'static str = "CheckProvenanceSpace";
+    type Implicit = ();
+    type Val = ();
+    type Pre = ();
+
+    fn weight(&self, _call: &T::RuntimeCall) -> Weight {
+        T::DbWeight::get().reads(1)
+    }
+
+    fn prepare(
+        self,
+        _val: Self::Val,
+        _origin: &DispatchOriginOf<T::RuntimeCall>,
+        call: &T::RuntimeCall,
+        info: &DispatchInfoOf<T::RuntimeCall>,
+        len: usize
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        if
+            info.class != DispatchClass::Normal ||
+            call.is_sub_type().is_some_and(Pallet::<T>::is_provenance_call)
+        {
+            return Ok(());
+        }
+        let block_weights = T::BlockWeights::get();
+        let normal = block_weights.get(DispatchClass::Normal);
+        let normal_limit = normal.max_total.unwrap_or(block_weights.max_block);
+        let available = normal_limit.saturating_sub(T::ProvenanceReservedWeight::get());
+        let extrinsic_weight = info
+            .total_weight()
+            .saturating_add(normal.base_extrinsic)
+            .saturating_add(Weight::from_parts(0, len as u64));
+        let consumed = <frame_system::Pallet<T>>
+            ::block_weight()
+            .get(DispatchClass::Normal)
+            .saturating_add(extrinsic_weight);
+        if consumed.any_gt(available) {
+            return Err(InvalidTransaction::ExhaustsResources.into());
+        }
+        Ok(())
+    }
+
+    impl_tx_ext_default!(T::RuntimeCall; validate);
+}
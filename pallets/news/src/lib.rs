@@ -3,38 +3,341 @@
 //! Records and tracks provenance of news articles with simple, valuable utility:
 //! - Immutable anchor hash per article (content_hash == anchor_hash)
 //! - Compact version history (append-only list of hashes per anchor)
-//! - Signature is stored (for off-chain verification), not verified on-chain
+//! - Publisher signature over the article metadata, verified on-chain and stored
 //!
 //! ## Storage
 //! - `ArticleByHash`: Content hash (any version) -> ArticleRecord
 //! - `RootByItem`: (collection_id, item_id) -> anchor hash (or latest depending on your policy)
 //! - `ArticlesByPublisher`: Publisher -> bounded list of their article anchor hashes (first versions)
 //! - `AnchorOf`: Any version hash -> anchor hash (first version)
-//! - `HistoryByAnchor`: Anchor hash -> bounded, chronological list of all version hashes (including anchor)
+//! - `HistoryByAnchor`: Anchor hash -> bounded, chronological list of the latest version hashes (including anchor
+//!   until the first page is archived)
+//! - `HistoryPages`: (anchor hash, page index) -> full page of older version hashes archived from `HistoryByAnchor`
+//! - `HistoryPageCount`: Anchor hash -> number of archived history pages
 //! - `ArticleUpdateCount`: Anchor hash -> updates count (version number of the latest record)
+//! - `Endorsements`: (version hash, reader) -> endorsement or flag, with the held deposit
+//! - `EndorsementTallies`: Version hash -> endorsement / flag counters
+//! - `FactCheckOf`: Version hash -> fact-check verdict and the block it was submitted at
+//! - `RetractedAt`: Version hash -> block at which the publisher retracted it
+//! - `PublisherReputation`: Publisher -> reputation score, stored in decayed form
+//! - `SyndicatedFrom`: Republished anchor -> anchor of the original article
+//! - `SyndicatedTo`: Original anchor -> bounded list of anchors republishing it
+//! - `CitationsOf`: Version hash -> bounded list of version hashes it cites
+//! - `CitedBy`: (cited hash, citing hash) -> () reverse citation index
+//! - `Embargoes`: Anchor hash -> embargo terms of an article whose metadata is not yet revealed
+//! - `ReleaseQueue`: Block number -> embargoed anchors whose embargo ends at that block
+//! - `DraftCommitments`: (Owner, salted draft commitment) -> deposit and expiry
+//! - `PriorityOf`: Version hash -> revealed draft commitment proving earlier possession
+//! - `IntegrityStatusOf`: Version hash -> live-page integrity state and the block it was last checked at
+//! - `IntegrityWatchers`: Accounts registered to report integrity checks besides `IntegrityReporters`
+//! - `FeelessPublishers`: Publishers approved to anchor articles through unsigned transactions
+//! - `FeelessAnchorsInEra`: Publisher -> feeless era index and anchors recorded in it
+//! - `SponsorBudgets`: Newsroom -> remaining budget for fees it pays on behalf of its journalists
+//! - `SponsoredJournalists`: Journalist -> sponsoring newsroom and remaining fee quota
+//! - `FreeAnchorsInSession`: Publisher -> session index and fee-free anchors recorded in it
+//! - `EthereumAddressOf`: Publisher -> Ethereum address it signs articles with
+//! - `PublisherOfEthereumAddress`: Ethereum address -> publisher it is registered to
+//! - `PublisherNonces`: Publisher -> nonce its next relayed or feeless signature must carry
+//! - `SigningKeys`: (publisher, key) -> validity window and revocation of a signing key
+//! - `Batches`: (publisher, Merkle root) -> leaf count, signature and block of an anchored batch
+//! - `BatchOf`: Version hash promoted from a batch -> the batch's Merkle root
+//! - `Tombstones`: Version hash of a removed lineage -> publisher, anchor, reason and block of removal
+//! - `PendingRemovals`: Anchor of a removed lineage -> versions whose indices are still being cleared
+//! - `VersionChanges`: Version hash (other than an anchor) -> kind of change, summary and whether title or URL changed
+//! - `CorrectionsOf`: (anchor hash, version hash) -> lineage index of a version marked as a substantive correction
 //!
 //! ## Calls
 //! - `record_article`: Create a new article (first version). Its content_hash is the anchor.
-//! - `update_article`: Append a new version (new hash) to the existing article lineage. Only publisher can update.
+//!   With an embargo, only the hash and a metadata commitment are published until `release_at`.
+//! - `update_article`: Append a new version (new hash) to the existing article lineage, with the kind of change
+//!   and an optional summary. Only publisher can update.
+//! - `endorse_article`: Endorse or flag an article version. Holds `EndorsementDeposit` from the reader.
+//! - `withdraw_endorsement`: Withdraw an endorsement / flag and release its deposit.
+//! - `submit_fact_check`: Record a fact-check verdict for a version (`FactCheckOrigin` only).
+//! - `retract_article`: Mark a version as retracted. Only publisher can retract.
+//! - `record_syndication`: Republish an article as a new anchor owned by the caller, linked to the original.
+//! - `declare_citations`: Declare, once, which recorded versions a version cites. Only publisher can declare.
+//! - `reveal_article`: Reveal the title and URL of an embargoed article once the embargo is over.
+//! - `commit_draft`: Anchor a salted commitment to unpublished reporting. Holds `DraftDeposit`.
+//! - `reveal_draft`: Open a commitment and link it to one of the caller's recorded versions.
+//! - `remove_expired_draft`: Clean up an unrevealed commitment after `DraftLifetime`, refunding its owner.
+//! - `submit_integrity_report`: Unsigned, reporter-signed result of an off-chain check of a canonical URL.
+//! - `add_integrity_watcher` / `remove_integrity_watcher`: Manage registered watchers (`IntegrityWatcherOrigin` only).
+//! - `record_article_unsigned`: Feeless `record_article` for approved publishers, authorized by the publisher signature.
+//! - `approve_feeless_publisher` / `revoke_feeless_publisher`: Manage feeless publishers (`FeelessApprovalOrigin` only).
+//! - `set_sponsorship_budget`: Set the caller's budget for sponsoring journalists' fees.
+//! - `sponsor_journalist`: Sponsor a journalist's `News` fees up to a quota.
+//! - `revoke_sponsorship`: End a sponsorship, by the newsroom or the journalist.
+//! - `register_ethereum_address` / `unregister_ethereum_address`: Link the caller to an EVM wallet address.
+//! - `record_article_for`: `record_article` relayed by any account, authorized by the publisher signature.
+//! - `add_signing_key` / `rotate_signing_key` / `revoke_signing_key`: Manage the caller's signing keys.
+//! - `anchor_batch`: Anchor many articles at once by the Merkle root of their leaves.
+//! - `promote_batch_leaf`: Turn a batch leaf into a full article lineage, given its inclusion proof.
+//! - `remove_article`: Take down one of the caller's lineages, leaving tombstones.
+//! - `purge_article`: Remove any lineage (`PurgeOrigin` only).
+//! - `continue_removal`: Clear further indices of a removed lineage.
+//! - `set_bylines`: Credit co-authors on the caller's lineage with a share of its tips.
+//! - `tip_article`: Send a tip to the publisher of a version, split with its co-authors.
 //!
 //! ## Events
 //! - ArticleRecorded, ArticleUpdated, ArticleHistoryExtended, ArticleVerified
+//! - ArticleEndorsed, EndorsementWithdrawn, FactCheckSubmitted, ArticleRetracted, ReputationUpdated
+//! - ArticleSyndicated, CitationsDeclared, EmbargoLifted, ArticleRevealed
+//! - DraftCommitted, DraftRevealed, DraftExpired
+//! - IntegrityStatusChanged, IntegrityWatcherAdded, IntegrityWatcherRemoved
+//! - FeelessPublisherApproved, FeelessPublisherRevoked
+//! - SponsorshipBudgetSet, JournalistSponsored, SponsorshipRevoked, FeeSponsored
+//! - EthereumAddressRegistered, EthereumAddressUnregistered, ArticleRelayed
+//! - SigningKeyAdded, SigningKeyRotated, SigningKeyRevoked
+//! - BatchAnchored, BatchLeafPromoted
+//! - ArticleRemoved, ArticleRemovalCompleted
+//! - BylinesSet, ArticleTipped
+//!
+//! ## Embargoes
+//! A newsroom can commit to a story before release by passing `EmbargoTerms` to
+//! `record_article`: the record keeps an empty title and URL, and `last_updated_at` proves the
+//! content hash existed at that block. At `release_at` the pallet lifts the embargo in
+//! `on_initialize` and emits `EmbargoLifted`. This does not publish the article: the chain only
+//! holds `metadata_commitment`, so the title and URL stay empty until the publisher calls
+//! `reveal_article` with the metadata, the salt and a fresh signature over the revealed record,
+//! which replaces the one over the empty metadata.
+//!
+//! ## Off-chain integrity checks
+//! Every `IntegrityCheckInterval` blocks the off-chain worker walks `HistoryByAnchor` (resuming
+//! where the previous run stopped) and, for the latest version of up to `MaxIntegrityChecksPerRun`
+//! lineages, fetches `canonical_url`, hashes the body with the record's `HashAlgo` and compares it
+//! with `content_hash`. The result is submitted as an unsigned `submit_integrity_report`, signed
+//! with a local `crypto::KEY_TYPE` key of an authorized reporter: a member of `IntegrityReporters`
+//! (e.g. the session's collators) or a registered watcher. Reports are accepted at most once per
+//! version per interval and update `IntegrityStatusOf`, so reader apps can show whether the live
+//! page still matches the anchored version.
+//!
+//! ## Feeless anchoring
+//! Outlets that cannot hold the native token can be approved through `approve_feeless_publisher`
+//! and then submit `record_article_unsigned` as an unsigned transaction; the publisher signature
+//! over the `ArticlePayload` and its next nonce, checked by `ValidateUnsigned`, authorizes it. Each approved publisher may
+//! anchor `MaxFeelessAnchorsPerEra` articles per `FeelessEraLength` blocks, and has at most one
+//! feeless anchor in the transaction pool at a time.
+//!
+//! ## Provenance block space
+//! `record_article`, `update_article`, `record_article_for` and `anchor_batch` are ordinary
+//! `Normal` calls. With `CheckProvenanceSpace` among its transaction extensions, the runtime keeps
+//! every other `Normal` extrinsic out of the last `ProvenanceReservedWeight` of the `Normal` block
+//! space, so anchoring has a budget that ordinary calls cannot fill. Approved
+//! publishers (see `approve_feeless_publisher`) get the fee of their first
+//! `FreeAnchorsPerSession` anchors and updates in each session refunded through `Pays::No`.
+//!
+//! ## Sponsored fees
+//! A newsroom sets a budget with `set_sponsorship_budget` and whitelists journalists with
+//! `sponsor_journalist`, each with its own quota. The runtime's `OnChargeTransaction` asks
+//! `sponsor_for` who pays for a journalist's `News` call, charges the fee to the newsroom's free
+//! balance and books it through `charge_sponsorship` / `settle_sponsorship`; fees beyond either
+//! the budget or the quota fall back to the journalist.
+//!
+//! ## Ethereum wallets
+//! Publishers signing in an EVM wallet such as MetaMask first link their account to an Ethereum
+//! address with `register_ethereum_address`, proven by a `personal_sign` of
+//! `eip191_registration_digest`. They can then authorize articles with a `PublisherSignature`
+//! of either `personal_sign` (EIP-191) over a readable rendering of the `ArticlePayload` or
+//! `eth_signTypedData` (EIP-712) over an `Article` in a domain named after the pallet, on
+//! `EthereumChainId` and salted with the genesis hash.
+//!
+//! ## Signing payload
+//! Publishers sign the SCALE-encoded `ArticlePayload` of a version: the genesis hash and pallet
+//! name for domain separation, followed by every field of the record and the version it
+//! updates. A signature can therefore not be replayed on another chain or with altered metadata.
+//! Signatures handed to a third party (`record_article_for`, `record_article_unsigned`) also
+//! carry the publisher's next `PublisherNonces` entry, consumed on use, so they cannot be
+//! submitted twice. While `AllowLegacySignatures` is set, native signatures of
+//! `<Bytes>content_hash</Bytes>` are accepted too, except for these calls.
+//!
+//! ## Relayed submission
+//! A CMS gateway can pay for anchoring with `record_article_for`: the author signs the payload
+//! with a `valid_until` block and its next nonce, and the gateway submits it from its own account
+//! before then. The record belongs to the author.
+//!
+//! ## Signing keys
+//! Publishers can keep their account key offline and sign versions with separate signing keys,
+//! registered with `add_signing_key` for a validity window and replaced with
+//! `rotate_signing_key`. Versions signed that way carry a `PublisherSignature::SigningKey`. A
+//! leaked key is revoked with `revoke_signing_key` back to the block it may have leaked at: it can
+//! no longer sign, and `signature_status` reports the versions it signed from that block on as
+//! `SignatureStatus::RevokedSigningKey`.
+//!
+//! ## Batch anchoring
+//! High-volume publishers such as wire services anchor a whole batch with `anchor_batch`: one
+//! `Batches` entry under the publisher holding the Merkle root over the `BatchLeaf` hashes of the
+//! batch (see `batch_merkle_root`) and the publisher signature of the `BatchPayload`. Leaves and
+//! payload both name the publisher, so a root or signature cannot be claimed by another account.
+//! Anyone can check that an article is part of an anchored batch with `verify_inclusion` and the
+//! sibling hashes from `batch_merkle_proof`. The publisher may later promote a leaf to a full
+//! `ArticleByHash` lineage with `promote_batch_leaf`, after which it can be updated, endorsed and
+//! cited like any other. `signature_status` judges a promoted version's signing key against the
+//! block the batch was anchored at, when it was signed, not the block of promotion.
+//!
+//! ## History
+//! `HistoryByAnchor` holds at most `MaxHistoryLen` version hashes. When an update finds it full,
+//! the full list is archived unchanged as the next `HistoryPages` entry and the new version starts
+//! a fresh list, so live blogs can be updated indefinitely. Version `i` of a lineage is at position
+//! `i % MaxHistoryLen` of page `i / MaxHistoryLen`, the page past the last archived one being
+//! `HistoryByAnchor`; pages never change once archived, so a storage proof of a page proves its
+//! versions. `history_page` reads a page by index.
+//!
+//! ## Removal
+//! A publisher takes a lineage down with `remove_article`; `PurgeOrigin` can do the same to any
+//! lineage with `purge_article`. The records of the versions in `HistoryByAnchor` and the
+//! publisher, NFT item, syndication and embargo links go at once, and each version hash is kept
+//! as a `Tombstone` so it cannot be anchored again. Archived history pages and indices of
+//! unbounded size (endorsements, citations) are cleared `MaxRemovalSteps` entries at a time, by the removing call and then by
+//! anyone calling `continue_removal`, with endorsement deposits returned to the readers. Last,
+//! `OnArticleRemoved` clears what pallets built on this one (e.g. license offers) store about the
+//! lineage, within the same budget.
+//!
+//! ## Tips
+//! Readers support a publisher directly with `tip_article` on any version of a lineage, in the
+//! native `Currency` or in one of the `Assets`, such as a stablecoin. The publisher may credit
+//! co-authors on the lineage's byline with `set_bylines`, each with a `Perbill` share of every
+//! later tip; the publisher receives what is left. Tips in `Currency` are counted per lineage in
+//! `TipsByAnchor` and per publisher in `TipsByPublisher`, and served by `TipsApi`. Amounts of
+//! different assets cannot be added up, so asset tips are only reported by `ArticleTipped`.
+//!
+//! ## Reputation
+//! Every publisher in `ArticlesByPublisher` accrues a signed score from endorsements, flags,
+//! fact-check verdicts and retractions of their articles. The score is stored together with the
+//! block it was last touched at, and retains `ReputationRetention` of its value every time the
+//! block number crosses a multiple of `ReputationDecayPeriod`; the decay is applied lazily on
+//! every write and on read through the runtime API. Since every score decays at the same
+//! boundaries, withdrawing an endorsement or replacing a verdict reverts its points decayed from
+//! the block they were applied at, which is exactly what remains of them in the score.
 //!
 //! ## Notes
-//! - Signatures are stored in `ArticleRecord.signature` for **off-chain** verification.
-//! - On-chain verification of signatures is intentionally omitted to keep runtime cost low.
+//! - Signatures are verified on-chain and stored in `ArticleRecord.signature` so anyone can
+//!   re-verify them off-chain against the `ArticlePayload`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use self::pallet::*;
+pub use batch::{ batch_merkle_proof, batch_merkle_root };
+pub use extension::CheckProvenanceSpace;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarking::BenchmarkHelper;
 
+mod batch;
+mod ethereum;
+mod extension;
+pub mod migrations;
+mod offchain;
+pub mod runtime_api;
 pub mod weights;
 
+/// Reputation points applied to a publisher's score.
+pub mod points {
+    pub const ENDORSEMENT: i64 = 10;
+    pub const FLAG: i64 = -20;
+    pub const FACT_CHECK_ACCURATE: i64 = 50;
+    pub const FACT_CHECK_MISLEADING: i64 = -100;
+    pub const FACT_CHECK_FALSE: i64 = -250;
+    pub const RETRACTION: i64 = -150;
+}
+
+/// Application crypto used by off-chain workers to sign integrity reports.
+pub mod crypto {
+    use frame::deps::{
+        frame_system::offchain::AppCrypto,
+        sp_core::sr25519,
+        sp_runtime::{ app_crypto::{ app_crypto, sr25519 as app_sr25519 }, KeyTypeId, MultiSignature, MultiSigner },
+    };
+
+    /// Key type of integrity reporter keys in the node keystore.
+    pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"nwsi");
+
+    app_crypto!(app_sr25519, KEY_TYPE);
+
+    /// Integrity reporter identity, for use as `Config::IntegrityAuthorityId`.
+    pub struct IntegrityReporterId;
+
+    impl AppCrypto<MultiSigner, MultiSignature> for IntegrityReporterId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sr25519::Signature;
+        type GenericPublic = sr25519::Public;
+    }
+}
+
+/// Decides whether an account may republish another publisher's article.
+///
+/// Implemented by licensing logic; `()` allows every syndication.
+pub trait SyndicationPolicy<AccountId> {
+    /// Returns `true` if `republisher` may syndicate the article anchored at `original_anchor`.
+    fn can_syndicate(
+        original_anchor: &pallet::ContentHash,
+        original_publisher: &AccountId,
+        republisher: &AccountId
+    ) -> bool;
+}
+
+impl<AccountId> SyndicationPolicy<AccountId> for () {
+    fn can_syndicate(_: &pallet::ContentHash, _: &AccountId, _: &AccountId) -> bool {
+        true
+    }
+}
+
+/// Lets pallets built on top of this one clear what they store about a removed lineage.
+///
+/// `()` stores nothing.
+pub trait OnArticleRemoved {
+    /// Clears up to `limit` entries stored about the removed lineage `anchor` and returns how many
+    /// it cleared. It is called again, with a fresh `limit`, until it clears fewer than `limit`.
+    fn on_article_removed(anchor: &pallet::ContentHash, limit: u32) -> u32;
+}
+
+impl OnArticleRemoved for () {
+    fn on_article_removed(_: &pallet::ContentHash, _: u32) -> u32 {
+        0
+    }
+}
+
+/// Read access to recorded lineages, for pallets built on top of this one.
+pub trait AnchorInspect<AccountId> {
+    /// Publisher of the lineage anchored at `anchor`; `None` if `anchor` is not a recorded anchor.
+    fn anchor_publisher(anchor: &pallet::ContentHash) -> Option<AccountId>;
+
+    /// Records a bare lineage `anchor` of `publisher`, for benchmarks of dependent pallets.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn insert_anchor(anchor: pallet::ContentHash, publisher: AccountId);
+}
+
+impl<T: pallet::Config> AnchorInspect<T::AccountId> for pallet::Pallet<T> {
+    fn anchor_publisher(anchor: &pallet::ContentHash) -> Option<T::AccountId> {
+        if pallet::AnchorOf::<T>::get(anchor) != Some(*anchor) {
+            return None;
+        }
+        pallet::ArticleByHash::<T>::get(anchor).map(|record| record.publisher)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn insert_anchor(anchor: pallet::ContentHash, publisher: T::AccountId) {
+        pallet::ArticleByHash::<T>::insert(anchor, pallet::ArticleRecord::<T> {
+            title: Default::default(),
+            canonical_url: Default::default(),
+            publisher,
+            collection_id: 0,
+            item_id: 0,
+            content_hash: anchor,
+            signature: pallet::PublisherSignature::Eip191([0u8; 65]),
+            hash_algo: pallet::HashAlgo::Blake2b256,
+            word_count: 0,
+            last_updated_at: frame::deps::frame_system::Pallet::<T>::block_number(),
+            updates: 0,
+        });
+        pallet::AnchorOf::<T>::insert(anchor, anchor);
+    }
+}
+
 // FRAME test scaffolding
 #[cfg(test)]
 mod mock;
@@ -43,7 +346,29 @@ mod tests;
 
 #[frame::pallet]
 pub mod pallet {
-    use frame::{ deps::sp_runtime::{ AccountId32, MultiSignature }, prelude::*, traits::Verify };
+    use crate::{ points, runtime_api::PublisherSummary, OnArticleRemoved, SyndicationPolicy };
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+    use frame::deps::frame_system::offchain::{ AppCrypto, CreateBare, SignedPayload, SigningTypes };
+    use frame::{
+        deps::{
+            sp_core::H160,
+            sp_io::hashing::blake2_256,
+            sp_runtime::{ traits::IdentifyAccount, PerThing, Perbill },
+        },
+        prelude::*,
+        traits::{
+            fungible::{ Inspect, Mutate, MutateHold },
+            fungibles,
+            tokens::{ Precision, Preservation },
+            Contains,
+            One,
+            SaturatedConversion,
+            Saturating,
+            Verify,
+            Zero,
+        },
+    };
 
     /// Unique identifier for a collection of articles (optional NFT linkage).
     pub type CollectionId = u128;
@@ -70,14 +395,91 @@ pub mod pallet {
         Blake2b256 = 2,
     }
 
+    /// Whether a reader vouches for or flags an article version.
+    #[derive(
+        TypeInfo,
+        DecodeWithMemTracking,
+        Encode,
+        Decode,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    pub enum Sentiment {
+        Endorse,
+        Flag,
+    }
+
+    /// Outcome of a fact-check of a single article version.
+    #[derive(
+        TypeInfo,
+        DecodeWithMemTracking,
+        Encode,
+        Decode,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    pub enum FactCheckVerdict {
+        Accurate,
+        Misleading,
+        False,
+    }
+
+    impl Sentiment {
+        /// Reputation points this sentiment contributes to the publisher's score.
+        pub fn points(&self) -> i64 {
+            match self {
+                Sentiment::Endorse => points::ENDORSEMENT,
+                Sentiment::Flag => points::FLAG,
+            }
+        }
+    }
+
+    impl FactCheckVerdict {
+        /// Reputation points this verdict contributes to the publisher's score.
+        pub fn points(&self) -> i64 {
+            match self {
+                FactCheckVerdict::Accurate => points::FACT_CHECK_ACCURATE,
+                FactCheckVerdict::Misleading => points::FACT_CHECK_MISLEADING,
+                FactCheckVerdict::False => points::FACT_CHECK_FALSE,
+            }
+        }
+    }
+
+    /// `InvalidTransaction::Custom` code for reports about versions that are not recorded.
+    pub const INVALID_REPORT_UNKNOWN_ARTICLE: u8 = 1;
+    /// `InvalidTransaction::Custom` code for reports signed by an unauthorized key.
+    pub const INVALID_REPORT_UNAUTHORIZED: u8 = 2;
+    /// `InvalidTransaction::Custom` code for feeless anchors from publishers that are not approved.
+    pub const INVALID_ANCHOR_NOT_APPROVED: u8 = 3;
+    /// `InvalidTransaction::Custom` code for feeless anchors beyond the publisher's era quota.
+    pub const INVALID_ANCHOR_QUOTA_EXHAUSTED: u8 = 4;
+
+    /// Balance type of the configured `Currency`.
+    pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<
+        <T as frame_system::Config>::AccountId
+    >>::Balance;
+    /// Asset id of the configured `Assets`.
+    pub type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<
+        <T as frame_system::Config>::AccountId
+    >>::AssetId;
+
     use crate::weights::WeightInfo;
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config + CreateBare<Call<Self>> + SigningTypes {
         /// Max number of anchor articles per publisher.
         #[pallet::constant]
         type MaxArticlesPerPublisher: Get<u32>;
-        /// Maximum length of a single article's version history (including the anchor).
+        /// Number of version hashes per history page. Older versions are archived a full page at
+        /// a time, so a lineage has no length limit.
         #[pallet::constant]
         type MaxHistoryLen: Get<u32>;
         /// Maximum length of article title
@@ -86,14 +488,453 @@ pub mod pallet {
         /// Maximum length of canonical URL
         #[pallet::constant]
         type MaxUrlLen: Get<u32>;
+        /// Maximum number of direct republications tracked per original anchor.
+        #[pallet::constant]
+        type MaxSyndications: Get<u32>;
+        /// Maximum number of embargoes that can be lifted in a single block.
+        #[pallet::constant]
+        type MaxReleasesPerBlock: Get<u32>;
+        /// Maximum number of citations a single article version can declare.
+        #[pallet::constant]
+        type MaxCitations: Get<u32>;
+        /// License check applied before an article may be republished.
+        type SyndicationPolicy: SyndicationPolicy<Self::AccountId>;
+        /// Clears what other pallets store about a lineage once it is removed.
+        type OnArticleRemoved: OnArticleRemoved;
         /// The overall runtime event type.
         #[allow(deprecated)]
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+        /// Currency used to hold reader deposits.
+        type Currency: Mutate<Self::AccountId> +
+            MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// Assets readers may tip in besides `Currency`, counted in the same unit.
+        type Assets: fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self>>;
+        /// Deposit held from a reader for each endorsement or flag (Sybil resistance).
+        #[pallet::constant]
+        type EndorsementDeposit: Get<BalanceOf<Self>>;
+        /// Proof-of-personhood hook: only accounts it contains may endorse or flag.
+        type PersonhoodVerifier: Contains<Self::AccountId>;
+        /// Origin allowed to submit fact-check verdicts.
+        type FactCheckOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Share of a publisher's reputation retained after each `ReputationDecayPeriod`.
+        #[pallet::constant]
+        type ReputationRetention: Get<Perbill>;
+        /// Number of blocks after which `ReputationRetention` is applied once.
+        #[pallet::constant]
+        type ReputationDecayPeriod: Get<BlockNumberFor<Self>>;
+        /// Deposit held for each draft commitment until it is revealed or removed.
+        #[pallet::constant]
+        type DraftDeposit: Get<BalanceOf<Self>>;
+        /// Number of blocks a draft commitment may stay unrevealed.
+        #[pallet::constant]
+        type DraftLifetime: Get<BlockNumberFor<Self>>;
+        /// Number of blocks between off-chain integrity check runs.
+        #[pallet::constant]
+        type IntegrityCheckInterval: Get<BlockNumberFor<Self>>;
+        /// Maximum number of article lineages checked per off-chain worker run.
+        #[pallet::constant]
+        type MaxIntegrityChecksPerRun: Get<u32>;
+        /// Transaction pool priority of unsigned integrity reports.
+        #[pallet::constant]
+        type IntegrityReportPriority: Get<TransactionPriority>;
+        /// Crypto used by off-chain workers to sign integrity reports.
+        type IntegrityAuthorityId: AppCrypto<Self::Public, <Self as SigningTypes>::Signature>;
+        /// Accounts allowed to report integrity checks, e.g. the current session's collators.
+        type IntegrityReporters: Contains<Self::AccountId>;
+        /// Origin that may register and remove integrity watchers.
+        type IntegrityWatcherOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin that may approve and revoke feeless publishers.
+        type FeelessApprovalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Number of blocks in a feeless anchoring era.
+        #[pallet::constant]
+        type FeelessEraLength: Get<BlockNumberFor<Self>>;
+        /// Maximum number of feeless anchors per approved publisher per era.
+        #[pallet::constant]
+        type MaxFeelessAnchorsPerEra: Get<u32>;
+        /// Transaction pool priority of feeless anchors.
+        #[pallet::constant]
+        type FeelessAnchorPriority: Get<TransactionPriority>;
+        /// Weight at the top of the `Normal` block space that only provenance calls may use, as
+        /// enforced by `CheckProvenanceSpace`.
+        #[pallet::constant]
+        type ProvenanceReservedWeight: Get<Weight>;
+        /// Anchors and updates per approved publisher per session that do not pay fees.
+        #[pallet::constant]
+        type FreeAnchorsPerSession: Get<u32>;
+        /// Index of the current session.
+        type CurrentSession: Get<u32>;
+        /// Signature publishers sign content hashes with, e.g. `MultiSignature`.
+        type Signature: Verify<Signer = Self::Signer> + Parameter + MaxEncodedLen;
+        /// Key type whose account `Signature` verifies against.
+        type Signer: IdentifyAccount<AccountId = Self::AccountId>;
+        /// `chainId` of the EIP-712 domain Ethereum wallets sign articles for.
+        #[pallet::constant]
+        type EthereumChainId: Get<u64>;
+        /// Whether native signatures of the legacy `<Bytes>content_hash</Bytes>` message are
+        /// still accepted besides signatures of the `ArticlePayload`.
+        #[pallet::constant]
+        type AllowLegacySignatures: Get<bool>;
+        /// Maximum depth of a batch inclusion proof accepted by `promote_batch_leaf`.
+        #[pallet::constant]
+        type MaxBatchProofLen: Get<u32>;
+        /// Origin that can purge any article lineage, e.g. for illegal content.
+        type PurgeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Index entries of a removed lineage cleared per `remove_article`, `purge_article` or
+        /// `continue_removal` call.
+        #[pallet::constant]
+        type MaxRemovalSteps: Get<u32>;
+        /// Maximum length of the text summary of a change passed to `update_article`.
+        #[pallet::constant]
+        type MaxChangeSummaryLen: Get<u32>;
+        /// Maximum number of co-authors on the byline of a lineage.
+        #[pallet::constant]
+        type MaxCoAuthors: Get<u32>;
         /// Weights.
         type WeightInfo: WeightInfo;
         /// Helper to create a signature to be benchmarked.
         #[cfg(feature = "runtime-benchmarks")]
-        type BenchmarkHelper: crate::BenchmarkHelper<MultiSignature, Self::AccountId>;
+        type BenchmarkHelper: crate::BenchmarkHelper<<Self as Config>::Signature, Self::AccountId>;
+    }
+
+    /// Signature publishers sign content hashes with.
+    pub type SignatureOf<T> = <T as Config>::Signature;
+
+    /// 65-byte recoverable secp256k1 signature (`r ‖ s ‖ v`), as produced by Ethereum wallets.
+    pub type EcdsaSignature = [u8; 65];
+
+    /// Signature by which a publisher authorizes an article version.
+    #[derive(
+        TypeInfo,
+        DecodeWithMemTracking,
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    pub enum PublisherSignature<Signature, AccountId> {
+        /// `Config::Signature` of the publisher account over the `ArticlePayload`.
+        Native(Signature),
+        /// `personal_sign` (EIP-191) of `Pallet::eip191_article_digest` by the publisher's
+        /// registered Ethereum address.
+        Eip191(EcdsaSignature),
+        /// `eth_signTypedData` (EIP-712) of `Pallet::eip712_article_digest` by the publisher's
+        /// registered Ethereum address.
+        Eip712(EcdsaSignature),
+        /// `Config::Signature` over the `ArticlePayload` by `key`, a signing key the publisher
+        /// registered with `add_signing_key`.
+        SigningKey {
+            key: AccountId,
+            signature: Signature,
+        },
+    }
+
+    impl<Signature, AccountId> From<Signature> for PublisherSignature<Signature, AccountId> {
+        fn from(signature: Signature) -> Self {
+            Self::Native(signature)
+        }
+    }
+
+    /// Publisher signature of an article version.
+    pub type PublisherSignatureOf<T> = PublisherSignature<
+        SignatureOf<T>,
+        <T as frame_system::Config>::AccountId
+    >;
+
+    /// Validity window of a publisher's signing key.
+    ///
+    /// The key may sign versions from `valid_from` until, excluding, `expires_at` or
+    /// `revoked_from`. Versions it signed at or after `revoked_from` are reported as signed with
+    /// a revoked key.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    pub struct SigningKeyInfo<BlockNumber> {
+        /// First block the key may sign at.
+        pub valid_from: BlockNumber,
+        /// Block from which the key may no longer sign, e.g. after a rotation.
+        pub expires_at: Option<BlockNumber>,
+        /// Block from which the key is considered compromised.
+        pub revoked_from: Option<BlockNumber>,
+    }
+
+    impl<BlockNumber: PartialOrd + Copy> SigningKeyInfo<BlockNumber> {
+        /// Whether the key may sign at block `at`.
+        pub fn is_valid_at(&self, at: BlockNumber) -> bool {
+            self.valid_from <= at &&
+                self.expires_at.is_none_or(|expires_at| at < expires_at) &&
+                !self.is_revoked_at(at)
+        }
+
+        /// Whether signatures made at block `at` fall under the key's revocation.
+        pub fn is_revoked_at(&self, at: BlockNumber) -> bool {
+            self.revoked_from.is_some_and(|revoked_from| revoked_from <= at)
+        }
+    }
+
+    /// Signing key window with this chain's block number.
+    pub type SigningKeyInfoOf<T> = SigningKeyInfo<BlockNumberFor<T>>;
+
+    /// Why an article lineage was removed.
+    #[derive(TypeInfo, Encode, Decode, Clone, Copy, PartialEq, Eq, MaxEncodedLen, Debug)]
+    pub enum RemovalReason {
+        /// Taken down by its publisher with `remove_article`.
+        Publisher,
+        /// Purged by `PurgeOrigin` with `purge_article`.
+        Purged,
+    }
+
+    /// What remains of a version of a removed lineage.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Tombstone<T: Config> {
+        /// Publisher the lineage belonged to.
+        pub publisher: T::AccountId,
+        /// Anchor of the removed lineage.
+        pub anchor: ContentHash,
+        /// Why it was removed.
+        pub reason: RemovalReason,
+        /// Block it was removed at.
+        pub removed_at: BlockNumberFor<T>,
+    }
+
+    /// What kind of change a version makes to its parent.
+    #[derive(
+        TypeInfo,
+        DecodeWithMemTracking,
+        Encode,
+        Decode,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    pub enum ChangeKind {
+        /// Typo, formatting or wording fix that leaves the reporting unchanged.
+        Minor,
+        /// New developments added, e.g. to a live blog.
+        Update,
+        /// Substantive correction of an error in the parent version.
+        Correction,
+        /// New headline, with the reporting unchanged.
+        Headline,
+    }
+
+    /// Publisher's note on what a version changed.
+    #[derive(
+        TypeInfo,
+        DecodeWithMemTracking,
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub enum ChangeSummary<T: Config> {
+        /// The summary itself.
+        Text(BoundedVec<u8, T::MaxChangeSummaryLen>),
+        /// Hash of a summary published elsewhere, e.g. a correction notice.
+        Hash(H256),
+    }
+
+    /// Change metadata of a version, relative to its parent.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct VersionChange<T: Config> {
+        /// Kind of change, as declared by the publisher.
+        pub kind: ChangeKind,
+        /// Optional summary of the change.
+        pub summary: Option<ChangeSummary<T>>,
+        /// Whether the title or canonical URL differ from the parent's, as recorded.
+        pub title_or_url_changed: bool,
+    }
+
+    /// Co-author credited on the byline of a lineage, with their share of its tips.
+    #[derive(
+        TypeInfo,
+        DecodeWithMemTracking,
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct CoAuthor<T: Config> {
+        /// Account receiving the share.
+        pub account: T::AccountId,
+        /// Share of every tip, rounded down.
+        pub share: Perbill,
+    }
+
+    /// Number and sum of the tips sent to a lineage or publisher in `Currency`.
+    #[derive(
+        TypeInfo,
+        Encode,
+        Decode,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    pub struct TipTally<Balance> {
+        pub tips: u32,
+        pub total: Balance,
+    }
+
+    /// Who signed a recorded version, as seen at the current block.
+    #[derive(TypeInfo, Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum SignatureStatus {
+        /// The publisher account or its registered Ethereum address.
+        Publisher,
+        /// A signing key of the publisher that has not been revoked for the version's block.
+        SigningKey,
+        /// A signing key revoked from the version's block or earlier.
+        RevokedSigningKey,
+    }
+
+    /// Encoding version of `ArticlePayload`.
+    pub const ARTICLE_PAYLOAD_VERSION: u8 = 1;
+
+    /// What a publisher signs to authorize an article version.
+    ///
+    /// Native signatures cover `<Bytes>` + the SCALE encoding + `</Bytes>` (see
+    /// `signing_message`), as wallet extensions wrap raw payloads that way. The genesis hash and
+    /// pallet name separate the signature from other chains and other instances of the pallet.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+    pub struct ArticlePayload<Hash, BlockNumber> {
+        /// `ARTICLE_PAYLOAD_VERSION`.
+        pub version: u8,
+        /// Genesis hash of the chain the version is recorded on.
+        pub genesis_hash: Hash,
+        /// Name of the pallet in the runtime.
+        pub pallet_name: Vec<u8>,
+        /// Hash of the version's content.
+        pub content_hash: ContentHash,
+        /// Version updated by this one, or the original anchor of a syndication.
+        pub previous: Option<ContentHash>,
+        /// NFT collection linkage.
+        pub collection_id: CollectionId,
+        /// NFT item linkage.
+        pub item_id: ItemId,
+        /// Title, empty while embargoed.
+        pub title: Vec<u8>,
+        /// Canonical URL, empty while embargoed.
+        pub canonical_url: Vec<u8>,
+        /// Hash algorithm of `content_hash`.
+        pub hash_algo: HashAlgo,
+        /// Word count of the version.
+        pub word_count: u32,
+        /// Last block a relayer may submit the version at; `None` for the publisher's own calls.
+        pub valid_until: Option<BlockNumber>,
+        /// Publisher's `PublisherNonces` entry for calls submitted by a third party; `None` for
+        /// the publisher's own calls.
+        pub nonce: Option<u64>,
+    }
+
+    impl<Hash: Encode, BlockNumber: Encode> ArticlePayload<Hash, BlockNumber> {
+        /// Bytes a native signature covers.
+        pub fn signing_message(&self) -> Vec<u8> {
+            let mut message = b"<Bytes>".to_vec();
+            message.extend(self.encode());
+            message.extend_from_slice(b"</Bytes>");
+            message
+        }
+
+        /// Bytes a legacy native signature covers: only the content hash.
+        pub fn legacy_signing_message(&self) -> Vec<u8> {
+            let mut message = b"<Bytes>".to_vec();
+            message.extend_from_slice(self.content_hash.as_bytes());
+            message.extend_from_slice(b"</Bytes>");
+            message
+        }
+    }
+
+    /// Signing payload of an article version on this chain.
+    pub type ArticlePayloadOf<T> = ArticlePayload<<T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+
+    /// What a publisher signs to anchor a batch of articles, with the same domain separation as
+    /// `ArticlePayload`.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+    pub struct BatchPayload<Hash, AccountId> {
+        /// `ARTICLE_PAYLOAD_VERSION`.
+        pub version: u8,
+        /// Genesis hash of the chain the batch is anchored on.
+        pub genesis_hash: Hash,
+        /// Name of the pallet in the runtime.
+        pub pallet_name: Vec<u8>,
+        /// Publisher anchoring the batch.
+        pub publisher: AccountId,
+        /// Root of the Merkle tree over the batch's `BatchLeaf` hashes.
+        pub merkle_root: H256,
+        /// Number of leaves in the batch.
+        pub count: u32,
+    }
+
+    impl<Hash: Encode, AccountId: Encode> BatchPayload<Hash, AccountId> {
+        /// Bytes a native signature covers: `<Bytes>` + the SCALE encoding of
+        /// `(b"news-batch", payload)` + `</Bytes>`, so it never doubles as an article signature.
+        pub fn signing_message(&self) -> Vec<u8> {
+            let mut message = b"<Bytes>".to_vec();
+            message.extend((b"news-batch", self).encode());
+            message.extend_from_slice(b"</Bytes>");
+            message
+        }
+    }
+
+    /// Signing payload of a batch on this chain.
+    pub type BatchPayloadOf<T> = BatchPayload<
+        <T as frame_system::Config>::Hash,
+        <T as frame_system::Config>::AccountId
+    >;
+
+    /// Leaf of an `anchor_batch` Merkle tree: the fields of the `ArticleRecord` it can be
+    /// promoted to. Its hash is `blake2_256(0x00 ++ SCALE encoding)`; see `BatchLeaf::hash`.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+    pub struct BatchLeaf<AccountId> {
+        /// Publisher of the batch, and of the lineage once promoted.
+        pub publisher: AccountId,
+        /// Hash of the article's content, its anchor once promoted.
+        pub content_hash: ContentHash,
+        /// NFT collection linkage.
+        pub collection_id: CollectionId,
+        /// NFT item linkage.
+        pub item_id: ItemId,
+        /// Title of the article.
+        pub title: Vec<u8>,
+        /// Canonical URL of the article.
+        pub canonical_url: Vec<u8>,
+        /// Hash algorithm of `content_hash`.
+        pub hash_algo: HashAlgo,
+        /// Word count of the article.
+        pub word_count: u32,
+    }
+
+    /// Batch leaf of a publisher on this chain.
+    pub type BatchLeafOf<T> = BatchLeaf<<T as frame_system::Config>::AccountId>;
+
+    /// A batch of articles anchored by its Merkle root.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct BatchRecord<T: Config> {
+        /// Number of leaves, as declared by the publisher.
+        pub count: u32,
+        /// Publisher signature over the `BatchPayload`.
+        pub signature: PublisherSignatureOf<T>,
+        /// Block the batch was anchored at.
+        pub anchored_at: BlockNumberFor<T>,
     }
 
     /// Bounded vector for storing a publisher's article **anchors** (first versions).
@@ -102,6 +943,37 @@ pub mod pallet {
         <T as Config>::MaxArticlesPerPublisher
     >;
 
+    /// Embargo requested when recording an article.
+    #[derive(
+        TypeInfo,
+        DecodeWithMemTracking,
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct EmbargoTerms<T: Config> {
+        /// Block from which the article may be revealed. The embargo is lifted at this block, but
+        /// the publisher still has to reveal the metadata.
+        pub release_at: BlockNumberFor<T>,
+        /// `Pallet::metadata_commitment` of the hidden title, canonical URL and salt.
+        pub metadata_commitment: H256,
+    }
+
+    /// Embargo state of an article whose metadata has not been revealed yet.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Embargo<T: Config> {
+        /// Terms as committed at record time.
+        pub terms: EmbargoTerms<T>,
+        /// Whether `release_at` has been reached and the embargo lifted.
+        pub lifted: bool,
+    }
+
     /// Record representing a single article version (including anchor).
     #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
     #[scale_info(skip_type_params(T))]
@@ -118,8 +990,8 @@ pub mod pallet {
         pub item_id: ItemId,
         /// Hash of this version's content (anchor for first version).
         pub content_hash: ContentHash,
-        /// Signature over the content hash (stored for off-chain verification; not verified on-chain).
-        pub signature: MultiSignature,
+        /// Publisher signature over the version's `ArticlePayload`.
+        pub signature: PublisherSignatureOf<T>,
         /// Hash algorithm used.
         pub hash_algo: HashAlgo,
         /// Word count of the article (this version).
@@ -131,7 +1003,213 @@ pub mod pallet {
         pub updates: u32,
     }
 
+    /// A reader's endorsement or flag of a single article version.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Endorsement<T: Config> {
+        /// Endorse or flag.
+        pub sentiment: Sentiment,
+        /// Deposit held from the reader, released on withdrawal.
+        pub deposit: BalanceOf<T>,
+        /// Block at which it was submitted.
+        pub at: BlockNumberFor<T>,
+    }
+
+    /// Fact-check verdict on a single article version.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct FactCheck<T: Config> {
+        pub verdict: FactCheckVerdict,
+        /// Block at which it was submitted.
+        pub at: BlockNumberFor<T>,
+    }
+
+    /// Endorsement and flag counters for a single article version.
+    #[derive(
+        TypeInfo,
+        Encode,
+        Decode,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    pub struct EndorsementTally {
+        pub endorsements: u32,
+        pub flags: u32,
+    }
+
+    /// A salted commitment to unpublished reporting.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct DraftCommitment<T: Config> {
+        /// Deposit held from the owner.
+        pub deposit: BalanceOf<T>,
+        /// Block at which the commitment was anchored.
+        pub committed_at: BlockNumberFor<T>,
+        /// Last block at which the commitment may be revealed.
+        pub expires_at: BlockNumberFor<T>,
+    }
+
+    /// Proof that a version's content was committed to before it was published.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PriorityProof<T: Config> {
+        /// The revealed commitment.
+        pub commitment: H256,
+        /// Block at which the commitment was anchored.
+        pub committed_at: BlockNumberFor<T>,
+    }
+
+    /// What the off-chain worker observed at an article's canonical URL.
+    #[derive(
+        TypeInfo,
+        DecodeWithMemTracking,
+        Encode,
+        Decode,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    pub enum IntegrityObservation {
+        /// The page was fetched and its body hashes to the anchored hash.
+        Matches,
+        /// The page was fetched, but its body hashes to `observed` instead of the anchored hash.
+        Changed {
+            observed: H256,
+        },
+        /// The page could not be fetched; `http_status` is set if the server answered.
+        Unreachable {
+            http_status: Option<u16>,
+        },
+    }
+
+    /// Integrity state of the page served at a version's canonical URL.
+    #[derive(
+        TypeInfo,
+        Encode,
+        Decode,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug,
+        Default
+    )]
+    pub enum IntegrityState {
+        /// No report was accepted for this version yet.
+        #[default]
+        Unchecked,
+        /// The live page matches the anchored version.
+        Matches,
+        /// The live page no longer matches the anchored version; its body hashes to `observed`.
+        Changed {
+            observed: H256,
+        },
+        /// The live page could not be fetched; `http_status` is set if the server answered.
+        Unreachable {
+            http_status: Option<u16>,
+        },
+    }
+
+    impl From<IntegrityObservation> for IntegrityState {
+        fn from(observation: IntegrityObservation) -> Self {
+            match observation {
+                IntegrityObservation::Matches => IntegrityState::Matches,
+                IntegrityObservation::Changed { observed } => IntegrityState::Changed { observed },
+                IntegrityObservation::Unreachable { http_status } => {
+                    IntegrityState::Unreachable { http_status }
+                }
+            }
+        }
+    }
+
+    /// Integrity state of a version, and the block of the check it was last updated from.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug, Default)]
+    pub struct IntegrityStatus<BlockNumber> {
+        /// Latest reported state.
+        pub state: IntegrityState,
+        /// Block at which the reported check ran; `None` while `Unchecked`.
+        pub last_checked: Option<BlockNumber>,
+    }
+
+    /// Report submitted by an off-chain worker, signed by the reporter's `public` key.
+    #[derive(
+        TypeInfo,
+        DecodeWithMemTracking,
+        Encode,
+        Decode,
+        Clone,
+        PartialEq,
+        Eq,
+        Debug
+    )]
+    pub struct IntegrityReport<Public, BlockNumber> {
+        /// Version whose canonical URL was checked.
+        pub content_hash: ContentHash,
+        /// What was observed.
+        pub observation: IntegrityObservation,
+        /// Block at which the off-chain worker ran the check.
+        pub checked_at: BlockNumber,
+        /// Key of the reporter that signed the report.
+        pub public: Public,
+    }
+
+    /// `IntegrityReport` of the runtime's signing types.
+    pub type IntegrityReportOf<T> = IntegrityReport<
+        <T as SigningTypes>::Public,
+        BlockNumberFor<T>
+    >;
+
+    impl<T: SigningTypes> SignedPayload<T> for IntegrityReport<T::Public, BlockNumberFor<T>> {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// A publisher's reputation, decayed up to `updated_at`.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ReputationScore<T: Config> {
+        /// Score as of `updated_at`.
+        pub score: i64,
+        /// Block at which `score` was last decayed and written.
+        pub updated_at: BlockNumberFor<T>,
+    }
+
+    /// A journalist's fee sponsorship.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Sponsorship<T: Config> {
+        /// Newsroom paying the journalist's fees.
+        pub sponsor: T::AccountId,
+        /// Fees the newsroom still pays for this journalist.
+        pub quota: BalanceOf<T>,
+    }
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit for endorsing or flagging an article version.
+        #[codec(index = 0)]
+        EndorsementDeposit,
+        /// Deposit for an unrevealed draft commitment.
+        #[codec(index = 1)]
+        DraftDeposit,
+    }
+
+    /// In-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Maps content hash (any version) to its record.
@@ -176,7 +1254,8 @@ pub mod pallet {
         OptionQuery
     >;
 
-    /// For each anchor, keep the ordered list of version hashes (anchor first).
+    /// For each anchor, keep the ordered list of the latest version hashes (anchor first until a
+    /// page is archived to `HistoryPages`).
     #[pallet::storage]
     pub type HistoryByAnchor<T: Config> = StorageMap<
         _,
@@ -186,6 +1265,29 @@ pub mod pallet {
         ValueQuery
     >;
 
+    /// Full pages of older version hashes per anchor, archived from `HistoryByAnchor`, page `0`
+    /// first.
+    #[pallet::storage]
+    pub type HistoryPages<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // anchor hash
+        Twox64Concat,
+        u32, // page index
+        BoundedVec<ContentHash, T::MaxHistoryLen>,
+        OptionQuery
+    >;
+
+    /// Number of archived `HistoryPages` per anchor.
+    #[pallet::storage]
+    pub type HistoryPageCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // anchor hash
+        u32,
+        ValueQuery
+    >;
+
     /// Updates count per anchor (latest version number for that lineage).
     #[pallet::storage]
     pub type ArticleUpdateCount<T: Config> = StorageMap<
@@ -196,177 +1298,2004 @@ pub mod pallet {
         ValueQuery
     >;
 
-    /// Events.
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// Emitted when a new anchor article is recorded.
-        ArticleRecorded {
-            publisher: T::AccountId,
-            content_hash: ContentHash, // also anchor
-        },
-        /// Emitted when an article's ownership is "verified" (publisher matches record).
-        ArticleVerified {
-            content_hash: ContentHash,
-            publisher: T::AccountId,
-            valid: bool,
-        },
-        /// Emitted on update (new version). `updates` is the new version count.
-        ArticleUpdated {
-            publisher: T::AccountId,
-            old_hash: ContentHash,
-            new_hash: ContentHash,
-            updates: u32,
-        },
-        /// Emitted whenever history timeline grows (append-only).
-        ArticleHistoryExtended {
-            anchor: ContentHash,
-            new_hash: ContentHash,
-            index: u32, // 0-based index in HistoryByAnchor[anchor]
-        },
-    }
-
-    /// Errors.
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Article with this content hash already exists.
-        ArticleAlreadyExists,
-        /// Article not found for the given hash.
-        ArticleNotFound,
-        /// Publisher's anchor list is full.
-        PublisherArticleListFull,
-        /// History too long for this anchor (MaxHistoryLen hit).
-        HistoryTooLong,
-        /// Caller is not the article's publisher / owner.
-        NotArticlePublisher,
-        /// Signature verification failed.
-        AccountIdNot32Bytes,
-        /// Signature verification failed.
-        SignatureInvalid,
-    }
+    /// Endorsements and flags per (version hash, reader).
+    #[pallet::storage]
+    pub type Endorsements<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContentHash,
+        Blake2_128Concat,
+        T::AccountId,
+        Endorsement<T>,
+        OptionQuery
+    >;
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Record a new article (first version).
-        ///
-        /// - `content_hash` is the **anchor hash**.
-        /// - **Verifies** the provided `signature` matches the extrinsic signer over `content_hash`.
-        /// - Initializes history with the anchor.
-        #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::record_article())]
-        pub fn record_article(
-            origin: OriginFor<T>,
-            content_hash: ContentHash,
-            collection_id: CollectionId,
-            item_id: ItemId,
-            title: BoundedVec<u8, T::MaxTitleLen>,
-            canonical_url: BoundedVec<u8, T::MaxUrlLen>,
-            signature: MultiSignature,
-            hash_algo: HashAlgo,
-            word_count: u32
-        ) -> DispatchResult {
-            let publisher = ensure_signed(origin)?;
+    /// Endorsement and flag counters per version hash.
+    #[pallet::storage]
+    pub type EndorsementTallies<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash,
+        EndorsementTally,
+        ValueQuery
+    >;
 
-            // Ensure content hash is unique
-            Self::ensure_content_hash_unique(&content_hash)?;
+    /// Fact-check verdict per version hash.
+    #[pallet::storage]
+    pub type FactCheckOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash,
+        FactCheck<T>,
+        OptionQuery
+    >;
 
-            // Verify signature
-            Self::verify_signature(&publisher, &content_hash, &signature)?;
+    /// Block at which a version was retracted by its publisher.
+    #[pallet::storage]
+    pub type RetractedAt<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash,
+        BlockNumberFor<T>,
+        OptionQuery
+    >;
 
-            // Create and store article record
-            let record = Self::create_article_record(
-                publisher.clone(),
-                content_hash,
-                collection_id,
-                item_id,
-                title,
-                canonical_url,
-                signature,
-                hash_algo,
-                word_count,
-                0 // Initial updates count
-            );
-            Self::store_new_article(record, publisher, content_hash, collection_id, item_id)?;
+    /// Reputation score per publisher (decayed lazily, see `ReputationScore`).
+    #[pallet::storage]
+    pub type PublisherReputation<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ReputationScore<T>,
+        OptionQuery
+    >;
 
-            Ok(())
-        }
+    /// For a republished anchor, the anchor of the article it was syndicated from.
+    #[pallet::storage]
+    pub type SyndicatedFrom<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // republished anchor
+        ContentHash, // original anchor
+        OptionQuery
+    >;
 
-        /// Update an existing article by adding a new version with a new `content_hash`.
-        ///
-        /// - Only the **original publisher** can update.
-        /// - Appends the new hash to the anchor's history and increments update counter.
-        #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::update_article())]
-        pub fn update_article(
-            origin: OriginFor<T>,
-            old_hash: ContentHash,
-            new_hash: ContentHash,
-            title: BoundedVec<u8, T::MaxTitleLen>,
-            canonical_url: BoundedVec<u8, T::MaxUrlLen>,
-            new_signature: MultiSignature,
-            hash_algo: HashAlgo,
-            new_word_count: u32
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+    /// For an original anchor, the anchors that directly republish it.
+    #[pallet::storage]
+    pub type SyndicatedTo<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // original anchor
+        BoundedVec<ContentHash, T::MaxSyndications>,
+        ValueQuery
+    >;
 
-            // Old record must exist and be owned by caller
-            let old = ArticleByHash::<T>::get(&old_hash).ok_or(Error::<T>::ArticleNotFound)?;
-            ensure!(old.publisher == who, Error::<T>::NotArticlePublisher);
+    /// For each version hash, the recorded version hashes it cites.
+    #[pallet::storage]
+    pub type CitationsOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // citing version
+        BoundedVec<ContentHash, T::MaxCitations>,
+        OptionQuery
+    >;
 
-            // New must be unique
-            ensure!(!ArticleByHash::<T>::contains_key(&new_hash), Error::<T>::ArticleAlreadyExists);
+    /// Reverse citation index: (cited version, citing version) -> ().
+    #[pallet::storage]
+    pub type CitedBy<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // cited version
+        Blake2_128Concat,
+        ContentHash, // citing version
+        (),
+        OptionQuery
+    >;
+
+    /// Embargo state per anchor, removed once the article is revealed.
+    #[pallet::storage]
+    pub type Embargoes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // anchor hash
+        Embargo<T>,
+        OptionQuery
+    >;
+
+    /// Anchors whose embargo is lifted, making them revealable, at a given block.
+    #[pallet::storage]
+    pub type ReleaseQueue<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<ContentHash, T::MaxReleasesPerBlock>,
+        ValueQuery
+    >;
+
+    /// Unrevealed draft commitments per owner. Keying by owner keeps anyone who copies a pending
+    /// `commit_draft` from taking the commitment first.
+    #[pallet::storage]
+    pub type DraftCommitments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        H256, // commitment
+        DraftCommitment<T>,
+        OptionQuery
+    >;
+
+    /// Revealed draft commitment per article version.
+    #[pallet::storage]
+    pub type PriorityOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // any version hash
+        PriorityProof<T>,
+        OptionQuery
+    >;
+
+    /// Live-page integrity status per version hash.
+    #[pallet::storage]
+    pub type IntegrityStatusOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash,
+        IntegrityStatus<BlockNumberFor<T>>,
+        ValueQuery
+    >;
+
+    /// Accounts registered to report integrity checks, in addition to `IntegrityReporters`.
+    #[pallet::storage]
+    pub type IntegrityWatchers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery
+    >;
+
+    /// Publishers approved to anchor articles through `record_article_unsigned`.
+    #[pallet::storage]
+    pub type FeelessPublishers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery
+    >;
+
+    /// Era index of a publisher's latest feeless anchor, and the number of feeless anchors
+    /// recorded in that era.
+    #[pallet::storage]
+    pub type FeelessAnchorsInEra<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (u32, u32),
+        ValueQuery
+    >;
+
+    /// Session index of a publisher's latest fee-free anchor or update, and the number of
+    /// fee-free calls in that session.
+    #[pallet::storage]
+    pub type FreeAnchorsInSession<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (u32, u32),
+        ValueQuery
+    >;
+
+    /// Remaining budget a newsroom spends on its journalists' fees.
+    #[pallet::storage]
+    pub type SponsorBudgets<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery
+    >;
+
+    /// Sponsorship of a journalist's fees.
+    #[pallet::storage]
+    pub type SponsoredJournalists<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Sponsorship<T>,
+        OptionQuery
+    >;
+
+    /// Ethereum address a publisher signs articles with from an EVM wallet.
+    #[pallet::storage]
+    pub type EthereumAddressOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        H160,
+        OptionQuery
+    >;
+
+    /// Publisher an Ethereum address is registered to.
+    #[pallet::storage]
+    pub type PublisherOfEthereumAddress<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H160,
+        T::AccountId,
+        OptionQuery
+    >;
+
+    /// Nonce the next signature a publisher hands to a relayer or submits unsigned must carry.
+    #[pallet::storage]
+    pub type PublisherNonces<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        ValueQuery
+    >;
+
+    /// Batches anchored with `anchor_batch`, by publisher and Merkle root.
+    #[pallet::storage]
+    pub type Batches<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        H256, // Merkle root
+        BatchRecord<T>,
+        OptionQuery
+    >;
+
+    /// Merkle root of the batch a version was promoted from with `promote_batch_leaf`, under the
+    /// version's publisher in `Batches`. Its `ArticleRecord.signature` is the batch signature.
+    #[pallet::storage]
+    pub type BatchOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash,
+        H256, // Merkle root
+        OptionQuery
+    >;
+
+    /// Tombstones of the versions of removed lineages. Their hashes cannot be recorded again.
+    #[pallet::storage]
+    pub type Tombstones<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash,
+        Tombstone<T>,
+        OptionQuery
+    >;
+
+    /// Versions of removed lineages whose per-version indices are still being cleared, by anchor.
+    #[pallet::storage]
+    pub type PendingRemovals<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // anchor hash
+        BoundedVec<ContentHash, T::MaxHistoryLen>,
+        OptionQuery
+    >;
+
+    /// Change metadata of every version recorded by `update_article`.
+    #[pallet::storage]
+    pub type VersionChanges<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // version hash
+        VersionChange<T>,
+        OptionQuery
+    >;
+
+    /// Versions marked `ChangeKind::Correction`, by anchor, with their index in the lineage.
+    #[pallet::storage]
+    pub type CorrectionsOf<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // anchor hash
+        Blake2_128Concat,
+        ContentHash, // version hash
+        u32,
+        OptionQuery
+    >;
+
+    /// Co-authors sharing the tips of a lineage with its publisher, by anchor.
+    #[pallet::storage]
+    pub type Bylines<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // anchor hash
+        BoundedVec<CoAuthor<T>, T::MaxCoAuthors>,
+        ValueQuery
+    >;
+
+    /// Tips sent in `Currency` to any version of a lineage, by anchor.
+    #[pallet::storage]
+    pub type TipsByAnchor<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ContentHash, // anchor hash
+        TipTally<BalanceOf<T>>,
+        ValueQuery
+    >;
+
+    /// Tips sent in `Currency` to the lineages of a publisher, co-author shares included.
+    #[pallet::storage]
+    pub type TipsByPublisher<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        TipTally<BalanceOf<T>>,
+        ValueQuery
+    >;
+
+    /// Signing keys of a publisher and their validity windows. Revoked keys are kept so that
+    /// versions they signed can still be reported as such.
+    #[pallet::storage]
+    pub type SigningKeys<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        SigningKeyInfoOf<T>,
+        OptionQuery
+    >;
+
+    /// Events.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Emitted when a new anchor article is recorded.
+        ArticleRecorded {
+            publisher: T::AccountId,
+            content_hash: ContentHash, // also anchor
+        },
+        /// Emitted when an article's ownership is "verified" (publisher matches record).
+        ArticleVerified {
+            content_hash: ContentHash,
+            publisher: T::AccountId,
+            valid: bool,
+        },
+        /// Emitted on update (new version). `updates` is the new version count.
+        ArticleUpdated {
+            publisher: T::AccountId,
+            old_hash: ContentHash,
+            new_hash: ContentHash,
+            updates: u32,
+            kind: ChangeKind,
+        },
+        /// Emitted whenever history timeline grows (append-only).
+        ArticleHistoryExtended {
+            anchor: ContentHash,
+            new_hash: ContentHash,
+            index: u32, // 0-based index in the lineage, across history pages
+        },
+        /// A reader endorsed or flagged an article version.
+        ArticleEndorsed {
+            content_hash: ContentHash,
+            who: T::AccountId,
+            sentiment: Sentiment,
+        },
+        /// A reader withdrew their endorsement or flag.
+        EndorsementWithdrawn {
+            content_hash: ContentHash,
+            who: T::AccountId,
+        },
+        /// A fact-check verdict was recorded for an article version.
+        FactCheckSubmitted {
+            content_hash: ContentHash,
+            verdict: FactCheckVerdict,
+        },
+        /// The publisher retracted an article version.
+        ArticleRetracted {
+            content_hash: ContentHash,
+            publisher: T::AccountId,
+        },
+        /// A publisher's reputation score changed.
+        ReputationUpdated {
+            publisher: T::AccountId,
+            score: i64,
+        },
+        /// An article was republished as a new anchor linked to its original.
+        ArticleSyndicated {
+            original_anchor: ContentHash,
+            new_anchor: ContentHash,
+            republisher: T::AccountId,
+        },
+        /// A version declared the versions it cites.
+        CitationsDeclared {
+            content_hash: ContentHash,
+            count: u32,
+        },
+        /// An article was recorded under embargo.
+        ArticleEmbargoed {
+            content_hash: ContentHash,
+            release_at: BlockNumberFor<T>,
+        },
+        /// The embargo of an article ended; it may now be revealed.
+        EmbargoLifted {
+            content_hash: ContentHash,
+        },
+        /// The publisher revealed the metadata of a formerly embargoed article.
+        ArticleRevealed {
+            content_hash: ContentHash,
+            publisher: T::AccountId,
+        },
+        /// A draft commitment was anchored.
+        DraftCommitted {
+            commitment: H256,
+            owner: T::AccountId,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// A draft commitment was revealed and linked to an article lineage.
+        DraftRevealed {
+            commitment: H256,
+            content_hash: ContentHash,
+            anchor: ContentHash,
+            committed_at: BlockNumberFor<T>,
+        },
+        /// An unrevealed draft commitment expired and was removed.
+        DraftExpired {
+            commitment: H256,
+            owner: T::AccountId,
+        },
+        /// A reporter observed a different integrity state for a version's canonical URL.
+        IntegrityStatusChanged {
+            content_hash: ContentHash,
+            previous: IntegrityState,
+            state: IntegrityState,
+            checked_at: BlockNumberFor<T>,
+            reporter: T::AccountId,
+        },
+        /// An account was registered as integrity watcher.
+        IntegrityWatcherAdded {
+            who: T::AccountId,
+        },
+        /// An integrity watcher was removed.
+        IntegrityWatcherRemoved {
+            who: T::AccountId,
+        },
+        /// A publisher was approved for feeless anchoring.
+        FeelessPublisherApproved {
+            publisher: T::AccountId,
+        },
+        /// A publisher's feeless anchoring approval was revoked.
+        FeelessPublisherRevoked {
+            publisher: T::AccountId,
+        },
+        /// A newsroom set its sponsorship budget.
+        SponsorshipBudgetSet {
+            sponsor: T::AccountId,
+            budget: BalanceOf<T>,
+        },
+        /// A newsroom sponsors a journalist's fees up to `quota`.
+        JournalistSponsored {
+            sponsor: T::AccountId,
+            journalist: T::AccountId,
+            quota: BalanceOf<T>,
+        },
+        /// A sponsorship ended.
+        SponsorshipRevoked {
+            sponsor: T::AccountId,
+            journalist: T::AccountId,
+        },
+        /// A newsroom paid `fee` for a journalist's transaction.
+        FeeSponsored {
+            sponsor: T::AccountId,
+            journalist: T::AccountId,
+            fee: BalanceOf<T>,
+        },
+        /// A relayer recorded an article signed by `publisher`.
+        ArticleRelayed {
+            content_hash: ContentHash,
+            publisher: T::AccountId,
+            relayer: T::AccountId,
+            valid_until: BlockNumberFor<T>,
+        },
+        /// A publisher registered the Ethereum address it signs articles with.
+        EthereumAddressRegistered {
+            publisher: T::AccountId,
+            address: H160,
+        },
+        /// A publisher unregistered its Ethereum address.
+        EthereumAddressUnregistered {
+            publisher: T::AccountId,
+            address: H160,
+        },
+        /// A publisher registered a signing key.
+        SigningKeyAdded {
+            publisher: T::AccountId,
+            key: T::AccountId,
+            valid_from: BlockNumberFor<T>,
+            expires_at: Option<BlockNumberFor<T>>,
+        },
+        /// A publisher replaced signing key `old` with `new`.
+        SigningKeyRotated {
+            publisher: T::AccountId,
+            old: T::AccountId,
+            new: T::AccountId,
+        },
+        /// A publisher revoked a signing key from block `from`.
+        SigningKeyRevoked {
+            publisher: T::AccountId,
+            key: T::AccountId,
+            from: BlockNumberFor<T>,
+        },
+        /// A publisher anchored a batch of `count` articles.
+        BatchAnchored {
+            publisher: T::AccountId,
+            merkle_root: H256,
+            count: u32,
+        },
+        /// A batch leaf was promoted to a full article lineage.
+        BatchLeafPromoted {
+            publisher: T::AccountId,
+            merkle_root: H256,
+            content_hash: ContentHash,
+        },
+        /// An article lineage was removed and tombstoned; its indices are cleared until
+        /// `ArticleRemovalCompleted`.
+        ArticleRemoved {
+            anchor: ContentHash,
+            publisher: T::AccountId,
+            reason: RemovalReason,
+        },
+        /// All indices of a removed lineage were cleared.
+        ArticleRemovalCompleted {
+            anchor: ContentHash,
+        },
+        /// A publisher set the co-authors on the byline of a lineage.
+        BylinesSet {
+            anchor: ContentHash,
+            co_authors: u32,
+        },
+        /// A reader tipped a lineage; `amount` of `asset` (`None`: `Currency`) was split between
+        /// the publisher and co-authors.
+        ArticleTipped {
+            anchor: ContentHash,
+            tipper: T::AccountId,
+            publisher: T::AccountId,
+            amount: BalanceOf<T>,
+            asset: Option<AssetIdOf<T>>,
+        },
+    }
+
+    /// Errors.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Article with this content hash already exists.
+        ArticleAlreadyExists,
+        /// Article not found for the given hash.
+        ArticleNotFound,
+        /// Publisher's anchor list is full.
+        PublisherArticleListFull,
+        /// A version did not fit in a history page (`MaxHistoryLen` is zero).
+        HistoryTooLong,
+        /// Caller is not the article's publisher / owner.
+        NotArticlePublisher,
+        /// No longer returned: signatures verify against any `AccountId`.
+        AccountIdNot32Bytes,
+        /// Signature verification failed.
+        SignatureInvalid,
+        /// Caller already endorsed or flagged this article version.
+        AlreadyEndorsed,
+        /// Caller has not endorsed or flagged this article version.
+        EndorsementNotFound,
+        /// Publishers cannot endorse or flag their own articles.
+        CannotEndorseOwnArticle,
+        /// Caller did not pass the proof-of-personhood check.
+        NotAPerson,
+        /// Article version has already been retracted.
+        AlreadyRetracted,
+        /// `SyndicationPolicy` does not allow the caller to republish this article.
+        SyndicationNotLicensed,
+        /// The original article has reached `MaxSyndications` republications.
+        TooManySyndications,
+        /// Citations have already been declared for this version.
+        CitationsAlreadyDeclared,
+        /// A cited hash is not a recorded article version.
+        CitedArticleNotFound,
+        /// A version cannot cite itself.
+        SelfCitation,
+        /// The same hash is cited more than once.
+        DuplicateCitation,
+        /// Embargo `release_at` must be in the future.
+        EmbargoReleaseInPast,
+        /// Title and URL must be empty while an article is embargoed.
+        EmbargoedMetadataNotEmpty,
+        /// Too many embargoes already end at the requested block.
+        ReleaseQueueFull,
+        /// Article is not under embargo.
+        NotEmbargoed,
+        /// Embargo has not ended yet.
+        EmbargoActive,
+        /// Revealed metadata does not match the embargo commitment.
+        CommitmentMismatch,
+        /// Article cannot be updated until its embargo ends and it is revealed.
+        ArticleEmbargoed,
+        /// Only the latest version of a lineage can be updated.
+        NotLatestVersion,
+        /// This draft commitment already exists.
+        DraftAlreadyCommitted,
+        /// No draft commitment found.
+        DraftNotFound,
+        /// The draft commitment has expired.
+        DraftExpired,
+        /// The draft commitment has not expired yet.
+        DraftNotExpired,
+        /// `content_hash` and `salt` do not open the commitment.
+        DraftCommitmentMismatch,
+        /// This version already has a revealed draft commitment.
+        PriorityAlreadyProven,
+        /// Integrity report is stale, from the future, for an unknown version, too frequent,
+        /// signed by an unauthorized reporter or carries an invalid signature.
+        InvalidIntegrityReport,
+        /// Account is already an integrity watcher.
+        AlreadyIntegrityWatcher,
+        /// Account is not an integrity watcher.
+        NotIntegrityWatcher,
+        /// Publisher is already approved for feeless anchoring.
+        AlreadyFeelessPublisher,
+        /// Publisher is not approved for feeless anchoring.
+        NotFeelessPublisher,
+        /// Publisher used up its feeless anchors for the current era.
+        FeelessQuotaExhausted,
+        /// Journalist is sponsored by another newsroom.
+        SponsoredByAnotherNewsroom,
+        /// Journalist is not sponsored.
+        NotSponsored,
+        /// Caller is neither the sponsoring newsroom nor the journalist.
+        NotSponsorshipParty,
+        /// Registration proof was not signed by the Ethereum address.
+        EthereumProofInvalid,
+        /// Ethereum address is registered to another publisher.
+        EthereumAddressInUse,
+        /// Publisher has no registered Ethereum address.
+        NoEthereumAddress,
+        /// Relayed signature is past its `valid_until` block.
+        SignatureExpired,
+        /// Signature carries a nonce the publisher already used.
+        SignatureReused,
+        /// Signature carries a nonce beyond the publisher's next one.
+        FutureSignatureNonce,
+        /// Key is already registered for the publisher.
+        SigningKeyExists,
+        /// Key is not registered for the publisher.
+        UnknownSigningKey,
+        /// Key did not sign `Pallet::signing_key_message` for the publisher.
+        SigningKeyProofInvalid,
+        /// Key would expire before it becomes valid.
+        InvalidSigningKeyWindow,
+        /// Key is expired, revoked or not valid yet.
+        SigningKeyNotValid,
+        /// The publisher already anchored a batch with this Merkle root.
+        BatchAlreadyAnchored,
+        /// A batch must contain at least one article.
+        EmptyBatch,
+        /// The publisher has no batch anchored with this Merkle root.
+        UnknownBatch,
+        /// The proof does not lead from the leaf to the batch's Merkle root.
+        InclusionProofInvalid,
+        /// Hash is a later version, not the anchor of its lineage.
+        NotAnAnchor,
+        /// Content hash belongs to a removed article.
+        ArticleRemoved,
+        /// No removal of this anchor is pending.
+        NoPendingRemoval,
+        /// Co-author shares add up to more than the whole tip.
+        SharesExceedTip,
+        /// A co-author is listed twice or is the publisher.
+        InvalidCoAuthor,
+        /// Tips must be non-zero.
+        ZeroTip,
+        /// Publishers cannot tip their own articles.
+        CannotTipOwnArticle,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let released = ReleaseQueue::<T>::take(now);
+            for content_hash in released.iter() {
+                Embargoes::<T>::mutate(content_hash, |maybe_embargo| {
+                    if let Some(embargo) = maybe_embargo {
+                        embargo.lifted = true;
+                    }
+                });
+                Self::deposit_event(Event::EmbargoLifted { content_hash: *content_hash });
+            }
+            T::WeightInfo::lift_embargoes(released.len() as u32)
+        }
+
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            let interval = T::IntegrityCheckInterval::get();
+            if interval.is_zero() || !(now % interval).is_zero() {
+                return;
+            }
+            Self::run_integrity_checks(now);
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match call {
+                Call::submit_integrity_report { report, signature } => {
+                    Self::validate_integrity_report(report, signature)
+                }
+                Call::record_article_unsigned {
+                    publisher,
+                    content_hash,
+                    collection_id,
+                    item_id,
+                    title,
+                    canonical_url,
+                    signature,
+                    hash_algo,
+                    word_count,
+                    nonce,
+                } => {
+                    let payload = ArticlePayload {
+                        nonce: Some(*nonce),
+                        ..Self::article_payload(
+                            *content_hash,
+                            None,
+                            *collection_id,
+                            *item_id,
+                            title,
+                            canonical_url,
+                            *hash_algo,
+                            *word_count
+                        )
+                    };
+                    Self::validate_feeless_anchor(publisher, &payload, signature)
+                }
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Record a new article (first version).
+        ///
+        /// - `content_hash` is the **anchor hash**.
+        /// - **Verifies** the provided `signature` by the extrinsic signer over the `ArticlePayload`.
+        /// - Initializes history with the anchor.
+        /// - With `embargo`, `title` and `canonical_url` must be empty; they are revealed later
+        ///   through `reveal_article`.
+        /// - Fee-free for approved publishers within `FreeAnchorsPerSession`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(
+            T::WeightInfo::record_article()
+                .saturating_add(Pallet::<T>::free_anchor_weight())
+                .saturating_add(
+                    if embargo.is_some() {
+                        T::DbWeight::get().reads_writes(1, 2)
+                    } else {
+                        Weight::zero()
+                    }
+                )
+        )]
+        pub fn record_article(
+            origin: OriginFor<T>,
+            content_hash: ContentHash,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            title: BoundedVec<u8, T::MaxTitleLen>,
+            canonical_url: BoundedVec<u8, T::MaxUrlLen>,
+            signature: PublisherSignatureOf<T>,
+            hash_algo: HashAlgo,
+            word_count: u32,
+            embargo: Option<EmbargoTerms<T>>
+        ) -> DispatchResultWithPostInfo {
+            let publisher = ensure_signed(origin)?;
+
+            Self::do_record_article(
+                publisher.clone(),
+                content_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                signature,
+                hash_algo,
+                word_count,
+                embargo,
+                None,
+                None
+            )?;
+
+            Ok(Self::use_free_anchor(&publisher).into())
+        }
+
+        /// Update an existing article by adding a new version with a new `content_hash`.
+        ///
+        /// - Only the **original publisher** can update, and only the latest version of a lineage.
+        /// - `new_signature` must sign the `ArticlePayload` of `new_hash`, with `old_hash` as
+        ///   `previous`.
+        /// - Appends the new hash to the anchor's history and increments update counter.
+        /// - Records `change_kind` and `change_summary` in `VersionChanges`; corrections are also
+        ///   indexed in `CorrectionsOf`.
+        /// - Fee-free for approved publishers within `FreeAnchorsPerSession`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(
+            T::WeightInfo::update_article().saturating_add(Pallet::<T>::free_anchor_weight())
+        )]
+        pub fn update_article(
+            origin: OriginFor<T>,
+            old_hash: ContentHash,
+            new_hash: ContentHash,
+            title: BoundedVec<u8, T::MaxTitleLen>,
+            canonical_url: BoundedVec<u8, T::MaxUrlLen>,
+            new_signature: PublisherSignatureOf<T>,
+            hash_algo: HashAlgo,
+            new_word_count: u32,
+            change_kind: ChangeKind,
+            change_summary: Option<ChangeSummary<T>>
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // Old record must exist and be owned by caller
+            let old = ArticleByHash::<T>::get(&old_hash).ok_or(Error::<T>::ArticleNotFound)?;
+            ensure!(old.publisher == who, Error::<T>::NotArticlePublisher);
+            ensure!(!Embargoes::<T>::contains_key(&old_hash), Error::<T>::ArticleEmbargoed);
+
+            // New must be unique
+            Self::ensure_content_hash_unique(&new_hash)?;
+
+            let payload = Self::article_payload(
+                new_hash,
+                Some(old_hash),
+                old.collection_id,
+                old.item_id,
+                &title,
+                &canonical_url,
+                hash_algo,
+                new_word_count
+            );
+            Self::verify_signature(&who, &payload, &new_signature)?;
+
+            // Determine anchor
+            let anchor = AnchorOf::<T>::get(&old_hash).unwrap_or(old_hash);
+            // Archived versions of a removed lineage stay recorded until their page is cleared
+            ensure!(!Tombstones::<T>::contains_key(&anchor), Error::<T>::ArticleRemoved);
+            // Lineages do not branch, so `updates` keeps counting the versions before this one
+            ensure!(
+                HistoryByAnchor::<T>::get(&anchor).last() == Some(&old_hash),
+                Error::<T>::NotLatestVersion
+            );
+
+            // Compute new updates = previous + 1
+            let updates = old.updates.saturating_add(1);
+
+            // Materialize new version record
+            let change = VersionChange::<T> {
+                kind: change_kind,
+                summary: change_summary,
+                title_or_url_changed: title != old.title || canonical_url != old.canonical_url,
+            };
+            let new_rec = ArticleRecord::<T> {
+                title,
+                canonical_url,
+                publisher: who.clone(),
+                collection_id: old.collection_id,
+                item_id: old.item_id,
+                content_hash: new_hash,
+                signature: new_signature,
+                hash_algo,
+                word_count: new_word_count,
+                last_updated_at: <frame_system::Pallet<T>>::block_number(),
+                updates,
+            };
+
+            // Insert new record
+            ArticleByHash::<T>::insert(&new_hash, &new_rec);
+
+            // Map new version to anchor
+            AnchorOf::<T>::insert(&new_hash, anchor);
+
+            // Append to history, archiving the current page once it is full
+            let index = Self::append_to_history(anchor, new_hash)?;
+
+            // Change metadata, with corrections indexed per lineage
+            VersionChanges::<T>::insert(&new_hash, change);
+            if change_kind == ChangeKind::Correction {
+                CorrectionsOf::<T>::insert(&anchor, &new_hash, index);
+            }
+
+            // Update lineage counter
+            ArticleUpdateCount::<T>::insert(&anchor, updates);
+
+            // (Optional policy) Update RootByItem to latest; comment out to keep pointing at anchor
+            RootByItem::<T>::insert(old.collection_id, old.item_id, new_hash);
+
+            // Events
+            Self::deposit_event(Event::ArticleUpdated {
+                publisher: who.clone(),
+                old_hash,
+                new_hash,
+                updates,
+                kind: change_kind,
+            });
+            Self::deposit_event(Event::ArticleHistoryExtended {
+                anchor,
+                new_hash,
+                index,
+            });
+
+            Ok(Self::use_free_anchor(&who).into())
+        }
+
+        /// Endorse or flag an article version.
+        ///
+        /// - Caller must pass `PersonhoodVerifier` and must not be the article's publisher.
+        /// - Holds `EndorsementDeposit` until the endorsement is withdrawn.
+        /// - Adjusts the publisher's reputation score.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::endorse_article())]
+        pub fn endorse_article(
+            origin: OriginFor<T>,
+            content_hash: ContentHash,
+            sentiment: Sentiment
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(T::PersonhoodVerifier::contains(&who), Error::<T>::NotAPerson);
+
+            let article = ArticleByHash::<T>::get(&content_hash).ok_or(Error::<T>::ArticleNotFound)?;
+            ensure!(article.publisher != who, Error::<T>::CannotEndorseOwnArticle);
+            ensure!(
+                !Endorsements::<T>::contains_key(&content_hash, &who),
+                Error::<T>::AlreadyEndorsed
+            );
+
+            let deposit = T::EndorsementDeposit::get();
+            T::Currency::hold(&HoldReason::EndorsementDeposit.into(), &who, deposit)?;
+
+            Endorsements::<T>::insert(&content_hash, &who, Endorsement::<T> {
+                sentiment,
+                deposit,
+                at: <frame_system::Pallet<T>>::block_number(),
+            });
+            EndorsementTallies::<T>::mutate(&content_hash, |tally| {
+                match sentiment {
+                    Sentiment::Endorse => {
+                        tally.endorsements = tally.endorsements.saturating_add(1);
+                    }
+                    Sentiment::Flag => {
+                        tally.flags = tally.flags.saturating_add(1);
+                    }
+                }
+            });
+            Self::adjust_reputation(&article.publisher, sentiment.points());
+
+            Self::deposit_event(Event::ArticleEndorsed {
+                content_hash,
+                who,
+                sentiment,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw an endorsement or flag and release its deposit.
+        ///
+        /// The reputation points it contributed are reverted, decayed since it was submitted.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::withdraw_endorsement())]
+        pub fn withdraw_endorsement(
+            origin: OriginFor<T>,
+            content_hash: ContentHash
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let endorsement = Endorsements::<T>
+                ::take(&content_hash, &who)
+                .ok_or(Error::<T>::EndorsementNotFound)?;
+
+            T::Currency::release(
+                &HoldReason::EndorsementDeposit.into(),
+                &who,
+                endorsement.deposit,
+                Precision::BestEffort
+            )?;
+
+            EndorsementTallies::<T>::mutate(&content_hash, |tally| {
+                match endorsement.sentiment {
+                    Sentiment::Endorse => {
+                        tally.endorsements = tally.endorsements.saturating_sub(1);
+                    }
+                    Sentiment::Flag => {
+                        tally.flags = tally.flags.saturating_sub(1);
+                    }
+                }
+            });
+            if let Some(article) = ArticleByHash::<T>::get(&content_hash) {
+                Self::adjust_reputation(
+                    &article.publisher,
+                    Self::decay(endorsement.sentiment.points(), endorsement.at).saturating_neg()
+                );
+            }
+
+            Self::deposit_event(Event::EndorsementWithdrawn { content_hash, who });
+
+            Ok(())
+        }
+
+        /// Record (or replace) the fact-check verdict for an article version.
+        ///
+        /// Replacing a verdict reverts the points of the previous one, decayed since it was
+        /// submitted.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::submit_fact_check())]
+        pub fn submit_fact_check(
+            origin: OriginFor<T>,
+            content_hash: ContentHash,
+            verdict: FactCheckVerdict
+        ) -> DispatchResult {
+            T::FactCheckOrigin::ensure_origin(origin)?;
+            let article = ArticleByHash::<T>::get(&content_hash).ok_or(Error::<T>::ArticleNotFound)?;
+
+            let previous = FactCheckOf::<T>
+                ::get(&content_hash)
+                .map_or(0, |check| Self::decay(check.verdict.points(), check.at));
+            FactCheckOf::<T>::insert(&content_hash, FactCheck::<T> {
+                verdict,
+                at: <frame_system::Pallet<T>>::block_number(),
+            });
+            Self::adjust_reputation(&article.publisher, verdict.points().saturating_sub(previous));
+
+            Self::deposit_event(Event::FactCheckSubmitted { content_hash, verdict });
+
+            Ok(())
+        }
+
+        /// Retract an article version.
+        ///
+        /// - Only the **original publisher** can retract.
+        /// - The record is kept; the retraction counts against the publisher's reputation.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::retract_article())]
+        pub fn retract_article(origin: OriginFor<T>, content_hash: ContentHash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let article = ArticleByHash::<T>::get(&content_hash).ok_or(Error::<T>::ArticleNotFound)?;
+            ensure!(article.publisher == who, Error::<T>::NotArticlePublisher);
+            ensure!(!RetractedAt::<T>::contains_key(&content_hash), Error::<T>::AlreadyRetracted);
+
+            RetractedAt::<T>::insert(&content_hash, <frame_system::Pallet<T>>::block_number());
+            Self::adjust_reputation(&who, points::RETRACTION);
+
+            Self::deposit_event(Event::ArticleRetracted {
+                content_hash,
+                publisher: who,
+            });
+
+            Ok(())
+        }
+
+        /// Republish an existing article as a new anchor owned by the caller.
+        ///
+        /// - `original_anchor` may be any version of the original article; it is resolved to its anchor.
+        /// - `new_hash` becomes the anchor of the republished lineage and must be signed by the caller.
+        /// - The original publisher's permission is checked through `SyndicationPolicy`.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::record_syndication())]
+        pub fn record_syndication(
+            origin: OriginFor<T>,
+            original_anchor: ContentHash,
+            new_hash: ContentHash,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            title: BoundedVec<u8, T::MaxTitleLen>,
+            canonical_url: BoundedVec<u8, T::MaxUrlLen>,
+            signature: PublisherSignatureOf<T>,
+            hash_algo: HashAlgo,
+            word_count: u32
+        ) -> DispatchResult {
+            let republisher = ensure_signed(origin)?;
+
+            // Resolve the original lineage
+            let original_anchor = AnchorOf::<T>
+                ::get(&original_anchor)
+                .ok_or(Error::<T>::ArticleNotFound)?;
+            let original = ArticleByHash::<T>
+                ::get(&original_anchor)
+                .ok_or(Error::<T>::ArticleNotFound)?;
+            ensure!(
+                T::SyndicationPolicy::can_syndicate(&original_anchor, &original.publisher, &republisher),
+                Error::<T>::SyndicationNotLicensed
+            );
+
+            Self::ensure_content_hash_unique(&new_hash)?;
+            let payload = Self::article_payload(
+                new_hash,
+                Some(original_anchor),
+                collection_id,
+                item_id,
+                &title,
+                &canonical_url,
+                hash_algo,
+                word_count
+            );
+            Self::verify_signature(&republisher, &payload, &signature)?;
+
+            let record = Self::create_article_record(
+                republisher.clone(),
+                new_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                signature,
+                hash_algo,
+                word_count,
+                0
+            );
+            Self::store_new_article(record, republisher.clone(), new_hash, collection_id, item_id)?;
+
+            // Link both directions
+            SyndicatedTo::<T>::try_mutate(&original_anchor, |list| {
+                list.try_push(new_hash).map_err(|_| Error::<T>::TooManySyndications)
+            })?;
+            SyndicatedFrom::<T>::insert(&new_hash, original_anchor);
+
+            Self::deposit_event(Event::ArticleSyndicated {
+                original_anchor,
+                new_anchor: new_hash,
+                republisher,
+            });
+
+            Ok(())
+        }
+
+        /// Declare the article versions cited by `content_hash`.
+        ///
+        /// - Only the **original publisher** can declare, and only once per version.
+        /// - Every cited hash must exist in `ArticleByHash`.
+        /// - Populates the reverse `CitedBy` index.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::declare_citations(citations.len() as u32))]
+        pub fn declare_citations(
+            origin: OriginFor<T>,
+            content_hash: ContentHash,
+            citations: BoundedVec<ContentHash, T::MaxCitations>
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let article = ArticleByHash::<T>::get(&content_hash).ok_or(Error::<T>::ArticleNotFound)?;
+            ensure!(article.publisher == who, Error::<T>::NotArticlePublisher);
+            ensure!(
+                !CitationsOf::<T>::contains_key(&content_hash),
+                Error::<T>::CitationsAlreadyDeclared
+            );
+
+            for (i, cited) in citations.iter().enumerate() {
+                ensure!(*cited != content_hash, Error::<T>::SelfCitation);
+                ensure!(!citations[..i].contains(cited), Error::<T>::DuplicateCitation);
+                ensure!(ArticleByHash::<T>::contains_key(cited), Error::<T>::CitedArticleNotFound);
+            }
+
+            for cited in citations.iter() {
+                CitedBy::<T>::insert(cited, &content_hash, ());
+            }
+            let count = citations.len() as u32;
+            CitationsOf::<T>::insert(&content_hash, citations);
+
+            Self::deposit_event(Event::CitationsDeclared { content_hash, count });
+
+            Ok(())
+        }
+
+        /// Reveal the title and canonical URL of an embargoed article.
+        ///
+        /// - Only the **original publisher** can reveal, once `release_at` has been reached.
+        /// - `title`, `canonical_url` and `salt` must match the embargo's `metadata_commitment`.
+        /// - `signature` must sign the `ArticlePayload` of the revealed record, and replaces the
+        ///   signature over the empty metadata, so the stored signature always covers the stored
+        ///   metadata.
+        /// - `last_updated_at` is kept, so the record still proves when the hash was committed.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::reveal_article())]
+        pub fn reveal_article(
+            origin: OriginFor<T>,
+            content_hash: ContentHash,
+            title: BoundedVec<u8, T::MaxTitleLen>,
+            canonical_url: BoundedVec<u8, T::MaxUrlLen>,
+            salt: H256,
+            signature: PublisherSignatureOf<T>
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let embargo = Embargoes::<T>::get(&content_hash).ok_or(Error::<T>::NotEmbargoed)?;
+            let mut article = ArticleByHash::<T>
+                ::get(&content_hash)
+                .ok_or(Error::<T>::ArticleNotFound)?;
+            ensure!(article.publisher == who, Error::<T>::NotArticlePublisher);
+            ensure!(
+                embargo.lifted ||
+                    <frame_system::Pallet<T>>::block_number() >= embargo.terms.release_at,
+                Error::<T>::EmbargoActive
+            );
+            ensure!(
+                Self::metadata_commitment(&title, &canonical_url, &salt) ==
+                    embargo.terms.metadata_commitment,
+                Error::<T>::CommitmentMismatch
+            );
+            let payload = Self::article_payload(
+                content_hash,
+                None,
+                article.collection_id,
+                article.item_id,
+                &title,
+                &canonical_url,
+                article.hash_algo,
+                article.word_count
+            );
+            Self::verify_signature(&who, &payload, &signature)?;
+
+            article.title = title;
+            article.canonical_url = canonical_url;
+            article.signature = signature;
+            ArticleByHash::<T>::insert(&content_hash, article);
+            Embargoes::<T>::remove(&content_hash);
+
+            Self::deposit_event(Event::ArticleRevealed { content_hash, publisher: who });
+
+            Ok(())
+        }
+
+        /// Anchor a salted commitment to unpublished notes or a draft.
+        ///
+        /// - `commitment` is `Pallet::draft_commitment(caller, content_hash, salt)`; nothing else is
+        ///   revealed.
+        /// - Holds `DraftDeposit` until the commitment is revealed or removed.
+        /// - Must be revealed within `DraftLifetime` blocks.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::commit_draft())]
+        pub fn commit_draft(origin: OriginFor<T>, commitment: H256) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                !DraftCommitments::<T>::contains_key(&who, &commitment),
+                Error::<T>::DraftAlreadyCommitted
+            );
+
+            let deposit = T::DraftDeposit::get();
+            T::Currency::hold(&HoldReason::DraftDeposit.into(), &who, deposit)?;
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let expires_at = now.saturating_add(T::DraftLifetime::get());
+            DraftCommitments::<T>::insert(&who, &commitment, DraftCommitment::<T> {
+                deposit,
+                committed_at: now,
+                expires_at,
+            });
+
+            Self::deposit_event(Event::DraftCommitted { commitment, owner: who, expires_at });
+
+            Ok(())
+        }
+
+        /// Reveal a draft commitment and link it to a recorded article version.
+        ///
+        /// - Only the committer can reveal, before the commitment expires.
+        /// - `content_hash` must be a version published by the caller; the proof is stored in
+        ///   `PriorityOf` and reachable from the lineage through `HistoryByAnchor`.
+        /// - Releases the deposit.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::reveal_draft())]
+        pub fn reveal_draft(
+            origin: OriginFor<T>,
+            commitment: H256,
+            salt: H256,
+            content_hash: ContentHash
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let draft = DraftCommitments::<T>
+                ::get(&who, &commitment)
+                .ok_or(Error::<T>::DraftNotFound)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() <= draft.expires_at,
+                Error::<T>::DraftExpired
+            );
+            ensure!(
+                Self::draft_commitment(&who, &content_hash, &salt) == commitment,
+                Error::<T>::DraftCommitmentMismatch
+            );
+
+            let article = ArticleByHash::<T>::get(&content_hash).ok_or(Error::<T>::ArticleNotFound)?;
+            ensure!(article.publisher == who, Error::<T>::NotArticlePublisher);
+            ensure!(!PriorityOf::<T>::contains_key(&content_hash), Error::<T>::PriorityAlreadyProven);
+            let anchor = AnchorOf::<T>::get(&content_hash).unwrap_or(content_hash);
+
+            T::Currency::release(
+                &HoldReason::DraftDeposit.into(),
+                &who,
+                draft.deposit,
+                Precision::BestEffort
+            )?;
+            DraftCommitments::<T>::remove(&who, &commitment);
+            PriorityOf::<T>::insert(&content_hash, PriorityProof::<T> {
+                commitment,
+                committed_at: draft.committed_at,
+            });
+
+            Self::deposit_event(Event::DraftRevealed {
+                commitment,
+                content_hash,
+                anchor,
+                committed_at: draft.committed_at,
+            });
 
-            // Determine anchor
-            let anchor = AnchorOf::<T>::get(&old_hash).unwrap_or(old_hash);
+            Ok(())
+        }
 
-            // Compute new updates = previous + 1
-            let updates = old.updates.saturating_add(1);
+        /// Remove an expired, unrevealed draft commitment and refund its owner.
+        ///
+        /// Callable by anyone once `expires_at` has passed.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::remove_expired_draft())]
+        pub fn remove_expired_draft(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+            commitment: H256
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            let draft = DraftCommitments::<T>
+                ::get(&owner, &commitment)
+                .ok_or(Error::<T>::DraftNotFound)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() > draft.expires_at,
+                Error::<T>::DraftNotExpired
+            );
 
-            // Materialize new version record
-            let new_rec = ArticleRecord::<T> {
+            T::Currency::release(
+                &HoldReason::DraftDeposit.into(),
+                &owner,
+                draft.deposit,
+                Precision::BestEffort
+            )?;
+            DraftCommitments::<T>::remove(&owner, &commitment);
+
+            Self::deposit_event(Event::DraftExpired { commitment, owner });
+
+            Ok(())
+        }
+
+        /// Report, from an off-chain worker, whether a canonical URL still serves the anchored
+        /// version.
+        ///
+        /// - Unsigned; `signature` by `report.public` is checked by `ValidateUnsigned` and again on
+        ///   dispatch.
+        /// - The reporter must be in `IntegrityReporters` or a registered watcher.
+        /// - Accepted at most once per version per `IntegrityCheckInterval`.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::submit_integrity_report())]
+        pub fn submit_integrity_report(
+            origin: OriginFor<T>,
+            report: IntegrityReportOf<T>,
+            signature: <T as SigningTypes>::Signature
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            Self::ensure_report_acceptable(&report).map_err(|_| Error::<T>::InvalidIntegrityReport)?;
+            ensure!(
+                SignedPayload::<T>::verify::<T::IntegrityAuthorityId>(&report, signature),
+                Error::<T>::InvalidIntegrityReport
+            );
+
+            let state = IntegrityState::from(report.observation);
+            let previous = IntegrityStatusOf::<T>::mutate(&report.content_hash, |status| {
+                let previous = status.state;
+                status.state = state;
+                status.last_checked = Some(report.checked_at);
+                previous
+            });
+
+            if previous != state {
+                Self::deposit_event(Event::IntegrityStatusChanged {
+                    content_hash: report.content_hash,
+                    previous,
+                    state,
+                    checked_at: report.checked_at,
+                    reporter: report.public.into_account(),
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Register `who` as integrity watcher, allowed to report integrity checks.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::add_integrity_watcher())]
+        pub fn add_integrity_watcher(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::IntegrityWatcherOrigin::ensure_origin(origin)?;
+            ensure!(!IntegrityWatchers::<T>::contains_key(&who), Error::<T>::AlreadyIntegrityWatcher);
+
+            IntegrityWatchers::<T>::insert(&who, ());
+
+            Self::deposit_event(Event::IntegrityWatcherAdded { who });
+
+            Ok(())
+        }
+
+        /// Remove a registered integrity watcher.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::remove_integrity_watcher())]
+        pub fn remove_integrity_watcher(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::IntegrityWatcherOrigin::ensure_origin(origin)?;
+            ensure!(IntegrityWatchers::<T>::contains_key(&who), Error::<T>::NotIntegrityWatcher);
+
+            IntegrityWatchers::<T>::remove(&who);
+
+            Self::deposit_event(Event::IntegrityWatcherRemoved { who });
+
+            Ok(())
+        }
+
+        /// Feeless `record_article` for an approved publisher, submitted as an unsigned
+        /// transaction.
+        ///
+        /// - `signature` by `publisher` over the `ArticlePayload` with `nonce` authorizes the call;
+        ///   it is checked by `ValidateUnsigned` and again on dispatch.
+        /// - `nonce` must be the publisher's next `PublisherNonces` entry.
+        /// - At most `MaxFeelessAnchorsPerEra` per publisher per `FeelessEraLength` blocks.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::record_article_unsigned())]
+        pub fn record_article_unsigned(
+            origin: OriginFor<T>,
+            publisher: T::AccountId,
+            content_hash: ContentHash,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            title: BoundedVec<u8, T::MaxTitleLen>,
+            canonical_url: BoundedVec<u8, T::MaxUrlLen>,
+            signature: PublisherSignatureOf<T>,
+            hash_algo: HashAlgo,
+            word_count: u32,
+            nonce: u64
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            ensure!(FeelessPublishers::<T>::contains_key(&publisher), Error::<T>::NotFeelessPublisher);
+
+            let (era, used) = Self::feeless_anchors_used(&publisher);
+            ensure!(used < T::MaxFeelessAnchorsPerEra::get(), Error::<T>::FeelessQuotaExhausted);
+            FeelessAnchorsInEra::<T>::insert(&publisher, (era, used.saturating_add(1)));
+
+            Self::do_record_article(
+                publisher,
+                content_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                signature,
+                hash_algo,
+                word_count,
+                None,
+                None,
+                Some(nonce)
+            )
+        }
+
+        /// Approve `publisher` for feeless anchoring through `record_article_unsigned`.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::approve_feeless_publisher())]
+        pub fn approve_feeless_publisher(
+            origin: OriginFor<T>,
+            publisher: T::AccountId
+        ) -> DispatchResult {
+            T::FeelessApprovalOrigin::ensure_origin(origin)?;
+            ensure!(
+                !FeelessPublishers::<T>::contains_key(&publisher),
+                Error::<T>::AlreadyFeelessPublisher
+            );
+
+            FeelessPublishers::<T>::insert(&publisher, ());
+
+            Self::deposit_event(Event::FeelessPublisherApproved { publisher });
+
+            Ok(())
+        }
+
+        /// Revoke a publisher's feeless anchoring approval.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::revoke_feeless_publisher())]
+        pub fn revoke_feeless_publisher(
+            origin: OriginFor<T>,
+            publisher: T::AccountId
+        ) -> DispatchResult {
+            T::FeelessApprovalOrigin::ensure_origin(origin)?;
+            ensure!(FeelessPublishers::<T>::contains_key(&publisher), Error::<T>::NotFeelessPublisher);
+
+            FeelessPublishers::<T>::remove(&publisher);
+            FeelessAnchorsInEra::<T>::remove(&publisher);
+
+            Self::deposit_event(Event::FeelessPublisherRevoked { publisher });
+
+            Ok(())
+        }
+
+        /// Set the total amount of fees the caller pays for the journalists it sponsors.
+        ///
+        /// Fees are paid from the caller's free balance; the budget only caps them.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::set_sponsorship_budget())]
+        pub fn set_sponsorship_budget(origin: OriginFor<T>, budget: BalanceOf<T>) -> DispatchResult {
+            let sponsor = ensure_signed(origin)?;
+
+            SponsorBudgets::<T>::insert(&sponsor, budget);
+
+            Self::deposit_event(Event::SponsorshipBudgetSet { sponsor, budget });
+
+            Ok(())
+        }
+
+        /// Sponsor the fees of `journalist`'s `News` calls, up to `quota`.
+        ///
+        /// - Calling it again for the same journalist replaces the quota.
+        /// - A journalist has at most one sponsor.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::sponsor_journalist())]
+        pub fn sponsor_journalist(
+            origin: OriginFor<T>,
+            journalist: T::AccountId,
+            quota: BalanceOf<T>
+        ) -> DispatchResult {
+            let sponsor = ensure_signed(origin)?;
+            if let Some(existing) = SponsoredJournalists::<T>::get(&journalist) {
+                ensure!(existing.sponsor == sponsor, Error::<T>::SponsoredByAnotherNewsroom);
+            }
+
+            SponsoredJournalists::<T>::insert(&journalist, Sponsorship {
+                sponsor: sponsor.clone(),
+                quota,
+            });
+
+            Self::deposit_event(Event::JournalistSponsored { sponsor, journalist, quota });
+
+            Ok(())
+        }
+
+        /// End the sponsorship of `journalist`. Callable by the newsroom or the journalist.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::revoke_sponsorship())]
+        pub fn revoke_sponsorship(origin: OriginFor<T>, journalist: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let sponsorship = SponsoredJournalists::<T>
+                ::get(&journalist)
+                .ok_or(Error::<T>::NotSponsored)?;
+            ensure!(
+                who == sponsorship.sponsor || who == journalist,
+                Error::<T>::NotSponsorshipParty
+            );
+
+            SponsoredJournalists::<T>::remove(&journalist);
+
+            Self::deposit_event(Event::SponsorshipRevoked {
+                sponsor: sponsorship.sponsor,
+                journalist,
+            });
+
+            Ok(())
+        }
+
+        /// Register the Ethereum address the caller signs articles with from an EVM wallet.
+        ///
+        /// - `proof` is the `personal_sign` of `Pallet::eip191_registration_digest` for the
+        ///   caller by `address`.
+        /// - Replaces the caller's previous address; an address belongs to one publisher.
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::register_ethereum_address())]
+        pub fn register_ethereum_address(
+            origin: OriginFor<T>,
+            address: H160,
+            proof: EcdsaSignature
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            let digest = Self::eip191_registration_digest(&publisher);
+            ensure!(
+                crate::ethereum::recover_address(&proof, &digest) == Some(address),
+                Error::<T>::EthereumProofInvalid
+            );
+            if let Some(owner) = PublisherOfEthereumAddress::<T>::get(address) {
+                ensure!(owner == publisher, Error::<T>::EthereumAddressInUse);
+            }
+
+            if let Some(previous) = EthereumAddressOf::<T>::get(&publisher) {
+                PublisherOfEthereumAddress::<T>::remove(previous);
+            }
+            EthereumAddressOf::<T>::insert(&publisher, address);
+            PublisherOfEthereumAddress::<T>::insert(address, &publisher);
+
+            Self::deposit_event(Event::EthereumAddressRegistered { publisher, address });
+
+            Ok(())
+        }
+
+        /// Unregister the caller's Ethereum address. Articles it signed stay recorded.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::unregister_ethereum_address())]
+        pub fn unregister_ethereum_address(origin: OriginFor<T>) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            let address = EthereumAddressOf::<T>
+                ::take(&publisher)
+                .ok_or(Error::<T>::NoEthereumAddress)?;
+            PublisherOfEthereumAddress::<T>::remove(address);
+
+            Self::deposit_event(Event::EthereumAddressUnregistered { publisher, address });
+
+            Ok(())
+        }
+
+        /// Record a new article for `publisher`, submitted and paid for by any relayer.
+        ///
+        /// - `signature` by `publisher` must sign the `ArticlePayload` with `valid_until` set, so
+        ///   a signature handed to a relayer cannot be submitted after that block.
+        /// - `nonce` must be the publisher's next `PublisherNonces` entry, so the signature cannot
+        ///   be submitted twice.
+        /// - Legacy signatures are not accepted.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::record_article_for())]
+        pub fn record_article_for(
+            origin: OriginFor<T>,
+            publisher: T::AccountId,
+            content_hash: ContentHash,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            title: BoundedVec<u8, T::MaxTitleLen>,
+            canonical_url: BoundedVec<u8, T::MaxUrlLen>,
+            signature: PublisherSignatureOf<T>,
+            hash_algo: HashAlgo,
+            word_count: u32,
+            valid_until: BlockNumberFor<T>,
+            nonce: u64
+        ) -> DispatchResult {
+            let relayer = ensure_signed(origin)?;
+
+            Self::do_record_article(
+                publisher.clone(),
+                content_hash,
+                collection_id,
+                item_id,
                 title,
                 canonical_url,
+                signature,
+                hash_algo,
+                word_count,
+                None,
+                Some(valid_until),
+                Some(nonce)
+            )?;
+
+            Self::deposit_event(Event::ArticleRelayed { content_hash, publisher, relayer, valid_until });
+
+            Ok(())
+        }
+
+        /// Register `key` as a signing key of the caller, valid from `valid_from` until,
+        /// excluding, `expires_at`.
+        ///
+        /// - `proof` must be a `Config::Signature` by `key` over `signing_key_message` of the
+        ///   caller, so that nobody can claim another account's signatures.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::add_signing_key())]
+        pub fn add_signing_key(
+            origin: OriginFor<T>,
+            key: T::AccountId,
+            valid_from: BlockNumberFor<T>,
+            expires_at: Option<BlockNumberFor<T>>,
+            proof: SignatureOf<T>
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            ensure!(
+                expires_at.is_none_or(|expires_at| valid_from < expires_at),
+                Error::<T>::InvalidSigningKeyWindow
+            );
+
+            Self::insert_signing_key(&publisher, &key, valid_from, expires_at, &proof)?;
+
+            Self::deposit_event(Event::SigningKeyAdded { publisher, key, valid_from, expires_at });
+
+            Ok(())
+        }
+
+        /// Replace the caller's signing key `old` with `new`: `old` expires at the current block
+        /// and `new` is valid from it, without expiry.
+        ///
+        /// - `proof` as in `add_signing_key`, by `new`.
+        /// - Versions `old` signed before stay valid; use `revoke_signing_key` if it leaked.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::rotate_signing_key())]
+        pub fn rotate_signing_key(
+            origin: OriginFor<T>,
+            old: T::AccountId,
+            new: T::AccountId,
+            proof: SignatureOf<T>
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            let mut old_info = SigningKeys::<T>
+                ::get(&publisher, &old)
+                .ok_or(Error::<T>::UnknownSigningKey)?;
+            ensure!(old_info.is_valid_at(now), Error::<T>::SigningKeyNotValid);
+
+            Self::insert_signing_key(&publisher, &new, now, None, &proof)?;
+            old_info.expires_at = Some(now);
+            SigningKeys::<T>::insert(&publisher, &old, old_info);
+
+            Self::deposit_event(Event::SigningKeyRotated { publisher, old, new });
+
+            Ok(())
+        }
+
+        /// Revoke the caller's signing key `key` from block `from`, which may lie in the past:
+        /// versions it signed from then on are reported as `SignatureStatus::RevokedSigningKey`.
+        /// A revocation can only be moved to an earlier block.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::revoke_signing_key())]
+        pub fn revoke_signing_key(
+            origin: OriginFor<T>,
+            key: T::AccountId,
+            from: BlockNumberFor<T>
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            let from = SigningKeys::<T>::try_mutate(&publisher, &key, |maybe_info| {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::UnknownSigningKey)?;
+                let from = info.revoked_from.map_or(from, |revoked_from| revoked_from.min(from));
+                info.revoked_from = Some(from);
+                Ok::<_, DispatchError>(from)
+            })?;
+
+            Self::deposit_event(Event::SigningKeyRevoked { publisher, key, from });
+
+            Ok(())
+        }
+
+        /// Anchor a batch of articles by the root of a Merkle tree over their `BatchLeaf`
+        /// hashes (see `batch_merkle_root`), in a single record.
+        ///
+        /// - `signature` by the caller must sign the `BatchPayload`; legacy signatures are not
+        ///   accepted.
+        /// - Leaves can later be promoted to full lineages with `promote_batch_leaf`.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::anchor_batch())]
+        pub fn anchor_batch(
+            origin: OriginFor<T>,
+            merkle_root: H256,
+            count: u32,
+            signature: PublisherSignatureOf<T>
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            ensure!(count > 0, Error::<T>::EmptyBatch);
+            ensure!(
+                !Batches::<T>::contains_key(&publisher, merkle_root),
+                Error::<T>::BatchAlreadyAnchored
+            );
+
+            let payload = Self::batch_payload(publisher.clone(), merkle_root, count);
+            Self::verify_batch_signature(&publisher, &payload, &signature)?;
+            Batches::<T>::insert(&publisher, merkle_root, BatchRecord {
+                count,
+                signature,
+                anchored_at: <frame_system::Pallet<T>>::block_number(),
+            });
+
+            Self::deposit_event(Event::BatchAnchored { publisher, merkle_root, count });
+
+            Ok(())
+        }
+
+        /// Promote a leaf of a batch the caller anchored to a full article lineage owned by the
+        /// caller, as if recorded with `record_article`.
+        ///
+        /// - The leaf's publisher is the caller.
+        /// - `proof` lists the sibling hashes from the leaf up to `merkle_root`.
+        /// - The new `ArticleRecord` carries the batch signature; `BatchOf` links it to the batch.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::promote_batch_leaf(proof.len() as u32))]
+        pub fn promote_batch_leaf(
+            origin: OriginFor<T>,
+            merkle_root: H256,
+            content_hash: ContentHash,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            title: BoundedVec<u8, T::MaxTitleLen>,
+            canonical_url: BoundedVec<u8, T::MaxUrlLen>,
+            hash_algo: HashAlgo,
+            word_count: u32,
+            proof: BoundedVec<H256, T::MaxBatchProofLen>
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let batch = Batches::<T>::get(&who, merkle_root).ok_or(Error::<T>::UnknownBatch)?;
+            Self::ensure_content_hash_unique(&content_hash)?;
+
+            let leaf = BatchLeaf {
                 publisher: who.clone(),
-                collection_id: old.collection_id,
-                item_id: old.item_id,
-                content_hash: new_hash,
-                signature: new_signature, // stored; off-chain verify only
+                content_hash,
+                collection_id,
+                item_id,
+                title: title.to_vec(),
+                canonical_url: canonical_url.to_vec(),
                 hash_algo,
-                word_count: new_word_count,
-                last_updated_at: <frame_system::Pallet<T>>::block_number(),
-                updates,
+                word_count,
             };
+            ensure!(
+                crate::batch::root_from_proof(leaf.hash(), &proof) == merkle_root,
+                Error::<T>::InclusionProofInvalid
+            );
+
+            let record = Self::create_article_record(
+                who.clone(),
+                content_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                batch.signature,
+                hash_algo,
+                word_count,
+                0 // Initial updates count
+            );
+            Self::store_new_article(record, who.clone(), content_hash, collection_id, item_id)?;
+            BatchOf::<T>::insert(content_hash, merkle_root);
+
+            Self::deposit_event(Event::BatchLeafPromoted { publisher: who, merkle_root, content_hash });
+
+            Ok(())
+        }
+
+        /// Remove the caller's article lineage `anchor`, e.g. for a GDPR takedown.
+        ///
+        /// - Every version's record is deleted at once and replaced by a `Tombstone`, so its hash
+        ///   can never be recorded again.
+        /// - Per-version indices (endorsements, citations, fact checks, ...) are cleared up to
+        ///   `MaxRemovalSteps` entries per call, followed by what `OnArticleRemoved` stores about
+        ///   the lineage; anyone can finish with `continue_removal`. Endorsement deposits are
+        ///   returned to the readers.
+        #[pallet::call_index(29)]
+        #[pallet::weight(
+            T::WeightInfo::remove_article().saturating_add(
+                T::WeightInfo::continue_removal(T::MaxRemovalSteps::get())
+            )
+        )]
+        pub fn remove_article(origin: OriginFor<T>, anchor: ContentHash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let article = ArticleByHash::<T>::get(&anchor).ok_or(Error::<T>::ArticleNotFound)?;
+            ensure!(article.publisher == who, Error::<T>::NotArticlePublisher);
+
+            Self::start_removal(anchor, who, RemovalReason::Publisher)
+        }
+
+        /// Remove any article lineage `anchor`, e.g. for illegal content. Same as
+        /// `remove_article`, by `PurgeOrigin`.
+        #[pallet::call_index(30)]
+        #[pallet::weight(
+            T::WeightInfo::remove_article().saturating_add(
+                T::WeightInfo::continue_removal(T::MaxRemovalSteps::get())
+            )
+        )]
+        pub fn purge_article(origin: OriginFor<T>, anchor: ContentHash) -> DispatchResult {
+            T::PurgeOrigin::ensure_origin(origin)?;
+            let article = ArticleByHash::<T>::get(&anchor).ok_or(Error::<T>::ArticleNotFound)?;
+
+            Self::start_removal(anchor, article.publisher, RemovalReason::Purged)
+        }
+
+        /// Clear up to `MaxRemovalSteps` further index entries of the removed lineage `anchor`.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::continue_removal(T::MaxRemovalSteps::get()))]
+        pub fn continue_removal(origin: OriginFor<T>, anchor: ContentHash) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(PendingRemovals::<T>::contains_key(&anchor), Error::<T>::NoPendingRemoval);
+
+            Self::clear_removed_versions(anchor);
+
+            Ok(())
+        }
 
-            // Insert new record
-            ArticleByHash::<T>::insert(&new_hash, &new_rec);
+        /// Set the co-authors credited on the byline of the caller's lineage `anchor`, replacing
+        /// any previous ones. Each receives its `share` of later tips; the publisher keeps the
+        /// rest. An empty list leaves every tip to the publisher.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::set_bylines(co_authors.len() as u32))]
+        pub fn set_bylines(
+            origin: OriginFor<T>,
+            anchor: ContentHash,
+            co_authors: BoundedVec<CoAuthor<T>, T::MaxCoAuthors>
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let article = ArticleByHash::<T>::get(&anchor).ok_or(Error::<T>::ArticleNotFound)?;
+            ensure!(article.publisher == who, Error::<T>::NotArticlePublisher);
+            ensure!(AnchorOf::<T>::get(&anchor) == Some(anchor), Error::<T>::NotAnAnchor);
 
-            // Map new version to anchor
-            AnchorOf::<T>::insert(&new_hash, anchor);
+            let mut shares = 0u32;
+            for (i, co_author) in co_authors.iter().enumerate() {
+                ensure!(
+                    co_author.account != who &&
+                        !co_authors[..i].iter().any(|other| other.account == co_author.account),
+                    Error::<T>::InvalidCoAuthor
+                );
+                shares = shares.saturating_add(co_author.share.deconstruct());
+            }
+            ensure!(shares <= Perbill::ACCURACY, Error::<T>::SharesExceedTip);
 
-            // Append to history (bounded)
-            let index = HistoryByAnchor::<T>::try_mutate(anchor, |timeline| {
-                let idx = timeline.len() as u32;
-                timeline.try_push(new_hash).map_err(|_| Error::<T>::HistoryTooLong)?;
-                Ok::<u32, Error<T>>(idx)
-            })?;
+            let count = co_authors.len() as u32;
+            if co_authors.is_empty() {
+                Bylines::<T>::remove(&anchor);
+            } else {
+                Bylines::<T>::insert(&anchor, co_authors);
+            }
 
-            // Update lineage counter
-            ArticleUpdateCount::<T>::insert(&anchor, updates);
+            Self::deposit_event(Event::BylinesSet { anchor, co_authors: count });
 
-            // (Optional policy) Update RootByItem to latest; comment out to keep pointing at anchor
-            RootByItem::<T>::insert(old.collection_id, old.item_id, new_hash);
+            Ok(())
+        }
 
-            // Events
-            Self::deposit_event(Event::ArticleUpdated {
-                publisher: who.clone(),
-                old_hash,
-                new_hash,
-                updates,
-            });
-            Self::deposit_event(Event::ArticleHistoryExtended {
+        /// Tip the publisher of the version `content_hash`, splitting `amount` of `asset` (`None`:
+        /// `Currency`) with the co-authors on the byline of its lineage.
+        ///
+        /// A tip in `Currency` counts towards `TipsByAnchor` of the lineage and `TipsByPublisher`
+        /// of its publisher. Each transfer keeps the caller's account alive.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::tip_article(T::MaxCoAuthors::get()))]
+        pub fn tip_article(
+            origin: OriginFor<T>,
+            content_hash: ContentHash,
+            amount: BalanceOf<T>,
+            asset: Option<AssetIdOf<T>>
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::ZeroTip);
+            let article = ArticleByHash::<T>::get(&content_hash).ok_or(Error::<T>::ArticleNotFound)?;
+            ensure!(article.publisher != who, Error::<T>::CannotTipOwnArticle);
+            let anchor = AnchorOf::<T>::get(&content_hash).unwrap_or(content_hash);
+
+            let transfer = |to: &T::AccountId, value: BalanceOf<T>| -> DispatchResult {
+                match &asset {
+                    Some(asset) => {
+                        <T::Assets as fungibles::Mutate<_>>::transfer(
+                            asset.clone(),
+                            &who,
+                            to,
+                            value,
+                            Preservation::Preserve
+                        )?;
+                    }
+                    None => {
+                        T::Currency::transfer(&who, to, value, Preservation::Preserve)?;
+                    }
+                }
+                Ok(())
+            };
+            let mut remainder = amount;
+            for co_author in Bylines::<T>::get(&anchor) {
+                let share = co_author.share.mul_floor(amount);
+                if !share.is_zero() {
+                    transfer(&co_author.account, share)?;
+                    remainder = remainder.saturating_sub(share);
+                }
+            }
+            if !remainder.is_zero() {
+                transfer(&article.publisher, remainder)?;
+            }
+
+            if asset.is_none() {
+                let add_tip = |tally: &mut TipTally<BalanceOf<T>>| {
+                    tally.tips = tally.tips.saturating_add(1);
+                    tally.total = tally.total.saturating_add(amount);
+                };
+                TipsByAnchor::<T>::mutate(&anchor, add_tip);
+                TipsByPublisher::<T>::mutate(&article.publisher, add_tip);
+            }
+
+            Self::deposit_event(Event::ArticleTipped {
                 anchor,
-                new_hash,
-                index,
+                tipper: who,
+                publisher: article.publisher,
+                amount,
+                asset,
             });
 
             Ok(())
@@ -375,37 +3304,858 @@ pub mod pallet {
 
     // Helper functions outside the dispatch section
     impl<T: Config> Pallet<T> {
+        /// Current reputation of `publisher`, with decay applied up to the current block.
+        pub fn reputation_of(publisher: &T::AccountId) -> i64 {
+            PublisherReputation::<T>
+                ::get(publisher)
+                .map_or(0, |rep| Self::decay(rep.score, rep.updated_at))
+        }
+
+        /// Anchor count and current reputation of `publisher`.
+        pub fn publisher_summary(publisher: &T::AccountId) -> PublisherSummary {
+            PublisherSummary {
+                articles: ArticlesByPublisher::<T>::decode_len(publisher).unwrap_or(0) as u32,
+                reputation: Self::reputation_of(publisher),
+            }
+        }
+
+        /// Walks `SyndicatedFrom` upstream from `anchor`, returning the chain of original anchors
+        /// (closest first). At most `max_depth` links are followed.
+        pub fn syndication_sources(anchor: ContentHash, max_depth: u32) -> Vec<ContentHash> {
+            let mut sources = Vec::new();
+            let mut current = anchor;
+            while (sources.len() as u32) < max_depth {
+                match SyndicatedFrom::<T>::get(&current) {
+                    Some(source) => {
+                        sources.push(source);
+                        current = source;
+                    }
+                    None => {
+                        break;
+                    }
+                }
+            }
+            sources
+        }
+
+        /// Collects every anchor that republishes `anchor`, directly or transitively, breadth
+        /// first. At most `max_results` anchors are returned.
+        pub fn syndication_reach(anchor: ContentHash, max_results: u32) -> Vec<ContentHash> {
+            let mut reach = Vec::new();
+            let mut cursor = 0;
+            let mut current = anchor;
+            loop {
+                for republished in SyndicatedTo::<T>::get(&current) {
+                    if (reach.len() as u32) >= max_results {
+                        return reach;
+                    }
+                    reach.push(republished);
+                }
+                match reach.get(cursor) {
+                    Some(next) => {
+                        current = *next;
+                        cursor += 1;
+                    }
+                    None => {
+                        return reach;
+                    }
+                }
+            }
+        }
+
+        /// Commitment to an embargoed article's metadata: `blake2_256((title, canonical_url, salt))`.
+        pub fn metadata_commitment(title: &[u8], canonical_url: &[u8], salt: &H256) -> H256 {
+            H256(blake2_256(&(title, canonical_url, salt).encode()))
+        }
+
+        /// Checks that an integrity report is fresh, refers to a recorded version, comes from an
+        /// authorized reporter and respects the per-version rate limit. The signature is checked
+        /// separately by `ValidateUnsigned`.
+        pub(crate) fn ensure_report_acceptable(
+            report: &IntegrityReportOf<T>
+        ) -> Result<(), TransactionValidityError> {
+            let now = <frame_system::Pallet<T>>::block_number();
+            let interval = T::IntegrityCheckInterval::get();
+            if report.checked_at > now {
+                return Err(InvalidTransaction::Future.into());
+            }
+            if now.saturating_sub(report.checked_at) > interval {
+                return Err(InvalidTransaction::Stale.into());
+            }
+            if !ArticleByHash::<T>::contains_key(&report.content_hash) {
+                return Err(InvalidTransaction::Custom(INVALID_REPORT_UNKNOWN_ARTICLE).into());
+            }
+            if !Self::is_integrity_reporter(&report.public.clone().into_account()) {
+                return Err(InvalidTransaction::Custom(INVALID_REPORT_UNAUTHORIZED).into());
+            }
+            if let Some(last) = IntegrityStatusOf::<T>::get(&report.content_hash).last_checked {
+                if now < last.saturating_add(interval) {
+                    return Err(InvalidTransaction::Stale.into());
+                }
+            }
+            Ok(())
+        }
+
+        /// Pool validity of an integrity report: acceptable and signed by `report.public`.
+        fn validate_integrity_report(
+            report: &IntegrityReportOf<T>,
+            signature: &<T as SigningTypes>::Signature
+        ) -> TransactionValidity {
+            Self::ensure_report_acceptable(report)?;
+            let signature_valid = SignedPayload::<T>::verify::<T::IntegrityAuthorityId>(
+                report,
+                signature.clone()
+            );
+            if !signature_valid {
+                return InvalidTransaction::BadProof.into();
+            }
+
+            ValidTransaction::with_tag_prefix("NewsIntegrityReport")
+                .priority(T::IntegrityReportPriority::get())
+                .and_provides(report.content_hash)
+                .longevity(T::IntegrityCheckInterval::get().saturated_into::<u64>().max(1))
+                .propagate(true)
+                .build()
+        }
+
+        /// Pool validity of a feeless anchor.
+        ///
+        /// The `(publisher, era, used)` tag lets each publisher have a single feeless anchor in
+        /// the pool at a time, and the transaction expires with the era it was counted against.
+        /// Anchors with a used nonce are stale, those with a later one are not yet valid.
+        fn validate_feeless_anchor(
+            publisher: &T::AccountId,
+            payload: &ArticlePayloadOf<T>,
+            signature: &PublisherSignatureOf<T>
+        ) -> TransactionValidity {
+            let content_hash = &payload.content_hash;
+            if !FeelessPublishers::<T>::contains_key(publisher) {
+                return InvalidTransaction::Custom(INVALID_ANCHOR_NOT_APPROVED).into();
+            }
+            let (era, used) = Self::feeless_anchors_used(publisher);
+            if used >= T::MaxFeelessAnchorsPerEra::get() {
+                return InvalidTransaction::Custom(INVALID_ANCHOR_QUOTA_EXHAUSTED).into();
+            }
+            if ArticleByHash::<T>::contains_key(content_hash) {
+                return InvalidTransaction::Stale.into();
+            }
+            let next_nonce = PublisherNonces::<T>::get(publisher);
+            match payload.nonce {
+                Some(nonce) if nonce < next_nonce => {
+                    return InvalidTransaction::Stale.into();
+                }
+                Some(nonce) if nonce > next_nonce => {
+                    return InvalidTransaction::Future.into();
+                }
+                _ => {}
+            }
+            if Self::verify_signature(publisher, payload, signature).is_err() {
+                return InvalidTransaction::BadProof.into();
+            }
+
+            let era_length = T::FeelessEraLength::get().max(One::one());
+            let now = <frame_system::Pallet<T>>::block_number();
+            let remaining = era_length.saturating_sub(now % era_length);
+
+            ValidTransaction::with_tag_prefix("NewsFeelessAnchor")
+                .priority(T::FeelessAnchorPriority::get())
+                .and_provides(content_hash)
+                .and_provides((publisher, era, used))
+                .longevity(remaining.saturated_into::<u64>().max(1))
+                .propagate(true)
+                .build()
+        }
+
+        /// Weight of `use_free_anchor`, which `record_article` and `update_article` add to their
+        /// benchmarked weight: it reads `FeelessPublishers`, the `CurrentSession` index and
+        /// `FreeAnchorsInSession`, and writes the latter.
+        pub fn free_anchor_weight() -> Weight {
+            T::DbWeight::get().reads_writes(3, 1)
+        }
+
+        /// Consumes one of `publisher`'s fee-free anchors for the current session, if it is an
+        /// approved publisher with any left.
+        fn use_free_anchor(publisher: &T::AccountId) -> Pays {
+            if !FeelessPublishers::<T>::contains_key(publisher) {
+                return Pays::Yes;
+            }
+            let session = T::CurrentSession::get();
+            let used = match FreeAnchorsInSession::<T>::get(publisher) {
+                (last_session, used) if last_session == session => used,
+                _ => 0,
+            };
+            if used >= T::FreeAnchorsPerSession::get() {
+                return Pays::Yes;
+            }
+            FreeAnchorsInSession::<T>::insert(publisher, (session, used.saturating_add(1)));
+            Pays::No
+        }
+
+        /// Current feeless era index and the number of feeless anchors `publisher` recorded in it.
+        pub fn feeless_anchors_used(publisher: &T::AccountId) -> (u32, u32) {
+            let era_length = T::FeelessEraLength::get().max(One::one());
+            let era = (<frame_system::Pallet<T>>::block_number() / era_length).saturated_into::<u32>();
+            match FeelessAnchorsInEra::<T>::get(publisher) {
+                (last_era, used) if last_era == era => (era, used),
+                _ => (era, 0),
+            }
+        }
+
+        /// Newsroom that pays `fee` for `journalist`, if both its budget and the journalist's quota
+        /// cover it.
+        pub fn sponsor_for(journalist: &T::AccountId, fee: BalanceOf<T>) -> Option<T::AccountId> {
+            let sponsorship = SponsoredJournalists::<T>::get(journalist)?;
+            let covered =
+                sponsorship.quota >= fee && SponsorBudgets::<T>::get(&sponsorship.sponsor) >= fee;
+            covered.then_some(sponsorship.sponsor)
+        }
+
+        /// Books `fee`, withdrawn from `sponsor`, against its budget and `journalist`'s quota.
+        pub fn charge_sponsorship(journalist: &T::AccountId, sponsor: &T::AccountId, fee: BalanceOf<T>) {
+            SponsorBudgets::<T>::mutate(sponsor, |budget| budget.saturating_reduce(fee));
+            SponsoredJournalists::<T>::mutate(journalist, |maybe_sponsorship| {
+                if let Some(sponsorship) = maybe_sponsorship {
+                    sponsorship.quota.saturating_reduce(fee);
+                }
+            });
+        }
+
+        /// Returns the part of `charged` that exceeds the `actual` fee to the budget and quota
+        /// it was booked against, and reports the `actual` fee, unless nothing was paid.
+        pub fn settle_sponsorship(
+            journalist: &T::AccountId,
+            sponsor: &T::AccountId,
+            charged: BalanceOf<T>,
+            actual: BalanceOf<T>
+        ) {
+            let refund = charged.saturating_sub(actual);
+            if !refund.is_zero() {
+                SponsorBudgets::<T>::mutate(sponsor, |budget| budget.saturating_accrue(refund));
+                SponsoredJournalists::<T>::mutate(journalist, |maybe_sponsorship| {
+                    if let Some(sponsorship) = maybe_sponsorship.as_mut().filter(|s| &s.sponsor == sponsor) {
+                        sponsorship.quota.saturating_accrue(refund);
+                    }
+                });
+            }
+
+            if !actual.is_zero() {
+                Self::deposit_event(Event::FeeSponsored {
+                    sponsor: sponsor.clone(),
+                    journalist: journalist.clone(),
+                    fee: actual,
+                });
+            }
+        }
+
+        /// Whether `signature` of `digest` is by the Ethereum address registered to `publisher`.
+        fn signed_by_ethereum_address(
+            publisher: &T::AccountId,
+            signature: &EcdsaSignature,
+            digest: &[u8; 32]
+        ) -> bool {
+            EthereumAddressOf::<T>::get(publisher).is_some_and(|address| {
+                crate::ethereum::recover_address(signature, digest) == Some(address)
+            })
+        }
+
+        /// Whether `who` may report integrity checks.
+        pub fn is_integrity_reporter(who: &T::AccountId) -> bool {
+            T::IntegrityReporters::contains(who) || IntegrityWatchers::<T>::contains_key(who)
+        }
+
+        /// Commitment of `owner` to unpublished reporting: `blake2_256((owner, content_hash, salt))`.
+        /// Binding the owner means a copied commitment cannot be opened by anyone else.
+        pub fn draft_commitment(owner: &T::AccountId, content_hash: &ContentHash, salt: &H256) -> H256 {
+            H256(blake2_256(&(owner, content_hash, salt).encode()))
+        }
+
+        /// Whether `call` anchors provenance and may use the block space reserved for it.
+        pub fn is_provenance_call(call: &Call<T>) -> bool {
+            matches!(
+                call,
+                Call::record_article { .. } |
+                    Call::update_article { .. } |
+                    Call::record_article_for { .. } |
+                    Call::anchor_batch { .. }
+            )
+        }
+
+        /// Version hashes of page `page` of the history of `anchor`: an archived page, or the
+        /// current `HistoryByAnchor` list for the page past the last archived one.
+        pub fn history_page(anchor: ContentHash, page: u32) -> Vec<ContentHash> {
+            let pages = HistoryPageCount::<T>::get(&anchor);
+            match page.cmp(&pages) {
+                Ordering::Less => HistoryPages::<T>::get(&anchor, page).unwrap_or_default().into_inner(),
+                Ordering::Equal => HistoryByAnchor::<T>::get(&anchor).into_inner(),
+                Ordering::Greater => Vec::new(),
+            }
+        }
+
+        /// Appends `version` to the history of `anchor`, first archiving `HistoryByAnchor` as a
+        /// new page if it is full. Returns the index of `version` in the lineage.
+        fn append_to_history(anchor: ContentHash, version: ContentHash) -> Result<u32, DispatchError> {
+            let mut pages = HistoryPageCount::<T>::get(&anchor);
+            let mut timeline = HistoryByAnchor::<T>::get(&anchor);
+            if timeline.is_full() {
+                HistoryPages::<T>::insert(&anchor, pages, core::mem::take(&mut timeline));
+                pages += 1;
+                HistoryPageCount::<T>::insert(&anchor, pages);
+            }
+            let index = pages
+                .saturating_mul(T::MaxHistoryLen::get())
+                .saturating_add(timeline.len() as u32);
+            timeline.try_push(version).map_err(|_| Error::<T>::HistoryTooLong)?;
+            HistoryByAnchor::<T>::insert(&anchor, timeline);
+            Ok(index)
+        }
+
+        /// Versions of the lineage of `anchor` marked as substantive corrections, in history order.
+        pub fn substantive_corrections(anchor: ContentHash) -> Vec<ContentHash> {
+            let mut corrections: Vec<_> = CorrectionsOf::<T>
+                ::iter_prefix(&anchor)
+                .map(|(version, index)| (index, version))
+                .collect();
+            corrections.sort();
+            corrections.into_iter().map(|(_, version)| version).collect()
+        }
+
+        /// Tips sent to the lineage of `content_hash`, which may be any of its versions.
+        pub fn article_tips(content_hash: ContentHash) -> TipTally<BalanceOf<T>> {
+            TipsByAnchor::<T>::get(AnchorOf::<T>::get(&content_hash).unwrap_or(content_hash))
+        }
+
+        /// Priority proofs of every version in the lineage of `anchor`, in history order.
+        pub fn priority_proofs(anchor: ContentHash) -> Vec<(ContentHash, PriorityProof<T>)> {
+            (0..=HistoryPageCount::<T>::get(&anchor))
+                .flat_map(|page| Self::history_page(anchor, page))
+                .filter_map(|version| PriorityOf::<T>::get(&version).map(|proof| (version, proof)))
+                .collect()
+        }
+
+        /// Validates `terms` and queues the embargo of `content_hash` for release.
+        fn place_embargo(
+            content_hash: ContentHash,
+            terms: EmbargoTerms<T>,
+            metadata_hidden: bool
+        ) -> DispatchResult {
+            ensure!(
+                terms.release_at > <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::EmbargoReleaseInPast
+            );
+            ensure!(metadata_hidden, Error::<T>::EmbargoedMetadataNotEmpty);
+
+            ReleaseQueue::<T>::try_mutate(terms.release_at, |queue| {
+                queue.try_push(content_hash).map_err(|_| Error::<T>::ReleaseQueueFull)
+            })?;
+            Self::deposit_event(Event::ArticleEmbargoed {
+                content_hash,
+                release_at: terms.release_at,
+            });
+            Embargoes::<T>::insert(content_hash, Embargo { terms, lifted: false });
+
+            Ok(())
+        }
+
+        /// Walks the citation graph breadth first from `root`, up to `depth` hops, returning the
+        /// `(citing, cited)` edges found. `outgoing` follows `CitationsOf` (what `root` cites),
+        /// otherwise `CitedBy` (who cites `root`). At most `max_edges` edges are returned.
+        pub fn citation_graph(
+            root: ContentHash,
+            depth: u32,
+            outgoing: bool,
+            max_edges: u32
+        ) -> Vec<(ContentHash, ContentHash)> {
+            let mut edges = Vec::new();
+            let mut visited = Vec::from([root]);
+            let mut frontier = Vec::from([root]);
+
+            for _ in 0..depth {
+                let mut next = Vec::new();
+                for node in frontier {
+                    let neighbours: Vec<ContentHash> = if outgoing {
+                        CitationsOf::<T>::get(&node).map(|c| c.into_inner()).unwrap_or_default()
+                    } else {
+                        CitedBy::<T>::iter_key_prefix(&node).collect()
+                    };
+                    for neighbour in neighbours {
+                        if (edges.len() as u32) >= max_edges {
+                            return edges;
+                        }
+                        edges.push(if outgoing { (node, neighbour) } else { (neighbour, node) });
+                        if !visited.contains(&neighbour) {
+                            visited.push(neighbour);
+                            next.push(neighbour);
+                        }
+                    }
+                }
+                if next.is_empty() {
+                    break;
+                }
+                frontier = next;
+            }
+            edges
+        }
+
+        /// Decays `score` from block `since` up to the current block, once per multiple of
+        /// `ReputationDecayPeriod` crossed in between.
+        fn decay(score: i64, since: BlockNumberFor<T>) -> i64 {
+            let now = <frame_system::Pallet<T>>::block_number();
+            let period = T::ReputationDecayPeriod::get();
+            if period.is_zero() {
+                return score;
+            }
+            let elapsed: u32 = (now / period).saturating_sub(since / period).saturated_into();
+            let retained = T::ReputationRetention::get()
+                .saturating_pow(elapsed as usize)
+                .mul_floor(score.unsigned_abs());
+            let retained = i64::try_from(retained).unwrap_or(i64::MAX);
+            if score < 0 { retained.saturating_neg() } else { retained }
+        }
+
+        /// Applies `delta` to the publisher's decayed reputation and stores the result.
+        fn adjust_reputation(publisher: &T::AccountId, delta: i64) {
+            let score = Self::reputation_of(publisher).saturating_add(delta);
+            PublisherReputation::<T>::insert(publisher, ReputationScore::<T> {
+                score,
+                updated_at: <frame_system::Pallet<T>>::block_number(),
+            });
+            Self::deposit_event(Event::ReputationUpdated {
+                publisher: publisher.clone(),
+                score,
+            });
+        }
+
         /// Ensures that an article with the given content hash does not already exist
         fn ensure_content_hash_unique(content_hash: &ContentHash) -> DispatchResult {
             ensure!(
                 !ArticleByHash::<T>::contains_key(content_hash),
                 Error::<T>::ArticleAlreadyExists
             );
+            ensure!(!Tombstones::<T>::contains_key(content_hash), Error::<T>::ArticleRemoved);
+            Ok(())
+        }
+
+        /// Deletes the records of the anchor and the versions in `HistoryByAnchor`, tombstones
+        /// them and unlinks the lineage from the publisher, NFT item, syndication and embargo indices, then starts
+        /// clearing the per-version indices.
+        fn start_removal(
+            anchor: ContentHash,
+            publisher: T::AccountId,
+            reason: RemovalReason
+        ) -> DispatchResult {
+            ensure!(AnchorOf::<T>::get(&anchor) == Some(anchor), Error::<T>::NotAnAnchor);
+
+            let tombstone = Tombstone {
+                publisher: publisher.clone(),
+                anchor,
+                reason,
+                removed_at: <frame_system::Pallet<T>>::block_number(),
+            };
+            // Archived pages are unlinked as `clear_removed_versions` reaches them; the anchor
+            // goes now so the lineage cannot be removed twice.
+            let versions = HistoryByAnchor::<T>::take(&anchor);
+            Self::unlink_removed_versions(&versions, &tombstone);
+            if !versions.contains(&anchor) {
+                Self::unlink_removed_versions(&[anchor], &tombstone);
+            }
+            ArticleUpdateCount::<T>::remove(&anchor);
+            ArticlesByPublisher::<T>::mutate(&publisher, |anchors| {
+                anchors.retain(|other| *other != anchor)
+            });
+            if let Some(original) = SyndicatedFrom::<T>::take(&anchor) {
+                SyndicatedTo::<T>::mutate(original, |republished| {
+                    republished.retain(|other| *other != anchor)
+                });
+            }
+            SyndicatedTo::<T>::remove(&anchor);
+            Bylines::<T>::remove(&anchor);
+            TipsByAnchor::<T>::remove(&anchor);
+            if let Some(embargo) = Embargoes::<T>::take(&anchor) {
+                ReleaseQueue::<T>::mutate(embargo.terms.release_at, |queue| {
+                    queue.retain(|other| *other != anchor)
+                });
+            }
+            PendingRemovals::<T>::insert(&anchor, versions);
+
+            Self::deposit_event(Event::ArticleRemoved { anchor, publisher, reason });
+
+            Self::clear_removed_versions(anchor);
+
+            Ok(())
+        }
+
+        /// Deletes the records of `versions` of a removed lineage and replaces them by `tombstone`.
+        fn unlink_removed_versions(versions: &[ContentHash], tombstone: &Tombstone<T>) {
+            for version in versions {
+                if let Some(record) = ArticleByHash::<T>::take(version) {
+                    if RootByItem::<T>::get(record.collection_id, record.item_id) == Some(*version) {
+                        RootByItem::<T>::remove(record.collection_id, record.item_id);
+                    }
+                }
+                AnchorOf::<T>::remove(version);
+                Tombstones::<T>::insert(version, tombstone);
+            }
+        }
+
+        /// Clears up to `MaxRemovalSteps` index entries of the removed lineage `anchor`, latest
+        /// version first, returning endorsement deposits, and then the entries of
+        /// `OnArticleRemoved`. Unlinking an archived history page counts as one entry. Returns
+        /// whether the removal completed.
+        ///
+        /// Like `withdraw_endorsement`, the reputation points of the cleared endorsements and
+        /// fact checks are reverted, decayed since they were submitted, and citations of the
+        /// removed versions are dropped from the citing versions' `CitationsOf`.
+        fn clear_removed_versions(anchor: ContentHash) -> bool {
+            let Some(mut versions) = PendingRemovals::<T>::get(&anchor) else {
+                return true;
+            };
+            let publisher = Tombstones::<T>::get(&anchor).map(|tombstone| tombstone.publisher);
+            let mut budget = T::MaxRemovalSteps::get();
+
+            loop {
+                let Some(version) = versions.last().copied() else {
+                    let pages = HistoryPageCount::<T>::get(&anchor);
+                    if pages == 0 {
+                        break;
+                    }
+                    if budget == 0 {
+                        PendingRemovals::<T>::insert(&anchor, versions);
+                        return false;
+                    }
+                    versions = HistoryPages::<T>::take(&anchor, pages - 1).unwrap_or_default();
+                    HistoryPageCount::<T>::insert(&anchor, pages - 1);
+                    if let Some(tombstone) = Tombstones::<T>::get(&anchor) {
+                        Self::unlink_removed_versions(&versions, &tombstone);
+                    }
+                    budget -= 1;
+                    continue;
+                };
+                let mut reverted = 0i64;
+                let mut endorsements = Endorsements::<T>::drain_prefix(&version);
+                while budget > 0 {
+                    let Some((reader, endorsement)) = endorsements.next() else {
+                        break;
+                    };
+                    let _ = T::Currency::release(
+                        &HoldReason::EndorsementDeposit.into(),
+                        &reader,
+                        endorsement.deposit,
+                        Precision::BestEffort
+                    );
+                    reverted = reverted.saturating_add(
+                        Self::decay(endorsement.sentiment.points(), endorsement.at)
+                    );
+                    budget -= 1;
+                }
+                let mut citing = CitedBy::<T>::drain_prefix(&version);
+                while budget > 0 {
+                    let Some((citing_version, ())) = citing.next() else {
+                        break;
+                    };
+                    CitationsOf::<T>::mutate(&citing_version, |citations| {
+                        if let Some(citations) = citations {
+                            citations.retain(|cited| *cited != version);
+                        }
+                    });
+                    budget -= 1;
+                }
+                if budget == 0 {
+                    Self::revert_removed_points(publisher.as_ref(), reverted);
+                    PendingRemovals::<T>::insert(&anchor, versions);
+                    return false;
+                }
+
+                for cited in CitationsOf::<T>::take(&version).unwrap_or_default() {
+                    CitedBy::<T>::remove(cited, version);
+                }
+                if let Some(check) = FactCheckOf::<T>::take(&version) {
+                    reverted = reverted.saturating_add(Self::decay(check.verdict.points(), check.at));
+                }
+                Self::revert_removed_points(publisher.as_ref(), reverted);
+                EndorsementTallies::<T>::remove(&version);
+                RetractedAt::<T>::remove(&version);
+                IntegrityStatusOf::<T>::remove(&version);
+                PriorityOf::<T>::remove(&version);
+                BatchOf::<T>::remove(&version);
+                VersionChanges::<T>::remove(&version);
+                CorrectionsOf::<T>::remove(&anchor, &version);
+                versions.pop();
+                budget -= 1;
+            }
+            if T::OnArticleRemoved::on_article_removed(&anchor, budget) >= budget {
+                PendingRemovals::<T>::insert(&anchor, versions);
+                return false;
+            }
+
+            PendingRemovals::<T>::remove(&anchor);
+            HistoryPageCount::<T>::remove(&anchor);
+            Self::deposit_event(Event::ArticleRemovalCompleted { anchor });
+            true
+        }
+
+        /// Reverts `points` of the endorsements and fact checks of a removed lineage from its
+        /// `publisher`'s reputation.
+        fn revert_removed_points(publisher: Option<&T::AccountId>, points: i64) {
+            if let Some(publisher) = publisher.filter(|_| points != 0) {
+                Self::adjust_reputation(publisher, points.saturating_neg());
+            }
+        }
+
+        /// Signing payload of an article version recorded on this chain.
+        pub fn article_payload(
+            content_hash: ContentHash,
+            previous: Option<ContentHash>,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            title: &[u8],
+            canonical_url: &[u8],
+            hash_algo: HashAlgo,
+            word_count: u32
+        ) -> ArticlePayloadOf<T> {
+            ArticlePayload {
+                version: ARTICLE_PAYLOAD_VERSION,
+                genesis_hash: <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero()),
+                pallet_name: <Self as PalletInfoAccess>::name().as_bytes().to_vec(),
+                content_hash,
+                previous,
+                collection_id,
+                item_id,
+                title: title.to_vec(),
+                canonical_url: canonical_url.to_vec(),
+                hash_algo,
+                word_count,
+                valid_until: None,
+                nonce: None,
+            }
+        }
+
+        /// Message a signing key signs to be registered for `publisher`: `<Bytes>` + the SCALE
+        /// encoding of `(b"news-signing-key", genesis_hash, pallet_name, publisher)` + `</Bytes>`.
+        pub fn signing_key_message(publisher: &T::AccountId) -> Vec<u8> {
+            let mut message = b"<Bytes>".to_vec();
+            message.extend(
+                (
+                    b"news-signing-key",
+                    <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero()),
+                    <Self as PalletInfoAccess>::name().as_bytes(),
+                    publisher,
+                ).encode()
+            );
+            message.extend_from_slice(b"</Bytes>");
+            message
+        }
+
+        /// Stores `key` for `publisher` once `proof` shows the key consents.
+        fn insert_signing_key(
+            publisher: &T::AccountId,
+            key: &T::AccountId,
+            valid_from: BlockNumberFor<T>,
+            expires_at: Option<BlockNumberFor<T>>,
+            proof: &SignatureOf<T>
+        ) -> DispatchResult {
+            ensure!(!SigningKeys::<T>::contains_key(publisher, key), Error::<T>::SigningKeyExists);
+            ensure!(
+                proof.verify(&Self::signing_key_message(publisher)[..], key),
+                Error::<T>::SigningKeyProofInvalid
+            );
+            SigningKeys::<T>::insert(publisher, key, SigningKeyInfo {
+                valid_from,
+                expires_at,
+                revoked_from: None,
+            });
             Ok(())
         }
 
-        /// Verifies that the signature provided by the publisher is valid for the content hash
+        /// Who signed the recorded version `content_hash`, judged against signing key
+        /// revocations known now. `None` if the version is not recorded.
+        pub fn signature_status(content_hash: &ContentHash) -> Option<SignatureStatus> {
+            let record = ArticleByHash::<T>::get(content_hash)?;
+            let status = match record.signature {
+                PublisherSignature::SigningKey { key, .. } => {
+                    // A promoted batch leaf was signed when its batch was anchored.
+                    let signed_at = BatchOf::<T>
+                        ::get(content_hash)
+                        .and_then(|root| Batches::<T>::get(&record.publisher, root))
+                        .map_or(record.last_updated_at, |batch| batch.anchored_at);
+                    let revoked = SigningKeys::<T>
+                        ::get(&record.publisher, &key)
+                        .is_some_and(|info| info.is_revoked_at(signed_at));
+                    if revoked {
+                        SignatureStatus::RevokedSigningKey
+                    } else {
+                        SignatureStatus::SigningKey
+                    }
+                }
+                _ => SignatureStatus::Publisher,
+            };
+            Some(status)
+        }
+
+        /// Verifies that `publisher` signed `payload`, and that it has neither expired nor been
+        /// used. The nonce is consumed by the caller.
         fn verify_signature(
             publisher: &T::AccountId,
-            content_hash: &ContentHash,
-            signature: &MultiSignature
+            payload: &ArticlePayloadOf<T>,
+            signature: &PublisherSignatureOf<T>
         ) -> DispatchResult {
-            let bytes = publisher.encode();
-            let account_id32 = AccountId32::try_from(&bytes[..]).map_err(
-                |_| Error::<T>::AccountIdNot32Bytes
-            )?;
+            if let Some(valid_until) = payload.valid_until {
+                ensure!(
+                    <frame_system::Pallet<T>>::block_number() <= valid_until,
+                    Error::<T>::SignatureExpired
+                );
+            }
+            if let Some(nonce) = payload.nonce {
+                let next_nonce = PublisherNonces::<T>::get(publisher);
+                ensure!(nonce >= next_nonce, Error::<T>::SignatureReused);
+                ensure!(nonce == next_nonce, Error::<T>::FutureSignatureNonce);
+            }
+
+            let ok = match signature {
+                PublisherSignature::Native(signature) => {
+                    signature.verify(&payload.signing_message()[..], publisher) ||
+                        // Legacy signatures cover neither an expiry nor a nonce
+                        (T::AllowLegacySignatures::get() &&
+                            payload.valid_until.is_none() &&
+                            payload.nonce.is_none() &&
+                            signature.verify(&payload.legacy_signing_message()[..], publisher))
+                }
+                PublisherSignature::Eip191(signature) => {
+                    let digest = Self::eip191_article_digest(payload);
+                    Self::signed_by_ethereum_address(publisher, signature, &digest)
+                }
+                PublisherSignature::Eip712(signature) => {
+                    let digest = Self::eip712_article_digest(payload);
+                    Self::signed_by_ethereum_address(publisher, signature, &digest)
+                }
+                PublisherSignature::SigningKey { key, signature } => {
+                    Self::ensure_signing_key_valid(publisher, key)?;
+                    signature.verify(&payload.signing_message()[..], key)
+                }
+            };
+            ensure!(ok, Error::<T>::SignatureInvalid);
+
+            Ok(())
+        }
 
-            // Wrap content_hash in <Bytes></Bytes> tags for verification
-            let mut wrapped_msg = b"<Bytes>".to_vec();
-            wrapped_msg.extend_from_slice(content_hash.as_bytes());
-            wrapped_msg.extend_from_slice(b"</Bytes>");
+        /// Signing payload of a batch `publisher` anchors on this chain.
+        pub fn batch_payload(
+            publisher: T::AccountId,
+            merkle_root: H256,
+            count: u32
+        ) -> BatchPayloadOf<T> {
+            BatchPayload {
+                version: ARTICLE_PAYLOAD_VERSION,
+                genesis_hash: <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero()),
+                pallet_name: <Self as PalletInfoAccess>::name().as_bytes().to_vec(),
+                publisher,
+                merkle_root,
+                count,
+            }
+        }
 
-            let ok = signature.verify(&wrapped_msg[..], &account_id32);
+        /// Verifies that `publisher` signed the batch `payload`.
+        fn verify_batch_signature(
+            publisher: &T::AccountId,
+            payload: &BatchPayloadOf<T>,
+            signature: &PublisherSignatureOf<T>
+        ) -> DispatchResult {
+            let ok = match signature {
+                PublisherSignature::Native(signature) => {
+                    signature.verify(&payload.signing_message()[..], publisher)
+                }
+                PublisherSignature::Eip191(signature) => {
+                    let digest = Self::eip191_batch_digest(payload);
+                    Self::signed_by_ethereum_address(publisher, signature, &digest)
+                }
+                PublisherSignature::Eip712(signature) => {
+                    let digest = Self::eip712_batch_digest(payload);
+                    Self::signed_by_ethereum_address(publisher, signature, &digest)
+                }
+                PublisherSignature::SigningKey { key, signature } => {
+                    Self::ensure_signing_key_valid(publisher, key)?;
+                    signature.verify(&payload.signing_message()[..], key)
+                }
+            };
             ensure!(ok, Error::<T>::SignatureInvalid);
 
             Ok(())
         }
 
+        /// Ensures `key` is a signing key of `publisher` valid at the current block.
+        fn ensure_signing_key_valid(publisher: &T::AccountId, key: &T::AccountId) -> DispatchResult {
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                SigningKeys::<T>::get(publisher, key).is_some_and(|info| info.is_valid_at(now)),
+                Error::<T>::SigningKeyNotValid
+            );
+            Ok(())
+        }
+
+        /// Records the anchor `content_hash` for `publisher`, shared by `record_article`,
+        /// `record_article_for` and `record_article_unsigned`.
+        fn do_record_article(
+            publisher: T::AccountId,
+            content_hash: ContentHash,
+            collection_id: CollectionId,
+            item_id: ItemId,
+            title: BoundedVec<u8, T::MaxTitleLen>,
+            canonical_url: BoundedVec<u8, T::MaxUrlLen>,
+            signature: PublisherSignatureOf<T>,
+            hash_algo: HashAlgo,
+            word_count: u32,
+            embargo: Option<EmbargoTerms<T>>,
+            valid_until: Option<BlockNumberFor<T>>,
+            nonce: Option<u64>
+        ) -> DispatchResult {
+            // Ensure content hash is unique
+            Self::ensure_content_hash_unique(&content_hash)?;
+
+            // Verify signature
+            let payload = ArticlePayload {
+                valid_until,
+                nonce,
+                ..Self::article_payload(
+                    content_hash,
+                    None,
+                    collection_id,
+                    item_id,
+                    &title,
+                    &canonical_url,
+                    hash_algo,
+                    word_count
+                )
+            };
+            Self::verify_signature(&publisher, &payload, &signature)?;
+            if nonce.is_some() {
+                PublisherNonces::<T>::mutate(&publisher, |next_nonce| {
+                    *next_nonce = next_nonce.saturating_add(1)
+                });
+            }
+            let metadata_hidden = title.is_empty() && canonical_url.is_empty();
+
+            // Create and store article record
+            let record = Self::create_article_record(
+                publisher.clone(),
+                content_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                signature,
+                hash_algo,
+                word_count,
+                0 // Initial updates count
+            );
+            Self::store_new_article(record, publisher, content_hash, collection_id, item_id)?;
+
+            // Hide metadata until release
+            if let Some(terms) = embargo {
+                Self::place_embargo(content_hash, terms, metadata_hidden)?;
+            }
+
+            Ok(())
+        }
+
         /// Creates a new ArticleRecord instance with the given parameters
         fn create_article_record(
             publisher: T::AccountId,
@@ -414,7 +4164,7 @@ pub mod pallet {
             item_id: ItemId,
             title: BoundedVec<u8, T::MaxTitleLen>,
             canonical_url: BoundedVec<u8, T::MaxUrlLen>,
-            signature: MultiSignature,
+            signature: PublisherSignatureOf<T>,
             hash_algo: HashAlgo,
             word_count: u32,
             updates: u32
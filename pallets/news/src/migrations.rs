@@ -0,0 +1,84 @@
+//! Storage migrations.
+
+use crate::pallet::*;
+use frame::prelude::*;
+
+/// Version 1 stores `ArticleRecord.signature` as a `PublisherSignature` instead of a bare
+/// `Config::Signature`.
+pub mod v1 {
+    use super::*;
+    use frame::deps::frame_support::{
+        migrations::VersionedMigration,
+        traits::UncheckedOnRuntimeUpgrade,
+    };
+    #[cfg(feature = "try-runtime")]
+    use alloc::vec::Vec;
+    #[cfg(feature = "try-runtime")]
+    use frame::deps::sp_runtime::TryRuntimeError;
+
+    /// `ArticleRecord` as stored in version 0.
+    #[derive(Encode, Decode)]
+    struct OldArticleRecord<T: Config> {
+        title: BoundedVec<u8, T::MaxTitleLen>,
+        canonical_url: BoundedVec<u8, T::MaxUrlLen>,
+        publisher: T::AccountId,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        content_hash: ContentHash,
+        signature: SignatureOf<T>,
+        hash_algo: HashAlgo,
+        word_count: u32,
+        last_updated_at: BlockNumberFor<T>,
+        updates: u32,
+    }
+
+    /// Wraps the signature of every `ArticleByHash` record in `PublisherSignature::Native`.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            ArticleByHash::<T>::translate::<OldArticleRecord<T>, _>(|_, old| {
+                translated += 1;
+                Some(ArticleRecord::<T> {
+                    title: old.title,
+                    canonical_url: old.canonical_url,
+                    publisher: old.publisher,
+                    collection_id: old.collection_id,
+                    item_id: old.item_id,
+                    content_hash: old.content_hash,
+                    signature: PublisherSignature::Native(old.signature),
+                    hash_algo: old.hash_algo,
+                    word_count: old.word_count,
+                    last_updated_at: old.last_updated_at,
+                    updates: old.updates,
+                })
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((ArticleByHash::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let before = u64::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+            ensure!(
+                ArticleByHash::<T>::iter_values().count() as u64 == before,
+                "article records were lost"
+            );
+            Ok(())
+        }
+    }
+
+    /// [`InnerMigrateV0ToV1`], run only while the pallet is at storage version 0.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight
+    >;
+}
@@ -1,9 +1,16 @@
 use frame::{
-	deps::{frame_support::weights::constants::RocksDbWeight, frame_system::GenesisConfig, sp_runtime::AccountId32},
+	deps::{frame_support::weights::constants::RocksDbWeight, frame_system::GenesisConfig, sp_runtime::{AccountId32, MultiSignature, MultiSigner, Perbill}},
 	prelude::*,
 	runtime::prelude::*,
 	testing_prelude::*,
 };
+use frame::deps::frame_system::{
+    offchain::{ CreateBare, CreateTransactionBase, SigningTypes },
+    EnsureRoot,
+    EnsureSigned,
+};
+use frame::deps::frame_support::traits::{ AsEnsureOriginWithArg, IsInVec };
+use polkadot_sdk::{ pallet_assets, pallet_balances, sp_keystore::{ testing::MemoryKeystore, KeystoreExt } };
 
 // Configure a mock runtime to test the pallet.
 #[frame_construct_runtime]
@@ -28,6 +35,10 @@ mod test_runtime {
 	pub type System = frame_system;
 	#[runtime::pallet_index(1)]
 	pub type News = crate;
+	#[runtime::pallet_index(2)]
+	pub type Balances = pallet_balances;
+	#[runtime::pallet_index(3)]
+	pub type Assets = pallet_assets;
 }
 
 // Note: because we want to test signatures, we have to change the account type to something that's not u64 - therefore, we must override the test config to do so.
@@ -51,7 +62,7 @@ impl frame_system::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -65,19 +76,386 @@ impl frame_system::Config for Test {
     type PostTransactions = ();
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+    type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+    type ForceOrigin = EnsureRoot<AccountId32>;
+}
+
+impl<C> CreateTransactionBase<C> for Test where RuntimeCall: From<C> {
+    type Extrinsic = MockUncheckedExtrinsic<Test>;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl SigningTypes for Test {
+    type Public = MultiSigner;
+    type Signature = MultiSignature;
+}
+
+impl<C> CreateBare<C> for Test where RuntimeCall: From<C> {
+    fn create_bare(call: Self::RuntimeCall) -> Self::Extrinsic {
+        MockUncheckedExtrinsic::<Test>::new_bare(call)
+    }
+}
+
+parameter_types! {
+    pub const EndorsementDeposit: u64 = 100;
+    pub const ReputationRetention: Perbill = Perbill::from_percent(50);
+    pub static SessionReporters: Vec<AccountId32> = Vec::new();
+    pub static SessionIndex: u32 = 0;
+    pub static LegacySignatures: bool = true;
+    pub const ProvenanceReservedWeight: Weight = Weight::from_parts(1_000_000_000, 0);
+    pub static ExternalEntries: u32 = 0;
+}
+
+/// Stands in for pallets storing `ExternalEntries` entries about every removed lineage.
+pub struct MockRemovalHook;
+impl crate::OnArticleRemoved for MockRemovalHook {
+    fn on_article_removed(_: &crate::ContentHash, limit: u32) -> u32 {
+        let cleared = ExternalEntries::get().min(limit);
+        ExternalEntries::mutate(|entries| *entries -= cleared);
+        cleared
+    }
+}
 
 impl crate::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type Assets = Assets;
+    type EndorsementDeposit = EndorsementDeposit;
+    type PersonhoodVerifier = Everything;
+    type FactCheckOrigin = EnsureRoot<AccountId32>;
+    type PurgeOrigin = EnsureRoot<AccountId32>;
+    type ReputationRetention = ReputationRetention;
+    type ReputationDecayPeriod = ConstU64<100>;
+    type DraftDeposit = ConstU64<50>;
+    type DraftLifetime = ConstU64<20>;
+    type IntegrityCheckInterval = ConstU64<10>;
+    type MaxIntegrityChecksPerRun = ConstU32<2>;
+    type IntegrityReportPriority = ConstU64<{ TransactionPriority::MAX / 2 }>;
+    type IntegrityAuthorityId = crate::crypto::IntegrityReporterId;
+    type IntegrityReporters = IsInVec<SessionReporters>;
+    type IntegrityWatcherOrigin = EnsureRoot<AccountId32>;
+    type FeelessApprovalOrigin = EnsureRoot<AccountId32>;
+    type FeelessEraLength = ConstU64<10>;
+    type MaxFeelessAnchorsPerEra = ConstU32<2>;
+    type FeelessAnchorPriority = ConstU64<0>;
+    type ProvenanceReservedWeight = ProvenanceReservedWeight;
+    type FreeAnchorsPerSession = ConstU32<2>;
+    type CurrentSession = SessionIndex;
+    type Signature = MultiSignature;
+    type Signer = MultiSigner;
+    type EthereumChainId = ConstU64<1>;
+    type AllowLegacySignatures = LegacySignatures;
+    type MaxBatchProofLen = ConstU32<32>;
+    type MaxRemovalSteps = ConstU32<3>;
+    type MaxChangeSummaryLen = ConstU32<256>;
+    type MaxCoAuthors = ConstU32<3>;
     type WeightInfo = ();
     type MaxArticlesPerPublisher = ConstU32<50>;
     type MaxHistoryLen = ConstU32<50>;
     type MaxTitleLen = ConstU32<128>;
     type MaxUrlLen = ConstU32<128>;
+    type MaxSyndications = ConstU32<50>;
+    type MaxReleasesPerBlock = ConstU32<50>;
+    type MaxCitations = ConstU32<32>;
+    type SyndicationPolicy = ();
+    type OnArticleRemoved = MockRemovalHook;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> TestState {
-	GenesisConfig::<Test>::default().build_storage().unwrap().into()
-}
\ No newline at end of file
+	let mut ext: TestState = GenesisConfig::<Test>::default().build_storage().unwrap().into();
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+/// Mock runtime with 20-byte, Ethereum-style accounts, for checking the pallet does not assume
+/// `AccountId32`.
+pub mod account20 {
+	use frame::{
+		deps::{
+			frame_support::weights::constants::RocksDbWeight,
+			frame_system::{
+				offchain::{ AppCrypto, CreateBare, CreateTransactionBase, SigningTypes },
+				EnsureRoot,
+				EnsureSigned,
+				GenesisConfig,
+			},
+			sp_core::{ ecdsa, sr25519, H160 },
+			sp_io::{ crypto::secp256k1_ecdsa_recover, hashing::{ blake2_256, keccak_256 } },
+			sp_runtime::traits::{ IdentifyAccount, Lazy, Verify },
+		},
+		prelude::*,
+		runtime::prelude::*,
+		testing_prelude::*,
+	};
+	use frame::deps::frame_support::traits::{ AsEnsureOriginWithArg, ConstBool, IsInVec };
+	use polkadot_sdk::{ pallet_assets, pallet_balances, sp_keystore::{ testing::MemoryKeystore, KeystoreExt } };
+
+	#[frame_construct_runtime]
+	mod test_runtime {
+		#[runtime::runtime]
+		#[runtime::derive(
+			RuntimeCall,
+			RuntimeEvent,
+			RuntimeError,
+			RuntimeOrigin,
+			RuntimeFreezeReason,
+			RuntimeHoldReason,
+			RuntimeSlashReason,
+			RuntimeLockId,
+			RuntimeTask,
+			RuntimeViewFunction
+		)]
+		pub struct Test20;
+
+		#[runtime::pallet_index(0)]
+		pub type System = frame_system;
+		#[runtime::pallet_index(1)]
+		pub type News = crate;
+		#[runtime::pallet_index(2)]
+		pub type Balances = pallet_balances;
+		#[runtime::pallet_index(3)]
+		pub type Assets = pallet_assets;
+	}
+
+	/// Recoverable secp256k1 signature over the keccak-256 hash of the message.
+	#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo)]
+	pub struct EthereumSignature(pub ecdsa::Signature);
+
+	/// Ethereum address a signature must recover to.
+	#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+	pub struct EthereumSigner(pub H160);
+
+	impl IdentifyAccount for EthereumSigner {
+		type AccountId = H160;
+		fn into_account(self) -> H160 {
+			self.0
+		}
+	}
+
+	impl Verify for EthereumSignature {
+		type Signer = EthereumSigner;
+		fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &H160) -> bool {
+			recover(&self.0, &keccak_256(msg.get())) == Some(*signer)
+		}
+	}
+
+	/// Address of the key that signed `digest`.
+	pub fn recover(signature: &ecdsa::Signature, digest: &[u8; 32]) -> Option<H160> {
+		let signature = <[u8; 65]>::try_from(signature.as_ref()).ok()?;
+		let public = secp256k1_ecdsa_recover(&signature, digest).ok()?;
+		Some(H160::from_slice(&keccak_256(&public)[12..]))
+	}
+
+	/// Signs `content_hash` the way publishers do and returns the signature and signer address.
+	pub fn sign(pair: &ecdsa::Pair, content_hash: &H256) -> (EthereumSignature, H160) {
+		let mut wrapped_msg = b"<Bytes>".to_vec();
+		wrapped_msg.extend_from_slice(content_hash.as_bytes());
+		wrapped_msg.extend_from_slice(b"</Bytes>");
+
+		let digest = keccak_256(&wrapped_msg);
+		let signature = pair.sign_prehashed(&digest);
+		let signer = recover(&signature, &digest).expect("signature was just created; qed");
+		(EthereumSignature(signature), signer)
+	}
+
+	/// Integrity reporter key, identified by the first 20 bytes of its hash.
+	#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Encode, Decode, TypeInfo)]
+	pub struct ReporterPublic(pub sr25519::Public);
+
+	/// Signature of an integrity reporter key.
+	#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+	pub struct ReporterSignature(pub sr25519::Signature);
+
+	impl IdentifyAccount for ReporterPublic {
+		type AccountId = H160;
+		fn into_account(self) -> H160 {
+			H160::from_slice(&blake2_256(self.0.as_ref())[..20])
+		}
+	}
+
+	impl From<sr25519::Public> for ReporterPublic {
+		fn from(public: sr25519::Public) -> Self {
+			Self(public)
+		}
+	}
+
+	impl From<ReporterPublic> for sr25519::Public {
+		fn from(public: ReporterPublic) -> Self {
+			public.0
+		}
+	}
+
+	impl From<sr25519::Signature> for ReporterSignature {
+		fn from(signature: sr25519::Signature) -> Self {
+			Self(signature)
+		}
+	}
+
+	impl From<ReporterSignature> for sr25519::Signature {
+		fn from(signature: ReporterSignature) -> Self {
+			signature.0
+		}
+	}
+
+	/// Integrity reporter identity of the 20-byte runtime.
+	pub struct IntegrityReporterId;
+
+	impl AppCrypto<ReporterPublic, ReporterSignature> for IntegrityReporterId {
+		type RuntimeAppPublic = crate::crypto::Public;
+		type GenericSignature = sr25519::Signature;
+		type GenericPublic = sr25519::Public;
+	}
+
+	/// Creates Ethereum-style signatures from a keystore key.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub struct EthereumBenchmarkHelper;
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl crate::BenchmarkHelper<EthereumSignature, H160> for EthereumBenchmarkHelper {
+		fn create_signature(index: u32, message: &[u8]) -> (EthereumSignature, H160) {
+			use frame::deps::sp_io::crypto::{ ecdsa_generate, ecdsa_sign_prehashed };
+
+			let digest = keccak_256(message);
+			let seed = format!("//Publisher{}", index).into_bytes();
+			let public = ecdsa_generate(0.into(), Some(seed));
+			let signature = ecdsa_sign_prehashed(0.into(), &public, &digest).unwrap();
+			let signer = recover(&signature, &digest).unwrap();
+			(EthereumSignature(signature), signer)
+		}
+	}
+
+	impl frame_system::Config for Test20 {
+		type Nonce = u64;
+		type AccountId = H160;
+		type Block = MockBlock<Test20>;
+		type BlockHashCount = ConstU64<250>;
+		type DbWeight = RocksDbWeight;
+		type BaseCallFilter = Everything;
+		type BlockWeights = ();
+		type BlockLength = ();
+		type RuntimeOrigin = RuntimeOrigin;
+		type RuntimeCall = RuntimeCall;
+		type RuntimeTask = ();
+		type Hash = H256;
+		type ExtensionsWeightInfo = ();
+		type Hashing = BlakeTwo256;
+		type Lookup = IdentityLookup<Self::AccountId>;
+		type RuntimeEvent = RuntimeEvent;
+		type Version = ();
+		type PalletInfo = PalletInfo;
+		type AccountData = pallet_balances::AccountData<u64>;
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+		type SS58Prefix = ();
+		type OnSetCode = ();
+		type MaxConsumers = ConstU32<16>;
+		type SingleBlockMigrations = ();
+		type MultiBlockMigrator = ();
+		type PreInherents = ();
+		type PostInherents = ();
+		type PostTransactions = ();
+	}
+
+	#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+	impl pallet_balances::Config for Test20 {
+		type AccountStore = System;
+		type RuntimeHoldReason = RuntimeHoldReason;
+	}
+
+	#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+	impl pallet_assets::Config for Test20 {
+		type Currency = Balances;
+		type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<H160>>;
+		type ForceOrigin = EnsureRoot<H160>;
+	}
+
+	impl<C> CreateTransactionBase<C> for Test20 where RuntimeCall: From<C> {
+		type Extrinsic = MockUncheckedExtrinsic<Test20>;
+		type RuntimeCall = RuntimeCall;
+	}
+
+	impl SigningTypes for Test20 {
+		type Public = ReporterPublic;
+		type Signature = ReporterSignature;
+	}
+
+	impl<C> CreateBare<C> for Test20 where RuntimeCall: From<C> {
+		fn create_bare(call: Self::RuntimeCall) -> Self::Extrinsic {
+			MockUncheckedExtrinsic::<Test20>::new_bare(call)
+		}
+	}
+
+	parameter_types! {
+		pub static SessionReporters20: Vec<H160> = Vec::new();
+	}
+
+	impl crate::Config for Test20 {
+		type RuntimeEvent = RuntimeEvent;
+		type RuntimeHoldReason = RuntimeHoldReason;
+		type Currency = Balances;
+		type Assets = Assets;
+		type EndorsementDeposit = super::EndorsementDeposit;
+		type PersonhoodVerifier = Everything;
+		type FactCheckOrigin = EnsureRoot<H160>;
+		type PurgeOrigin = EnsureRoot<H160>;
+		type ReputationRetention = super::ReputationRetention;
+		type ReputationDecayPeriod = ConstU64<100>;
+		type DraftDeposit = ConstU64<50>;
+		type DraftLifetime = ConstU64<20>;
+		type IntegrityCheckInterval = ConstU64<10>;
+		type MaxIntegrityChecksPerRun = ConstU32<2>;
+		type IntegrityReportPriority = ConstU64<{ TransactionPriority::MAX / 2 }>;
+		type IntegrityAuthorityId = IntegrityReporterId;
+		type IntegrityReporters = IsInVec<SessionReporters20>;
+		type IntegrityWatcherOrigin = EnsureRoot<H160>;
+		type FeelessApprovalOrigin = EnsureRoot<H160>;
+		type FeelessEraLength = ConstU64<10>;
+		type MaxFeelessAnchorsPerEra = ConstU32<2>;
+		type FeelessAnchorPriority = ConstU64<0>;
+		type ProvenanceReservedWeight = super::ProvenanceReservedWeight;
+		type FreeAnchorsPerSession = ConstU32<2>;
+		type CurrentSession = super::SessionIndex;
+		type Signature = EthereumSignature;
+		type Signer = EthereumSigner;
+		type EthereumChainId = ConstU64<1>;
+		type AllowLegacySignatures = ConstBool<true>;
+		type MaxBatchProofLen = ConstU32<32>;
+		type MaxRemovalSteps = ConstU32<3>;
+		type MaxChangeSummaryLen = ConstU32<256>;
+		type MaxCoAuthors = ConstU32<3>;
+		type WeightInfo = ();
+		type MaxArticlesPerPublisher = ConstU32<50>;
+		type MaxHistoryLen = ConstU32<50>;
+		type MaxTitleLen = ConstU32<128>;
+		type MaxUrlLen = ConstU32<128>;
+		type MaxSyndications = ConstU32<50>;
+		type MaxReleasesPerBlock = ConstU32<50>;
+		type MaxCitations = ConstU32<32>;
+		type SyndicationPolicy = ();
+		type OnArticleRemoved = ();
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper = EthereumBenchmarkHelper;
+	}
+
+	// Build genesis storage according to the 20-byte mock runtime.
+	pub fn new_test_ext() -> TestState {
+		let mut ext: TestState = GenesisConfig::<Test20>::default().build_storage().unwrap().into();
+		ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
@@ -0,0 +1,145 @@
+//! Off-chain worker that checks canonical URLs against anchored content hashes.
+
+use crate::pallet::*;
+use alloc::vec::Vec;
+use frame::deps::{
+    frame_system::offchain::{ AppCrypto, CreateBare, SignedPayload, SigningTypes, SubmitTransaction },
+    sp_core::H256,
+    sp_io::hashing::{ blake2_256, sha2_256 },
+    sp_runtime::{
+        offchain::{ http, storage::StorageValueRef, Duration },
+        traits::IdentifyAccount,
+        RuntimeAppPublic,
+    },
+};
+use frame::prelude::*;
+
+/// Local storage key of the `HistoryByAnchor` raw key the previous run stopped at.
+const CURSOR_KEY: &[u8] = b"pallet-news::integrity::cursor";
+/// Time allowed for a single HTTP fetch.
+const FETCH_DEADLINE_MS: u64 = 5_000;
+
+impl<T: Config> Pallet<T> {
+    /// Checks the latest version of up to `MaxIntegrityChecksPerRun` lineages and submits a
+    /// signed, unsigned-transaction report of each result.
+    ///
+    /// Does nothing unless the node's keystore holds the key of an authorized reporter.
+    pub(crate) fn run_integrity_checks(now: BlockNumberFor<T>) {
+        let Some(public) = Self::local_reporter() else {
+            return;
+        };
+
+        let mut cursor = StorageValueRef::persistent(CURSOR_KEY);
+        let start = cursor.get::<Vec<u8>>().ok().flatten().unwrap_or_default();
+
+        let mut last_key = None;
+        let mut checked = 0u32;
+        let mut iter = if start.is_empty() {
+            HistoryByAnchor::<T>::iter()
+        } else {
+            HistoryByAnchor::<T>::iter_from(start)
+        };
+        while checked < T::MaxIntegrityChecksPerRun::get() {
+            let Some((anchor, history)) = iter.next() else {
+                break;
+            };
+            last_key = Some(HistoryByAnchor::<T>::hashed_key_for(anchor));
+            checked += 1;
+
+            let Some(latest) = history.last() else {
+                continue;
+            };
+            let Some(record) = ArticleByHash::<T>::get(latest) else {
+                continue;
+            };
+            // Embargoed articles have no public URL yet; retracted ones are expected to change.
+            if record.canonical_url.is_empty() || RetractedAt::<T>::contains_key(latest) {
+                continue;
+            }
+
+            let report = IntegrityReport {
+                content_hash: *latest,
+                observation: Self::check_canonical_url(&record),
+                checked_at: now,
+                public: public.clone(),
+            };
+            if Self::ensure_report_acceptable(&report).is_ok() {
+                Self::submit_report(report);
+            }
+        }
+
+        // Resume after the last checked lineage, or start over once the map is exhausted.
+        match last_key {
+            Some(key) if checked == T::MaxIntegrityChecksPerRun::get() => cursor.set(&key),
+            _ => cursor.clear(),
+        }
+    }
+
+    /// Fetches the canonical URL of `record` and compares its body with the anchored hash.
+    pub(crate) fn check_canonical_url(record: &ArticleRecord<T>) -> IntegrityObservation {
+        let body = match Self::fetch(&record.canonical_url) {
+            Ok(body) => body,
+            Err(http_status) => {
+                return IntegrityObservation::Unreachable { http_status };
+            }
+        };
+
+        let observed = H256(match record.hash_algo {
+            HashAlgo::Sha256 => sha2_256(&body),
+            HashAlgo::Blake2b256 => blake2_256(&body),
+        });
+        if observed == record.content_hash {
+            IntegrityObservation::Matches
+        } else {
+            IntegrityObservation::Changed { observed }
+        }
+    }
+
+    /// First key in the local keystore that belongs to an authorized integrity reporter.
+    fn local_reporter() -> Option<T::Public> {
+        type Crypto<T> = <T as Config>::IntegrityAuthorityId;
+        type GenericPublic<T> = <Crypto<T> as AppCrypto<
+            <T as SigningTypes>::Public,
+            <T as SigningTypes>::Signature
+        >>::GenericPublic;
+
+        <Crypto<T> as AppCrypto<T::Public, <T as SigningTypes>::Signature>>::RuntimeAppPublic
+            ::all()
+            .into_iter()
+            .map(|key| -> T::Public { GenericPublic::<T>::from(key).into() })
+            .find(|public| Self::is_integrity_reporter(&public.clone().into_account()))
+    }
+
+    /// GETs `url`, returning the body on `200 OK`, or the HTTP status (if any) on failure.
+    fn fetch(url: &[u8]) -> Result<Vec<u8>, Option<u16>> {
+        let url = core::str::from_utf8(url).map_err(|_| None)?;
+        let deadline = frame::deps::sp_io::offchain
+            ::timestamp()
+            .add(Duration::from_millis(FETCH_DEADLINE_MS));
+
+        let pending = http::Request::get(url)
+            .deadline(deadline)
+            .send()
+            .map_err(|_| None)?;
+        let response = pending
+            .try_wait(deadline)
+            .map_err(|_| None)?
+            .map_err(|_| None)?;
+        if response.code != 200 {
+            return Err(Some(response.code));
+        }
+        Ok(response.body().collect::<Vec<u8>>())
+    }
+
+    /// Signs `report` with the reporter's local key and submits it to the transaction pool as an
+    /// unsigned transaction.
+    fn submit_report(report: IntegrityReportOf<T>) {
+        let Some(signature) = SignedPayload::<T>::sign::<T::IntegrityAuthorityId>(&report) else {
+            return;
+        };
+        let call = Call::<T>::submit_integrity_report { report, signature };
+        let xt = <T as CreateBare<Call<T>>>::create_bare(call.into());
+        // A rejected submission is retried on the next run.
+        let _ = SubmitTransaction::<T, Call<T>>::submit_transaction(xt);
+    }
+}
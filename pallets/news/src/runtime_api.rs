@@ -0,0 +1,77 @@
+//! Runtime API for querying article provenance and publisher reputation.
+
+use crate::{ BatchLeaf, ContentHash, EndorsementTally, IntegrityStatus, SignatureStatus, TipTally };
+use frame::deps::sp_core::H256;
+use alloc::vec::Vec;
+use codec::Codec;
+
+/// Summary of a publisher, built from the `ArticlesByPublisher` index.
+#[derive(
+    scale_info::TypeInfo,
+    codec::Encode,
+    codec::Decode,
+    Clone,
+    PartialEq,
+    Eq,
+    Debug
+)]
+pub struct PublisherSummary {
+    /// Number of anchor articles recorded by the publisher.
+    pub articles: u32,
+    /// Current (decayed) reputation score.
+    pub reputation: i64,
+}
+
+frame::deps::sp_api::decl_runtime_apis! {
+    /// Read-only queries over `pallet_news` state.
+    pub trait NewsApi<AccountId, BlockNumber> where AccountId: Codec, BlockNumber: Codec {
+        /// Current reputation score of `publisher`, with decay applied up to the queried block.
+        fn publisher_reputation(publisher: AccountId) -> i64;
+        /// Anchor count and reputation of `publisher`.
+        fn publisher_summary(publisher: AccountId) -> PublisherSummary;
+        /// Endorsement and flag counters of a single article version.
+        fn endorsement_tally(content_hash: ContentHash) -> EndorsementTally;
+        /// Anchors `anchor` was syndicated from, closest first, following at most `max_depth` links.
+        fn syndication_sources(anchor: ContentHash, max_depth: u32) -> Vec<ContentHash>;
+        /// Anchors republishing `anchor`, directly or transitively, up to `max_results` entries.
+        fn syndication_reach(anchor: ContentHash, max_results: u32) -> Vec<ContentHash>;
+        /// Hashes cited by `content_hash`, if it declared citations.
+        fn citations_of(content_hash: ContentHash) -> Vec<ContentHash>;
+        /// `(citing, cited)` edges reachable from `root` within `depth` hops. With `outgoing` the
+        /// walk follows what `root` cites, otherwise who cites it. Bounded by `max_edges`.
+        fn citation_graph(
+            root: ContentHash,
+            depth: u32,
+            outgoing: bool,
+            max_edges: u32,
+        ) -> Vec<(ContentHash, ContentHash)>;
+        /// Versions in the lineage of `anchor` with a revealed draft commitment, and the block
+        /// each commitment was anchored at.
+        fn priority_proofs(anchor: ContentHash) -> Vec<(ContentHash, BlockNumber)>;
+        /// Whether the live page at the canonical URL of `content_hash` still matches it, as of
+        /// the last accepted integrity report.
+        fn integrity_status(content_hash: ContentHash) -> IntegrityStatus<BlockNumber>;
+        /// Nonce the next signature `publisher` hands to a relayer or submits unsigned must carry.
+        fn publisher_nonce(publisher: AccountId) -> u64;
+        /// Whether `content_hash` was signed by its publisher, a signing key, or a signing key
+        /// revoked since; `None` if it is not recorded.
+        fn signature_status(content_hash: ContentHash) -> Option<SignatureStatus>;
+        /// Whether `leaf` is part of the batch its publisher anchored as `merkle_root`, given the
+        /// sibling hashes from the leaf up to the root.
+        fn verify_inclusion(merkle_root: H256, leaf: BatchLeaf<AccountId>, proof: Vec<H256>) -> bool;
+        /// Version hashes of history page `page` of `anchor`, oldest first. Pages below
+        /// `HistoryPageCount` are archived; the next one is the current `HistoryByAnchor` list.
+        fn history_page(anchor: ContentHash, page: u32) -> Vec<ContentHash>;
+        /// Versions of the lineage of `anchor` its publisher marked as substantive corrections,
+        /// oldest first.
+        fn substantive_corrections(anchor: ContentHash) -> Vec<ContentHash>;
+    }
+
+    /// Tips sent through `pallet_news`.
+    pub trait TipsApi<AccountId, Balance> where AccountId: Codec, Balance: Codec {
+        /// Tips sent to the lineage of `content_hash`, which may be any of its versions.
+        fn article_tips(content_hash: ContentHash) -> TipTally<Balance>;
+        /// Tips sent to the lineages of `publisher`, co-author shares included.
+        fn publisher_tips(publisher: AccountId) -> TipTally<Balance>;
+    }
+}
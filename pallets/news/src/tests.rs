@@ -6,21 +6,124 @@ use crate::{
     HistoryByAnchor,
     ArticleUpdateCount,
     RootByItem,
+    Endorsements,
+    EndorsementTallies,
+    FactCheckOf,
+    RetractedAt,
+    SyndicatedFrom,
+    SyndicatedTo,
+    CitationsOf,
+    CitedBy,
+    Embargoes,
+    EmbargoTerms,
+    ReleaseQueue,
+    DraftCommitments,
+    PriorityOf,
+    IntegrityStatusOf,
+    IntegrityStatus,
+    IntegrityState,
+    IntegrityObservation,
+    IntegrityReport,
+    IntegrityReportOf,
+    crypto::KEY_TYPE,
+    FeelessAnchorsInEra,
+    FeelessPublishers,
+    SponsorBudgets,
+    FreeAnchorsInSession,
+    SponsoredJournalists,
+    EthereumAddressOf,
+    PublisherOfEthereumAddress,
+    PublisherSignature,
+    PublisherSignatureOf,
+    ArticlePayload,
+    ArticlePayloadOf,
+    EcdsaSignature,
+    PublisherNonces,
+    SigningKeys,
+    SignatureStatus,
+    Batches,
+    BatchOf,
+    BatchLeaf,
+    batch_merkle_root,
+    batch_merkle_proof,
+    Tombstones,
+    PendingRemovals,
+    HistoryPages,
+    HistoryPageCount,
+    ChangeKind,
+    ChangeSummary,
+    VersionChanges,
+    CorrectionsOf,
+    CoAuthor,
+    Bylines,
+    TipsByAnchor,
+    TipsByPublisher,
+    TipTally,
+    RemovalReason,
+    CheckProvenanceSpace,
     Error, 
-    HashAlgo 
+    HashAlgo,
+    Sentiment,
+    FactCheckVerdict,
+    HoldReason,
+    points,
 };
 use frame::testing_prelude::*;
-use frame::deps::sp_core::{ sr25519, Pair, H256 };
-use frame::deps::sp_runtime::{ AccountId32, MultiSignature };
+use frame::deps::frame_support::dispatch::{ DispatchClass, DispatchInfo, GetDispatchInfo, Pays };
+use frame::deps::sp_core::{ ecdsa, sr25519, Pair, H160, H256 };
+use frame::deps::sp_runtime::{
+    traits::{ IdentifyAccount, TransactionExtension, ValidateUnsigned },
+    transaction_validity::{ InvalidTransaction, TransactionSource, TransactionValidity },
+    AccountId32,
+    MultiSignature,
+    MultiSigner,
+    Perbill,
+};
+use frame::traits::{ fungible::{ InspectHold, Mutate }, fungibles, UnfilteredDispatchable };
+use frame::deps::sp_core::offchain::{
+    testing::{ OffchainState, PendingRequest, TestOffchainExt, TestTransactionPoolExt },
+    OffchainDbExt,
+    OffchainWorkerExt,
+    TransactionPoolExt,
+};
+use frame::deps::sp_io::{
+    crypto::{ secp256k1_ecdsa_recover, sr25519_generate, sr25519_sign },
+    hashing::{ blake2_256, keccak_256 },
+};
 
-fn make_test_signature(pair: &sr25519::Pair, hash: &H256) -> MultiSignature {
+fn make_test_signature(pair: &sr25519::Pair, hash: &H256) -> PublisherSignatureOf<Test> {
     // Wrap the hash in <Bytes></Bytes> tags for signature verification
     let mut wrapped_msg = b"<Bytes>".to_vec();
     wrapped_msg.extend_from_slice(hash.as_bytes());
     wrapped_msg.extend_from_slice(b"</Bytes>");
     
     let sig = pair.sign(&wrapped_msg[..]);
-    MultiSignature::from(sig)
+    MultiSignature::from(sig).into()
+}
+
+fn fund(who: &AccountId32, amount: u64) {
+    <Balances as Mutate<AccountId32>>::set_balance(who, amount);
+}
+
+/// Records the default test article signed by `pair` and returns its publisher.
+fn record_default_article(pair: &sr25519::Pair) -> AccountId32 {
+    let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+    let publisher: AccountId32 = pair.public().into();
+    assert_ok!(
+        News::record_article(
+            RuntimeOrigin::signed(publisher.clone()),
+            content_hash,
+            collection_id,
+            item_id,
+            title,
+            canonical_url,
+            make_test_signature(pair, &content_hash),
+            HashAlgo::Blake2b256,
+            word_count,
+            None
+        )
+    );
+    publisher
 }
 
 fn create_test_article_data() -> (
@@ -60,7 +163,8 @@ fn record_article_works() {
                 canonical_url.clone(),
                 signature.clone(),
                 HashAlgo::Blake2b256,
-                word_count
+                word_count,
+                None
             )
         );
 
@@ -103,7 +207,8 @@ fn record_article_works() {
                 canonical_url,
                 signature,
                 HashAlgo::Sha256,
-                word_count
+                word_count,
+                None
             ),
             Error::<Test>::ArticleAlreadyExists
         );
@@ -129,7 +234,8 @@ fn update_article_works() {
                 canonical_url.clone(),
                 signature,
                 HashAlgo::Blake2b256,
-                word_count
+                word_count,
+                None
             )
         );
 
@@ -150,7 +256,9 @@ fn update_article_works() {
                 new_canonical_url.clone(),
                 new_signature,
                 HashAlgo::Blake2b256,
-                new_word_count
+                new_word_count,
+                ChangeKind::Minor,
+                None
             )
         );
 
@@ -211,7 +319,8 @@ fn update_article_fails_for_non_publisher() {
                 canonical_url.clone(),
                 signature,
                 HashAlgo::Blake2b256,
-                word_count
+                word_count,
+                None
             )
         );
 
@@ -228,7 +337,9 @@ fn update_article_fails_for_non_publisher() {
                 canonical_url,
                 new_signature,
                 HashAlgo::Blake2b256,
-                456
+                456,
+                ChangeKind::Minor,
+                None
             ),
             Error::<Test>::NotArticlePublisher
         );
@@ -254,7 +365,8 @@ fn multiple_updates_create_proper_history() {
                 canonical_url.clone(),
                 signature,
                 HashAlgo::Blake2b256,
-                word_count
+                word_count,
+                None
             )
         );
 
@@ -274,7 +386,9 @@ fn multiple_updates_create_proper_history() {
                 canonical_url.clone(),
                 sig2,
                 HashAlgo::Blake2b256,
-                200
+                200,
+                ChangeKind::Minor,
+                None
             )
         );
 
@@ -288,7 +402,9 @@ fn multiple_updates_create_proper_history() {
                 canonical_url.clone(),
                 sig3,
                 HashAlgo::Blake2b256,
-                300
+                300,
+                ChangeKind::Minor,
+                None
             )
         );
 
@@ -340,7 +456,8 @@ fn multiple_publishers_tracked_separately() {
                 canonical_url.clone(),
                 sig1,
                 HashAlgo::Blake2b256,
-                word_count
+                word_count,
+                None
             )
         );
 
@@ -358,7 +475,8 @@ fn multiple_publishers_tracked_separately() {
                 canonical_url.clone(),
                 sig2,
                 HashAlgo::Blake2b256,
-                word_count
+                word_count,
+                None
             )
         );
 
@@ -376,7 +494,8 @@ fn multiple_publishers_tracked_separately() {
                 canonical_url.clone(),
                 sig3,
                 HashAlgo::Blake2b256,
-                word_count
+                word_count,
+                None
             )
         );
 
@@ -415,7 +534,8 @@ fn signature_verification_works() {
                 canonical_url.clone(),
                 wrong_signature,
                 HashAlgo::Blake2b256,
-                word_count
+                word_count,
+                None
             ),
             Error::<Test>::SignatureInvalid
         );
@@ -432,7 +552,8 @@ fn signature_verification_works() {
                 canonical_url,
                 correct_signature,
                 HashAlgo::Blake2b256,
-                word_count
+                word_count,
+                None
             )
         );
     });
@@ -457,7 +578,9 @@ fn update_nonexistent_article_fails() {
                 canonical_url,
                 new_signature,
                 HashAlgo::Blake2b256,
-                456
+                456,
+                ChangeKind::Minor,
+                None
             ),
             Error::<Test>::ArticleNotFound
         );
@@ -483,7 +606,8 @@ fn update_with_existing_hash_fails() {
                 canonical_url.clone(),
                 signature,
                 HashAlgo::Blake2b256,
-                word_count
+                word_count,
+                None
             )
         );
 
@@ -498,9 +622,2805 @@ fn update_with_existing_hash_fails() {
                 canonical_url,
                 same_signature,
                 HashAlgo::Blake2b256,
-                456
+                456,
+                ChangeKind::Minor,
+                None
             ),
             Error::<Test>::ArticleAlreadyExists
         );
     });
 }
+
+
+#[test]
+fn endorse_article_holds_deposit_and_updates_reputation() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let content_hash = H256::repeat_byte(42);
+        let reader: AccountId32 = sr25519::Pair::from_seed(&[3u8; 32]).public().into();
+        fund(&reader, 1_000);
+
+        assert_ok!(News::endorse_article(RuntimeOrigin::signed(reader.clone()), content_hash, Sentiment::Endorse));
+
+        let endorsement = Endorsements::<Test>::get(content_hash, &reader).unwrap();
+        assert_eq!(endorsement.sentiment, Sentiment::Endorse);
+        assert_eq!(endorsement.deposit, 100);
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::EndorsementDeposit.into(), &reader),
+            100
+        );
+        assert_eq!(EndorsementTallies::<Test>::get(content_hash).endorsements, 1);
+        assert_eq!(News::reputation_of(&publisher), points::ENDORSEMENT);
+
+        // One endorsement per reader and version
+        assert_noop!(
+            News::endorse_article(RuntimeOrigin::signed(reader), content_hash, Sentiment::Flag),
+            Error::<Test>::AlreadyEndorsed
+        );
+    });
+}
+
+#[test]
+fn withdraw_endorsement_releases_deposit_and_reverts_points() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let content_hash = H256::repeat_byte(42);
+        let reader: AccountId32 = sr25519::Pair::from_seed(&[3u8; 32]).public().into();
+        fund(&reader, 1_000);
+
+        assert_ok!(News::endorse_article(RuntimeOrigin::signed(reader.clone()), content_hash, Sentiment::Flag));
+        assert_eq!(EndorsementTallies::<Test>::get(content_hash).flags, 1);
+        assert_eq!(News::reputation_of(&publisher), points::FLAG);
+
+        assert_ok!(News::withdraw_endorsement(RuntimeOrigin::signed(reader.clone()), content_hash));
+        assert!(Endorsements::<Test>::get(content_hash, &reader).is_none());
+        assert_eq!(EndorsementTallies::<Test>::get(content_hash).flags, 0);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::EndorsementDeposit.into(), &reader), 0);
+        assert_eq!(News::reputation_of(&publisher), 0);
+
+        assert_noop!(
+            News::withdraw_endorsement(RuntimeOrigin::signed(reader), content_hash),
+            Error::<Test>::EndorsementNotFound
+        );
+    });
+}
+
+#[test]
+fn endorse_article_rejects_publisher_and_unfunded_readers() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let content_hash = H256::repeat_byte(42);
+        fund(&publisher, 1_000);
+
+        assert_noop!(
+            News::endorse_article(RuntimeOrigin::signed(publisher), content_hash, Sentiment::Endorse),
+            Error::<Test>::CannotEndorseOwnArticle
+        );
+
+        // Reader without funds cannot place the deposit
+        let reader: AccountId32 = sr25519::Pair::from_seed(&[3u8; 32]).public().into();
+        assert!(News::endorse_article(RuntimeOrigin::signed(reader), content_hash, Sentiment::Endorse).is_err());
+
+        assert_noop!(
+            News::endorse_article(
+                RuntimeOrigin::signed(sr25519::Pair::from_seed(&[4u8; 32]).public().into()),
+                H256::repeat_byte(99),
+                Sentiment::Endorse
+            ),
+            Error::<Test>::ArticleNotFound
+        );
+    });
+}
+
+#[test]
+fn fact_checks_and_retractions_affect_reputation() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let content_hash = H256::repeat_byte(42);
+
+        assert_noop!(
+            News::submit_fact_check(RuntimeOrigin::signed(publisher.clone()), content_hash, FactCheckVerdict::Accurate),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(News::submit_fact_check(RuntimeOrigin::root(), content_hash, FactCheckVerdict::Misleading));
+        assert_eq!(
+            FactCheckOf::<Test>::get(content_hash).map(|check| check.verdict),
+            Some(FactCheckVerdict::Misleading)
+        );
+        assert_eq!(News::reputation_of(&publisher), points::FACT_CHECK_MISLEADING);
+
+        // Replacing a verdict reverts the previous one
+        assert_ok!(News::submit_fact_check(RuntimeOrigin::root(), content_hash, FactCheckVerdict::Accurate));
+        assert_eq!(News::reputation_of(&publisher), points::FACT_CHECK_ACCURATE);
+
+        assert_ok!(News::retract_article(RuntimeOrigin::signed(publisher.clone()), content_hash));
+        assert_eq!(RetractedAt::<Test>::get(content_hash), Some(1));
+        assert_eq!(News::reputation_of(&publisher), points::FACT_CHECK_ACCURATE + points::RETRACTION);
+
+        assert_noop!(
+            News::retract_article(RuntimeOrigin::signed(publisher), content_hash),
+            Error::<Test>::AlreadyRetracted
+        );
+    });
+}
+
+#[test]
+fn reputation_decays_over_time() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let content_hash = H256::repeat_byte(42);
+
+        assert_ok!(News::submit_fact_check(RuntimeOrigin::root(), content_hash, FactCheckVerdict::False));
+        assert_eq!(News::reputation_of(&publisher), points::FACT_CHECK_FALSE);
+
+        // Mock retains 50% per 100 blocks
+        System::set_block_number(101);
+        assert_eq!(News::reputation_of(&publisher), points::FACT_CHECK_FALSE / 2);
+        System::set_block_number(201);
+        assert_eq!(News::reputation_of(&publisher), points::FACT_CHECK_FALSE / 4);
+
+        // Writes continue from the decayed value
+        assert_ok!(News::retract_article(RuntimeOrigin::signed(publisher.clone()), content_hash));
+        assert_eq!(News::reputation_of(&publisher), points::FACT_CHECK_FALSE / 4 + points::RETRACTION);
+        assert_eq!(News::publisher_summary(&publisher).articles, 1);
+    });
+}
+
+#[test]
+fn reverted_points_decay_like_the_score() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let content_hash = H256::repeat_byte(42);
+        let reader: AccountId32 = sr25519::Pair::from_seed(&[3u8; 32]).public().into();
+        fund(&reader, 1_000);
+
+        // A flag withdrawn after it decayed leaves no net gain
+        assert_ok!(News::endorse_article(RuntimeOrigin::signed(reader.clone()), content_hash, Sentiment::Flag));
+        System::set_block_number(101);
+        assert_eq!(News::reputation_of(&publisher), points::FLAG / 2);
+        assert_ok!(News::withdraw_endorsement(RuntimeOrigin::signed(reader), content_hash));
+        assert_eq!(News::reputation_of(&publisher), 0);
+
+        // Replacing a decayed verdict only reverts what is left of it
+        assert_ok!(News::submit_fact_check(RuntimeOrigin::root(), content_hash, FactCheckVerdict::False));
+        System::set_block_number(201);
+        assert_eq!(News::reputation_of(&publisher), points::FACT_CHECK_FALSE / 2);
+        assert_ok!(News::submit_fact_check(RuntimeOrigin::root(), content_hash, FactCheckVerdict::Accurate));
+        assert_eq!(News::reputation_of(&publisher), points::FACT_CHECK_ACCURATE);
+    });
+}
+
+/// Republishes `original` as `new_hash`, signed by `pair`.
+fn syndicate(pair: &sr25519::Pair, original: H256, new_hash: H256, item_id: u128) -> DispatchResult {
+    let (_, collection_id, _, title, _, word_count) = create_test_article_data();
+    let canonical_url = BoundedVec::<u8, <Test as crate::Config>::MaxUrlLen>::try_from(b"https://wire.example".to_vec()).unwrap();
+    News::record_syndication(
+        RuntimeOrigin::signed(pair.public().into()),
+        original,
+        new_hash,
+        collection_id,
+        item_id,
+        title,
+        canonical_url,
+        make_test_signature(pair, &new_hash),
+        HashAlgo::Blake2b256,
+        word_count
+    )
+}
+
+#[test]
+fn record_syndication_links_new_anchor_to_original() {
+    new_test_ext().execute_with(|| {
+        let wire = sr25519::Pair::from_seed(&[1u8; 32]);
+        let outlet = sr25519::Pair::from_seed(&[2u8; 32]);
+        record_default_article(&wire);
+        let original = H256::repeat_byte(42);
+        let republished = H256::repeat_byte(50);
+
+        assert_ok!(syndicate(&outlet, original, republished, 10));
+
+        // The republished piece is a full anchor owned by the republisher
+        let outlet_account: AccountId32 = outlet.public().into();
+        let record = ArticleByHash::<Test>::get(republished).unwrap();
+        assert_eq!(record.publisher, outlet_account);
+        assert_eq!(AnchorOf::<Test>::get(republished), Some(republished));
+        assert_eq!(ArticlesByPublisher::<Test>::get(&outlet_account).to_vec(), vec![republished]);
+
+        // ...linked in both directions
+        assert_eq!(SyndicatedFrom::<Test>::get(republished), Some(original));
+        assert_eq!(SyndicatedTo::<Test>::get(original).to_vec(), vec![republished]);
+    });
+}
+
+#[test]
+fn record_syndication_resolves_versions_and_traces_reach() {
+    new_test_ext().execute_with(|| {
+        let wire = sr25519::Pair::from_seed(&[1u8; 32]);
+        let outlet = sr25519::Pair::from_seed(&[2u8; 32]);
+        let aggregator = sr25519::Pair::from_seed(&[3u8; 32]);
+        let wire_account = record_default_article(&wire);
+        let original = H256::repeat_byte(42);
+        let (_, _, _, title, canonical_url, _) = create_test_article_data();
+
+        // Update the original, then syndicate from the newer version
+        let v2 = H256::repeat_byte(43);
+        assert_ok!(
+            News::update_article(
+                RuntimeOrigin::signed(wire_account),
+                original,
+                v2,
+                title,
+                canonical_url,
+                make_test_signature(&wire, &v2),
+                HashAlgo::Blake2b256,
+                200,
+                ChangeKind::Minor,
+                None
+            )
+        );
+        let outlet_copy = H256::repeat_byte(50);
+        assert_ok!(syndicate(&outlet, v2, outlet_copy, 10));
+        assert_eq!(SyndicatedFrom::<Test>::get(outlet_copy), Some(original));
+
+        // A copy of the copy
+        let aggregator_copy = H256::repeat_byte(51);
+        assert_ok!(syndicate(&aggregator, outlet_copy, aggregator_copy, 11));
+
+        assert_eq!(News::syndication_sources(aggregator_copy, 10), vec![outlet_copy, original]);
+        assert_eq!(News::syndication_sources(aggregator_copy, 1), vec![outlet_copy]);
+        assert_eq!(News::syndication_reach(original, 10), vec![outlet_copy, aggregator_copy]);
+        assert_eq!(News::syndication_reach(original, 1), vec![outlet_copy]);
+    });
+}
+
+#[test]
+fn record_syndication_fails_for_unknown_original_or_bad_signature() {
+    new_test_ext().execute_with(|| {
+        let outlet = sr25519::Pair::from_seed(&[2u8; 32]);
+        assert_noop!(
+            syndicate(&outlet, H256::repeat_byte(99), H256::repeat_byte(50), 10),
+            Error::<Test>::ArticleNotFound
+        );
+
+        let wire = sr25519::Pair::from_seed(&[1u8; 32]);
+        record_default_article(&wire);
+        let (_, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+        let new_hash = H256::repeat_byte(50);
+        assert_noop!(
+            News::record_syndication(
+                RuntimeOrigin::signed(outlet.public().into()),
+                H256::repeat_byte(42),
+                new_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                make_test_signature(&wire, &new_hash),
+                HashAlgo::Blake2b256,
+                word_count
+            ),
+            Error::<Test>::SignatureInvalid
+        );
+    });
+}
+
+/// Records a minimal article `hash` signed by `pair` on its own NFT item.
+fn record_article_with_hash(pair: &sr25519::Pair, hash: H256, item_id: u128) {
+    let (_, collection_id, _, title, canonical_url, word_count) = create_test_article_data();
+    assert_ok!(
+        News::record_article(
+            RuntimeOrigin::signed(pair.public().into()),
+            hash,
+            collection_id,
+            item_id,
+            title,
+            canonical_url,
+            make_test_signature(pair, &hash),
+            HashAlgo::Blake2b256,
+            word_count,
+            None
+        )
+    );
+}
+
+fn citations(hashes: &[H256]) -> BoundedVec<H256, <Test as crate::Config>::MaxCitations> {
+    BoundedVec::try_from(hashes.to_vec()).unwrap()
+}
+
+#[test]
+fn declare_citations_builds_forward_and_reverse_index() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let other = sr25519::Pair::from_seed(&[2u8; 32]);
+        let (a, b, c) = (H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3));
+        record_article_with_hash(&pair, a, 1);
+        record_article_with_hash(&other, b, 2);
+        record_article_with_hash(&pair, c, 3);
+
+        assert_ok!(News::declare_citations(RuntimeOrigin::signed(pair.public().into()), c, citations(&[a, b])));
+        assert_eq!(CitationsOf::<Test>::get(c).unwrap().to_vec(), vec![a, b]);
+        assert_eq!(CitedBy::<Test>::get(a, c), Some(()));
+        assert_eq!(CitedBy::<Test>::get(b, c), Some(()));
+
+        // Declared once per version
+        assert_noop!(
+            News::declare_citations(RuntimeOrigin::signed(pair.public().into()), c, citations(&[a])),
+            Error::<Test>::CitationsAlreadyDeclared
+        );
+    });
+}
+
+#[test]
+fn declare_citations_validates_cited_hashes() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let other = sr25519::Pair::from_seed(&[2u8; 32]);
+        let (a, b) = (H256::repeat_byte(1), H256::repeat_byte(2));
+        record_article_with_hash(&pair, a, 1);
+        record_article_with_hash(&pair, b, 2);
+        let publisher: AccountId32 = pair.public().into();
+
+        assert_noop!(
+            News::declare_citations(RuntimeOrigin::signed(publisher.clone()), b, citations(&[H256::repeat_byte(9)])),
+            Error::<Test>::CitedArticleNotFound
+        );
+        assert_noop!(
+            News::declare_citations(RuntimeOrigin::signed(publisher.clone()), b, citations(&[b])),
+            Error::<Test>::SelfCitation
+        );
+        assert_noop!(
+            News::declare_citations(RuntimeOrigin::signed(publisher), b, citations(&[a, a])),
+            Error::<Test>::DuplicateCitation
+        );
+        assert_noop!(
+            News::declare_citations(RuntimeOrigin::signed(other.public().into()), b, citations(&[a])),
+            Error::<Test>::NotArticlePublisher
+        );
+    });
+}
+
+#[test]
+fn citation_graph_walks_to_given_depth() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let (a, b, c, d) = (H256::repeat_byte(1), H256::repeat_byte(2), H256::repeat_byte(3), H256::repeat_byte(4));
+        for (i, hash) in [a, b, c, d].into_iter().enumerate() {
+            record_article_with_hash(&pair, hash, i as u128);
+        }
+        // d -> c -> {a, b}, d -> a
+        assert_ok!(News::declare_citations(RuntimeOrigin::signed(publisher.clone()), c, citations(&[a, b])));
+        assert_ok!(News::declare_citations(RuntimeOrigin::signed(publisher), d, citations(&[c, a])));
+
+        assert_eq!(News::citation_graph(d, 1, true, 10), vec![(d, c), (d, a)]);
+        assert_eq!(News::citation_graph(d, 2, true, 10), vec![(d, c), (d, a), (c, a), (c, b)]);
+        assert_eq!(News::citation_graph(d, 2, true, 3).len(), 3);
+
+        let cited_by_a = News::citation_graph(a, 2, false, 10);
+        assert_eq!(cited_by_a.len(), 3);
+        assert!(cited_by_a.contains(&(c, a)));
+        assert!(cited_by_a.contains(&(d, a)));
+        assert!(cited_by_a.contains(&(d, c)));
+    });
+}
+
+/// Records `hash` under embargo until `release_at`, committing to `title`, `url` and `salt`.
+fn record_embargoed(pair: &sr25519::Pair, hash: H256, release_at: u64, title: &[u8], url: &[u8], salt: H256) -> DispatchResult {
+    News::record_article(
+        RuntimeOrigin::signed(pair.public().into()),
+        hash,
+        1,
+        2,
+        BoundedVec::default(),
+        BoundedVec::default(),
+        make_test_signature(pair, &hash),
+        HashAlgo::Blake2b256,
+        500,
+        Some(EmbargoTerms {
+            release_at,
+            metadata_commitment: News::metadata_commitment(title, url, &salt),
+        })
+    )
+}
+
+#[test]
+fn embargoed_article_is_lifted_and_revealed() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let hash = H256::repeat_byte(42);
+        let salt = H256::repeat_byte(7);
+        let (_, _, _, title, canonical_url, _) = create_test_article_data();
+
+        assert_ok!(record_embargoed(&pair, hash, 10, &title, &canonical_url, salt));
+
+        // Only the commitment is public; the commit block is kept as proof of time
+        let record = ArticleByHash::<Test>::get(hash).unwrap();
+        assert!(record.title.is_empty() && record.canonical_url.is_empty());
+        assert_eq!(record.last_updated_at, 1);
+        assert_eq!(ReleaseQueue::<Test>::get(10).to_vec(), vec![hash]);
+
+        // Too early
+        assert_noop!(
+            News::reveal_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                hash,
+                title.clone(),
+                canonical_url.clone(),
+                salt,
+                make_test_signature(&pair, &hash)
+            ),
+            Error::<Test>::EmbargoActive
+        );
+
+        // Lifted automatically at the release block
+        System::set_block_number(10);
+        News::on_initialize(10);
+        assert!(Embargoes::<Test>::get(hash).unwrap().lifted);
+        assert!(ReleaseQueue::<Test>::get(10).is_empty());
+
+        // Wrong salt does not match the commitment
+        let signature = sign_payload(
+            &pair,
+            &News::article_payload(hash, None, 1, 2, &title, &canonical_url, HashAlgo::Blake2b256, 500)
+        );
+        assert_noop!(
+            News::reveal_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                hash,
+                title.clone(),
+                canonical_url.clone(),
+                H256::zero(),
+                signature.clone()
+            ),
+            Error::<Test>::CommitmentMismatch
+        );
+
+        // The signature must cover the revealed metadata
+        let stale = sign_payload(
+            &pair,
+            &News::article_payload(hash, None, 1, 2, &[], &[], HashAlgo::Blake2b256, 500)
+        );
+        assert_noop!(
+            News::reveal_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                hash,
+                title.clone(),
+                canonical_url.clone(),
+                salt,
+                stale
+            ),
+            Error::<Test>::SignatureInvalid
+        );
+
+        assert_ok!(
+            News::reveal_article(
+                RuntimeOrigin::signed(publisher),
+                hash,
+                title.clone(),
+                canonical_url.clone(),
+                salt,
+                signature.clone()
+            )
+        );
+        let record = ArticleByHash::<Test>::get(hash).unwrap();
+        assert_eq!(record.title, title);
+        assert_eq!(record.canonical_url, canonical_url);
+        assert_eq!(record.signature, signature);
+        assert_eq!(record.last_updated_at, 1);
+        assert!(Embargoes::<Test>::get(hash).is_none());
+    });
+}
+
+#[test]
+fn embargo_terms_are_validated() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let hash = H256::repeat_byte(42);
+        let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+
+        assert_noop!(
+            record_embargoed(&pair, hash, 1, b"t", b"u", H256::zero()),
+            Error::<Test>::EmbargoReleaseInPast
+        );
+        assert_noop!(
+            News::record_article(
+                RuntimeOrigin::signed(pair.public().into()),
+                content_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                make_test_signature(&pair, &content_hash),
+                HashAlgo::Blake2b256,
+                word_count,
+                Some(EmbargoTerms { release_at: 10, metadata_commitment: H256::zero() })
+            ),
+            Error::<Test>::EmbargoedMetadataNotEmpty
+        );
+    });
+}
+
+#[test]
+fn embargoed_article_cannot_be_updated_before_reveal() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let hash = H256::repeat_byte(42);
+        let new_hash = H256::repeat_byte(43);
+        assert_ok!(record_embargoed(&pair, hash, 10, b"t", b"u", H256::zero()));
+
+        let (_, _, _, title, canonical_url, _) = create_test_article_data();
+        assert_noop!(
+            News::update_article(
+                RuntimeOrigin::signed(pair.public().into()),
+                hash,
+                new_hash,
+                title,
+                canonical_url,
+                make_test_signature(&pair, &new_hash),
+                HashAlgo::Blake2b256,
+                600,
+                ChangeKind::Minor,
+                None
+            ),
+            Error::<Test>::ArticleEmbargoed
+        );
+    });
+}
+
+#[test]
+fn draft_commitment_is_revealed_and_linked_to_lineage() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let journalist: AccountId32 = pair.public().into();
+        fund(&journalist, 1_000);
+        let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+        let salt = H256::repeat_byte(7);
+        let commitment = News::draft_commitment(&journalist, &content_hash, &salt);
+
+        // Commit at block 1; nothing but the commitment is on chain
+        assert_ok!(News::commit_draft(RuntimeOrigin::signed(journalist.clone()), commitment));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::DraftDeposit.into(), &journalist), 50);
+        assert_noop!(
+            News::commit_draft(RuntimeOrigin::signed(journalist.clone()), commitment),
+            Error::<Test>::DraftAlreadyCommitted
+        );
+
+        // Publish later
+        System::set_block_number(5);
+        assert_ok!(
+            News::record_article(
+                RuntimeOrigin::signed(journalist.clone()),
+                content_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                make_test_signature(&pair, &content_hash),
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            )
+        );
+
+        assert_noop!(
+            News::reveal_draft(RuntimeOrigin::signed(journalist.clone()), commitment, H256::zero(), content_hash),
+            Error::<Test>::DraftCommitmentMismatch
+        );
+        assert_ok!(News::reveal_draft(RuntimeOrigin::signed(journalist.clone()), commitment, salt, content_hash));
+
+        assert!(DraftCommitments::<Test>::get(&journalist, commitment).is_none());
+        assert_eq!(Balances::balance_on_hold(&HoldReason::DraftDeposit.into(), &journalist), 0);
+        let proof = PriorityOf::<Test>::get(content_hash).unwrap();
+        assert_eq!(proof.commitment, commitment);
+        assert_eq!(proof.committed_at, 1);
+
+        let proofs = News::priority_proofs(content_hash);
+        assert_eq!(proofs.len(), 1);
+        assert_eq!(proofs[0].0, content_hash);
+    });
+}
+
+#[test]
+fn draft_reveal_requires_owner_and_own_article() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let other = sr25519::Pair::from_seed(&[2u8; 32]);
+        let journalist: AccountId32 = pair.public().into();
+        let rival: AccountId32 = other.public().into();
+        fund(&journalist, 1_000);
+        fund(&rival, 1_000);
+
+        // The rival publishes an article and commits to its hash
+        let rival_hash = H256::repeat_byte(60);
+        record_article_with_hash(&other, rival_hash, 1);
+        let salt = H256::repeat_byte(7);
+        let commitment = News::draft_commitment(&journalist, &rival_hash, &salt);
+        assert_ok!(News::commit_draft(RuntimeOrigin::signed(journalist.clone()), commitment));
+
+        assert_noop!(
+            News::reveal_draft(RuntimeOrigin::signed(rival.clone()), commitment, salt, rival_hash),
+            Error::<Test>::DraftNotFound
+        );
+
+        // A copy of the commitment does not block the journalist and cannot be opened by the rival
+        assert_ok!(News::commit_draft(RuntimeOrigin::signed(rival.clone()), commitment));
+        assert_noop!(
+            News::reveal_draft(RuntimeOrigin::signed(rival), commitment, salt, rival_hash),
+            Error::<Test>::DraftCommitmentMismatch
+        );
+        assert_noop!(
+            News::reveal_draft(RuntimeOrigin::signed(journalist), commitment, salt, rival_hash),
+            Error::<Test>::NotArticlePublisher
+        );
+    });
+}
+
+#[test]
+fn expired_draft_is_removed_and_refunded() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let journalist: AccountId32 = pair.public().into();
+        let anyone: AccountId32 = sr25519::Pair::from_seed(&[2u8; 32]).public().into();
+        fund(&journalist, 1_000);
+        let commitment = News::draft_commitment(&journalist, &H256::repeat_byte(42), &H256::repeat_byte(7));
+        assert_ok!(News::commit_draft(RuntimeOrigin::signed(journalist.clone()), commitment));
+
+        // Lifetime is 20 blocks in the mock
+        System::set_block_number(21);
+        assert_noop!(
+            News::remove_expired_draft(RuntimeOrigin::signed(anyone.clone()), journalist.clone(), commitment),
+            Error::<Test>::DraftNotExpired
+        );
+
+        System::set_block_number(22);
+        assert_noop!(
+            News::reveal_draft(RuntimeOrigin::signed(journalist.clone()), commitment, H256::repeat_byte(7), H256::repeat_byte(42)),
+            Error::<Test>::DraftExpired
+        );
+        assert_ok!(News::remove_expired_draft(RuntimeOrigin::signed(anyone), journalist.clone(), commitment));
+        assert!(DraftCommitments::<Test>::get(&journalist, commitment).is_none());
+        assert_eq!(Balances::balance_on_hold(&HoldReason::DraftDeposit.into(), &journalist), 0);
+        assert_eq!(Balances::free_balance(&journalist), 1_000);
+    });
+}
+
+const PAGE: &[u8] = b"<html>Anchored article body</html>";
+
+/// Records an article whose content hash is the Blake2 hash of `PAGE`, served at `https://example.com`.
+fn record_page_article(pair: &sr25519::Pair) -> H256 {
+    let content_hash = H256(blake2_256(PAGE));
+    record_article_with_hash(pair, content_hash, 1);
+    content_hash
+}
+
+/// Generates an integrity reporter key in the test keystore.
+fn reporter_key() -> sr25519::Public {
+    sr25519_generate(KEY_TYPE, None)
+}
+
+fn reporter_account(public: &sr25519::Public) -> AccountId32 {
+    MultiSigner::Sr25519(*public).into_account()
+}
+
+fn signed_report(
+    public: &sr25519::Public,
+    content_hash: H256,
+    checked_at: u64
+) -> (IntegrityReportOf<Test>, MultiSignature) {
+    let report = IntegrityReport {
+        content_hash,
+        observation: IntegrityObservation::Unreachable { http_status: Some(404) },
+        checked_at,
+        public: MultiSigner::Sr25519(*public),
+    };
+    let signature = report.using_encoded(|payload| sr25519_sign(KEY_TYPE, public, payload).unwrap());
+    (report, MultiSignature::Sr25519(signature))
+}
+
+fn expect_get(state: &mut OffchainState, body: &[u8]) {
+    state.expect_request(PendingRequest {
+        method: "GET".into(),
+        uri: "https://example.com".into(),
+        response: Some(body.to_vec()),
+        sent: true,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn offchain_worker_reports_silently_changed_page() {
+    let (offchain, state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let content_hash = record_page_article(&pair);
+        let reporter = reporter_key();
+        assert_ok!(News::add_integrity_watcher(RuntimeOrigin::root(), reporter_account(&reporter)));
+
+        // Not a check block
+        System::set_block_number(9);
+        News::offchain_worker(9);
+        assert!(pool_state.read().transactions.is_empty());
+
+        // Page was edited after anchoring
+        let edited = b"<html>Edited article body</html>";
+        expect_get(&mut state.write(), edited);
+        System::set_block_number(10);
+        News::offchain_worker(10);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = MockUncheckedExtrinsic::<Test>::decode(&mut &*tx).unwrap();
+        let RuntimeCall::News(call) = tx.function else {
+            panic!("unexpected call");
+        };
+        let crate::Call::submit_integrity_report { report, signature } = call.clone() else {
+            panic!("unexpected call");
+        };
+        assert_eq!(
+            report,
+            IntegrityReport {
+                content_hash,
+                observation: IntegrityObservation::Changed { observed: H256(blake2_256(edited)) },
+                checked_at: 10,
+                public: MultiSigner::Sr25519(reporter),
+            }
+        );
+        assert!(
+            <News as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call).is_ok()
+        );
+
+        // A report altered after signing is rejected on dispatch too
+        let forged = IntegrityReport { observation: IntegrityObservation::Matches, ..report.clone() };
+        assert_noop!(
+            News::submit_integrity_report(RuntimeOrigin::none(), forged, signature.clone()),
+            Error::<Test>::InvalidIntegrityReport
+        );
+
+        // Applying the report updates the status and rate-limits further reports
+        assert_ok!(News::submit_integrity_report(RuntimeOrigin::none(), report.clone(), signature.clone()));
+        assert_eq!(
+            IntegrityStatusOf::<Test>::get(content_hash),
+            IntegrityStatus {
+                state: IntegrityState::Changed { observed: H256(blake2_256(edited)) },
+                last_checked: Some(10),
+            }
+        );
+        assert_noop!(
+            News::submit_integrity_report(RuntimeOrigin::none(), report, signature),
+            Error::<Test>::InvalidIntegrityReport
+        );
+    });
+}
+
+#[test]
+fn offchain_worker_reports_matching_page_for_session_reporter() {
+    let (offchain, state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let content_hash = record_page_article(&pair);
+        let reporter = reporter_key();
+        SessionReporters::set(vec![reporter_account(&reporter)]);
+
+        expect_get(&mut state.write(), PAGE);
+        System::set_block_number(10);
+        News::offchain_worker(10);
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = MockUncheckedExtrinsic::<Test>::decode(&mut &*tx).unwrap();
+        let RuntimeCall::News(crate::Call::submit_integrity_report { report, signature }) = tx.function else {
+            panic!("unexpected call");
+        };
+        assert_eq!(report.observation, IntegrityObservation::Matches);
+
+        assert_ok!(News::submit_integrity_report(RuntimeOrigin::none(), report, signature));
+        assert_eq!(IntegrityStatusOf::<Test>::get(content_hash).state, IntegrityState::Matches);
+        SessionReporters::set(Vec::new());
+    });
+}
+
+#[test]
+fn offchain_worker_needs_authorized_key() {
+    let (offchain, _state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let content_hash = record_page_article(&pair);
+        reporter_key();
+
+        // No request is expected: an unexpected fetch would panic
+        System::set_block_number(10);
+        News::offchain_worker(10);
+
+        assert!(pool_state.read().transactions.is_empty());
+        assert_eq!(IntegrityStatusOf::<Test>::get(content_hash), IntegrityStatus::default());
+    });
+}
+
+#[test]
+fn integrity_reports_are_validated() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let content_hash = record_page_article(&pair);
+        let reporter = reporter_key();
+        System::set_block_number(30);
+        let validate = |(report, signature)| {
+            <News as ValidateUnsigned>::validate_unsigned(
+                TransactionSource::External,
+                &crate::Call::submit_integrity_report { report, signature }
+            )
+        };
+
+        // Unregistered reporter
+        assert_eq!(
+            validate(signed_report(&reporter, content_hash, 30)),
+            InvalidTransaction::Custom(crate::INVALID_REPORT_UNAUTHORIZED).into()
+        );
+        assert_ok!(News::add_integrity_watcher(RuntimeOrigin::root(), reporter_account(&reporter)));
+
+        assert_eq!(validate(signed_report(&reporter, content_hash, 31)), InvalidTransaction::Future.into());
+        assert_eq!(validate(signed_report(&reporter, content_hash, 19)), InvalidTransaction::Stale.into());
+        assert_eq!(
+            validate(signed_report(&reporter, H256::repeat_byte(9), 30)),
+            InvalidTransaction::Custom(crate::INVALID_REPORT_UNKNOWN_ARTICLE).into()
+        );
+
+        // Signature over a different report
+        let (report, _) = signed_report(&reporter, content_hash, 20);
+        let (_, other_signature) = signed_report(&reporter, content_hash, 21);
+        assert_eq!(validate((report, other_signature)), InvalidTransaction::BadProof.into());
+
+        assert!(validate(signed_report(&reporter, content_hash, 20)).is_ok());
+
+        // Only unsigned integrity reports are accepted
+        assert_eq!(
+            <News as ValidateUnsigned>::validate_unsigned(
+                TransactionSource::External,
+                &crate::Call::retract_article { content_hash }
+            ),
+            InvalidTransaction::Call.into()
+        );
+    });
+}
+
+#[test]
+fn integrity_watchers_are_managed_by_origin() {
+    new_test_ext().execute_with(|| {
+        let watcher = AccountId32::new([7u8; 32]);
+
+        assert_noop!(
+            News::add_integrity_watcher(RuntimeOrigin::signed(watcher.clone()), watcher.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(News::add_integrity_watcher(RuntimeOrigin::root(), watcher.clone()));
+        assert!(News::is_integrity_reporter(&watcher));
+        assert_noop!(
+            News::add_integrity_watcher(RuntimeOrigin::root(), watcher.clone()),
+            Error::<Test>::AlreadyIntegrityWatcher
+        );
+
+        assert_ok!(News::remove_integrity_watcher(RuntimeOrigin::root(), watcher.clone()));
+        assert!(!News::is_integrity_reporter(&watcher));
+        assert_noop!(
+            News::remove_integrity_watcher(RuntimeOrigin::root(), watcher),
+            Error::<Test>::NotIntegrityWatcher
+        );
+    });
+}
+
+/// Unsigned `record_article_unsigned` call for `hash`, signed by `pair`.
+fn feeless_anchor(pair: &sr25519::Pair, hash: H256, item_id: u128) -> crate::Call<Test> {
+    let (_, collection_id, _, title, canonical_url, word_count) = create_test_article_data();
+    let publisher: AccountId32 = pair.public().into();
+    let nonce = PublisherNonces::<Test>::get(&publisher);
+    let payload = ArticlePayload {
+        nonce: Some(nonce),
+        ..News::article_payload(
+            hash,
+            None,
+            collection_id,
+            item_id,
+            &title,
+            &canonical_url,
+            HashAlgo::Blake2b256,
+            word_count
+        )
+    };
+    crate::Call::record_article_unsigned {
+        publisher,
+        content_hash: hash,
+        collection_id,
+        item_id,
+        title,
+        canonical_url,
+        signature: sign_payload(pair, &payload),
+        hash_algo: HashAlgo::Blake2b256,
+        word_count,
+        nonce,
+    }
+}
+
+fn validate_feeless(call: &crate::Call<Test>) -> TransactionValidity {
+    <News as ValidateUnsigned>::validate_unsigned(TransactionSource::External, call)
+}
+
+fn dispatch_feeless(call: crate::Call<Test>) -> DispatchResultWithPostInfo {
+    call.dispatch_bypass_filter(RuntimeOrigin::none())
+}
+
+#[test]
+fn feeless_anchor_requires_approval_and_valid_signature() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let hash = H256::repeat_byte(42);
+        let call = feeless_anchor(&pair, hash, 1);
+
+        assert_eq!(
+            validate_feeless(&call),
+            InvalidTransaction::Custom(crate::INVALID_ANCHOR_NOT_APPROVED).into()
+        );
+        assert_noop!(dispatch_feeless(call.clone()), Error::<Test>::NotFeelessPublisher);
+
+        assert_ok!(News::approve_feeless_publisher(RuntimeOrigin::root(), publisher.clone()));
+
+        // Signed by someone else
+        let other = sr25519::Pair::from_seed(&[2u8; 32]);
+        let mut forged = call.clone();
+        if let crate::Call::record_article_unsigned { signature, .. } = &mut forged {
+            *signature = make_test_signature(&other, &hash);
+        }
+        assert_eq!(validate_feeless(&forged), InvalidTransaction::BadProof.into());
+
+        // Block 1 of a 10-block era: valid for the rest of the era
+        let valid = validate_feeless(&call).unwrap();
+        assert_eq!(valid.priority, 0);
+        assert_eq!(valid.longevity, 9);
+
+        // The publisher holds no funds
+        assert_ok!(dispatch_feeless(call.clone()));
+        assert_eq!(ArticleByHash::<Test>::get(hash).unwrap().publisher, publisher);
+        assert_eq!(Balances::free_balance(&publisher), 0);
+        assert_eq!(FeelessAnchorsInEra::<Test>::get(&publisher), (0, 1));
+
+        // Already anchored
+        assert_eq!(validate_feeless(&call), InvalidTransaction::Stale.into());
+    });
+}
+
+#[test]
+fn feeless_anchor_signature_cannot_be_reused_with_other_metadata() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        assert_ok!(News::approve_feeless_publisher(RuntimeOrigin::root(), publisher.clone()));
+        let call = feeless_anchor(&pair, H256::repeat_byte(42), 1);
+
+        // A pending signature copied from the pool, resubmitted with another title, URL or item
+        let mut retitled = call.clone();
+        if let crate::Call::record_article_unsigned { title, .. } = &mut retitled {
+            *title = BoundedVec::try_from(b"Forged title".to_vec()).unwrap();
+        }
+        let mut redirected = call.clone();
+        if let crate::Call::record_article_unsigned { canonical_url, .. } = &mut redirected {
+            *canonical_url = BoundedVec::try_from(b"https://forged.example".to_vec()).unwrap();
+        }
+        let mut moved = call.clone();
+        if let crate::Call::record_article_unsigned { item_id, .. } = &mut moved {
+            *item_id = 2;
+        }
+        for forged in [retitled, redirected, moved] {
+            assert_eq!(validate_feeless(&forged), InvalidTransaction::BadProof.into());
+            assert!(dispatch_feeless(forged).is_err());
+        }
+
+        // The publisher's quota is untouched and the original still goes through
+        assert_eq!(FeelessAnchorsInEra::<Test>::get(&publisher), (0, 0));
+        assert_ok!(dispatch_feeless(call));
+    });
+}
+
+#[test]
+fn feeless_anchors_are_rate_limited_per_era() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        assert_ok!(News::approve_feeless_publisher(RuntimeOrigin::root(), publisher.clone()));
+
+        assert_ok!(dispatch_feeless(feeless_anchor(&pair, H256::repeat_byte(1), 1)));
+        assert_ok!(dispatch_feeless(feeless_anchor(&pair, H256::repeat_byte(2), 2)));
+
+        let third = feeless_anchor(&pair, H256::repeat_byte(3), 3);
+        assert_eq!(
+            validate_feeless(&third),
+            InvalidTransaction::Custom(crate::INVALID_ANCHOR_QUOTA_EXHAUSTED).into()
+        );
+        assert_noop!(dispatch_feeless(third.clone()), Error::<Test>::FeelessQuotaExhausted);
+
+        // The quota resets with the next era
+        System::set_block_number(10);
+        assert!(validate_feeless(&third).is_ok());
+        assert_ok!(dispatch_feeless(third));
+        assert_eq!(FeelessAnchorsInEra::<Test>::get(&publisher), (1, 1));
+    });
+}
+
+#[test]
+fn pending_feeless_anchors_share_a_pool_slot() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        assert_ok!(News::approve_feeless_publisher(RuntimeOrigin::root(), publisher));
+
+        let first = validate_feeless(&feeless_anchor(&pair, H256::repeat_byte(1), 1)).unwrap();
+        let second = validate_feeless(&feeless_anchor(&pair, H256::repeat_byte(2), 2)).unwrap();
+
+        // Different anchors, but both claim the publisher's next slot in the era
+        assert_ne!(first.provides[0], second.provides[0]);
+        assert_eq!(first.provides[1], second.provides[1]);
+    });
+}
+
+#[test]
+fn feeless_publishers_are_managed_by_origin() {
+    new_test_ext().execute_with(|| {
+        let publisher = AccountId32::new([7u8; 32]);
+
+        assert_noop!(
+            News::approve_feeless_publisher(RuntimeOrigin::signed(publisher.clone()), publisher.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(News::approve_feeless_publisher(RuntimeOrigin::root(), publisher.clone()));
+        assert!(FeelessPublishers::<Test>::contains_key(&publisher));
+        assert_noop!(
+            News::approve_feeless_publisher(RuntimeOrigin::root(), publisher.clone()),
+            Error::<Test>::AlreadyFeelessPublisher
+        );
+
+        assert_ok!(News::revoke_feeless_publisher(RuntimeOrigin::root(), publisher.clone()));
+        assert!(!FeelessPublishers::<Test>::contains_key(&publisher));
+        assert_noop!(
+            News::revoke_feeless_publisher(RuntimeOrigin::root(), publisher),
+            Error::<Test>::NotFeelessPublisher
+        );
+    });
+}
+
+#[test]
+fn newsroom_sponsors_journalist_fees_within_budget_and_quota() {
+    new_test_ext().execute_with(|| {
+        let newsroom = AccountId32::new([1u8; 32]);
+        let journalist = AccountId32::new([2u8; 32]);
+
+        // No sponsorship yet
+        assert_eq!(News::sponsor_for(&journalist, 10), None);
+
+        assert_ok!(News::set_sponsorship_budget(RuntimeOrigin::signed(newsroom.clone()), 100));
+        assert_ok!(
+            News::sponsor_journalist(RuntimeOrigin::signed(newsroom.clone()), journalist.clone(), 30)
+        );
+        assert_eq!(News::sponsor_for(&journalist, 30), Some(newsroom.clone()));
+        // Beyond the journalist's quota
+        assert_eq!(News::sponsor_for(&journalist, 31), None);
+
+        // 25 withdrawn up front, 15 actually due after dispatch
+        News::charge_sponsorship(&journalist, &newsroom, 25);
+        assert_eq!(SponsorBudgets::<Test>::get(&newsroom), 75);
+        assert_eq!(SponsoredJournalists::<Test>::get(&journalist).unwrap().quota, 5);
+        News::settle_sponsorship(&journalist, &newsroom, 25, 15);
+        assert_eq!(SponsorBudgets::<Test>::get(&newsroom), 85);
+        assert_eq!(SponsoredJournalists::<Test>::get(&journalist).unwrap().quota, 15);
+        System::assert_last_event(
+            crate::Event::FeeSponsored { sponsor: newsroom.clone(), journalist: journalist.clone(), fee: 15 }.into()
+        );
+
+        // A fee refunded in full is not reported as sponsored
+        let events = System::events().len();
+        News::charge_sponsorship(&journalist, &newsroom, 5);
+        News::settle_sponsorship(&journalist, &newsroom, 5, 0);
+        assert_eq!(System::events().len(), events);
+        assert_eq!(SponsorBudgets::<Test>::get(&newsroom), 85);
+
+        // Beyond the newsroom's budget
+        assert_ok!(News::set_sponsorship_budget(RuntimeOrigin::signed(newsroom.clone()), 10));
+        assert_eq!(News::sponsor_for(&journalist, 15), None);
+        assert_eq!(News::sponsor_for(&journalist, 10), Some(newsroom));
+    });
+}
+
+#[test]
+fn sponsorships_are_exclusive_and_revocable() {
+    new_test_ext().execute_with(|| {
+        let newsroom = AccountId32::new([1u8; 32]);
+        let rival = AccountId32::new([2u8; 32]);
+        let journalist = AccountId32::new([3u8; 32]);
+
+        assert_ok!(
+            News::sponsor_journalist(RuntimeOrigin::signed(newsroom.clone()), journalist.clone(), 30)
+        );
+        assert_noop!(
+            News::sponsor_journalist(RuntimeOrigin::signed(rival.clone()), journalist.clone(), 50),
+            Error::<Test>::SponsoredByAnotherNewsroom
+        );
+        // The sponsor may replace the quota
+        assert_ok!(
+            News::sponsor_journalist(RuntimeOrigin::signed(newsroom.clone()), journalist.clone(), 50)
+        );
+        assert_eq!(SponsoredJournalists::<Test>::get(&journalist).unwrap().quota, 50);
+
+        assert_noop!(
+            News::revoke_sponsorship(RuntimeOrigin::signed(rival), journalist.clone()),
+            Error::<Test>::NotSponsorshipParty
+        );
+        // The journalist may leave the sponsorship
+        assert_ok!(News::revoke_sponsorship(RuntimeOrigin::signed(journalist.clone()), journalist.clone()));
+        assert_eq!(News::sponsor_for(&journalist, 1), None);
+        assert_noop!(
+            News::revoke_sponsorship(RuntimeOrigin::signed(newsroom), journalist.clone()),
+            Error::<Test>::NotSponsored
+        );
+    });
+}
+
+#[test]
+fn other_calls_cannot_fill_provenance_block_space() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let origin = RuntimeOrigin::signed(pair.public().into());
+        let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+        let anchor = RuntimeCall::News(crate::Call::<Test>::record_article {
+            content_hash,
+            collection_id,
+            item_id,
+            title,
+            canonical_url,
+            signature: make_test_signature(&pair, &content_hash),
+            hash_algo: HashAlgo::Blake2b256,
+            word_count,
+            embargo: None,
+        });
+        let remark = RuntimeCall::System(frame_system::Call::remark { remark: Vec::new() });
+        let (anchor_info, remark_info) = (anchor.get_dispatch_info(), remark.get_dispatch_info());
+        // Anchoring gets no operational priority or space
+        assert_eq!(anchor_info.class, DispatchClass::Normal);
+        // Its weight covers the free-anchor bookkeeping
+        assert_eq!(
+            anchor_info.call_weight,
+            <() as crate::weights::WeightInfo>::record_article().saturating_add(News::free_anchor_weight())
+        );
+        let len = 100;
+        let prepare = |call: &RuntimeCall, info: &DispatchInfo| {
+            CheckProvenanceSpace::<Test>::new().prepare((), &origin, call, info, len)
+        };
+
+        // Fill the unreserved `Normal` space up to just the remark, counted with its base weight
+        // and length
+        let block_weights = <Test as frame_system::Config>::BlockWeights::get();
+        let normal = block_weights.get(DispatchClass::Normal);
+        let unreserved = normal.max_total
+            .unwrap()
+            .saturating_sub(ProvenanceReservedWeight::get())
+            .saturating_sub(System::block_weight().get(DispatchClass::Normal))
+            .saturating_sub(remark_info.total_weight())
+            .saturating_sub(normal.base_extrinsic)
+            .saturating_sub(Weight::from_parts(0, len as u64));
+        System::register_extra_weight_unchecked(unreserved, DispatchClass::Normal);
+        assert_ok!(prepare(&remark, &remark_info));
+
+        // Past it, only provenance calls get in
+        System::register_extra_weight_unchecked(Weight::from_parts(1, 0), DispatchClass::Normal);
+        assert_eq!(prepare(&remark, &remark_info), Err(InvalidTransaction::ExhaustsResources.into()));
+        assert_ok!(prepare(&anchor, &anchor_info));
+    });
+}
+
+#[test]
+fn approved_publishers_anchor_fee_free_within_session_quota() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let record = |hash: H256, item_id: u128| {
+            let (_, collection_id, _, title, canonical_url, word_count) = create_test_article_data();
+            News::record_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                make_test_signature(&pair, &hash),
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            ).unwrap().pays_fee
+        };
+
+        // Not approved: pays
+        assert_eq!(record(H256::repeat_byte(1), 1), Pays::Yes);
+
+        assert_ok!(News::approve_feeless_publisher(RuntimeOrigin::root(), publisher.clone()));
+        assert_eq!(record(H256::repeat_byte(2), 2), Pays::No);
+        assert_eq!(record(H256::repeat_byte(3), 3), Pays::No);
+        // Quota of 2 used up
+        assert_eq!(record(H256::repeat_byte(4), 4), Pays::Yes);
+        assert_eq!(FreeAnchorsInSession::<Test>::get(&publisher), (0, 2));
+
+        // Updates share the quota, which resets with the session
+        SessionIndex::set(1);
+        let (_, _, _, title, canonical_url, word_count) = create_test_article_data();
+        let new_hash = H256::repeat_byte(5);
+        let post_info = News::update_article(
+            RuntimeOrigin::signed(publisher.clone()),
+            H256::repeat_byte(1),
+            new_hash,
+            title,
+            canonical_url,
+            make_test_signature(&pair, &new_hash),
+            HashAlgo::Blake2b256,
+            word_count,
+            ChangeKind::Minor,
+            None
+        ).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        assert_eq!(FreeAnchorsInSession::<Test>::get(&publisher), (1, 1));
+        SessionIndex::set(0);
+    });
+}
+
+#[test]
+fn articles_are_signed_and_updated_with_20_byte_accounts() {
+    use account20::{ Test20, RuntimeOrigin as Origin20, News as News20 };
+
+    account20::new_test_ext().execute_with(|| {
+        let pair = ecdsa::Pair::from_seed(&[1u8; 32]);
+        let wrong_pair = ecdsa::Pair::from_seed(&[2u8; 32]);
+        let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+        let (signature, publisher) = account20::sign(&pair, &content_hash);
+        let (wrong_signature, _) = account20::sign(&wrong_pair, &content_hash);
+
+        assert_noop!(
+            News20::record_article(
+                Origin20::signed(publisher),
+                content_hash,
+                collection_id,
+                item_id,
+                title.clone(),
+                canonical_url.clone(),
+                wrong_signature.into(),
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            ),
+            Error::<Test20>::SignatureInvalid
+        );
+        assert_ok!(
+            News20::record_article(
+                Origin20::signed(publisher),
+                content_hash,
+                collection_id,
+                item_id,
+                title.clone(),
+                canonical_url.clone(),
+                signature.into(),
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            )
+        );
+        assert_eq!(ArticleByHash::<Test20>::get(content_hash).unwrap().publisher, publisher);
+
+        let new_hash = H256::repeat_byte(43);
+        let (new_signature, _) = account20::sign(&pair, &new_hash);
+        assert_ok!(
+            News20::update_article(
+                Origin20::signed(publisher),
+                content_hash,
+                new_hash,
+                title,
+                canonical_url,
+                new_signature.into(),
+                HashAlgo::Blake2b256,
+                word_count,
+                ChangeKind::Minor,
+                None
+            )
+        );
+        assert_eq!(AnchorOf::<Test20>::get(new_hash), Some(content_hash));
+    });
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+#[test]
+fn benchmark_helpers_sign_for_32_and_20_byte_accounts() {
+    use crate::BenchmarkHelper;
+    use account20::{ Test20, RuntimeOrigin as Origin20, News as News20 };
+
+    let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+
+    new_test_ext().execute_with(|| {
+        let payload = News::article_payload(
+            content_hash,
+            None,
+            collection_id,
+            item_id,
+            &title,
+            &canonical_url,
+            HashAlgo::Blake2b256,
+            word_count
+        );
+        let (signature, publisher) = <Test as crate::Config>::BenchmarkHelper::create_signature(
+            0,
+            &payload.signing_message()
+        );
+        assert_ok!(
+            News::record_article(
+                RuntimeOrigin::signed(publisher),
+                content_hash,
+                collection_id,
+                item_id,
+                title.clone(),
+                canonical_url.clone(),
+                signature.into(),
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            )
+        );
+    });
+
+    account20::new_test_ext().execute_with(|| {
+        let payload = News20::article_payload(
+            content_hash,
+            None,
+            collection_id,
+            item_id,
+            &title,
+            &canonical_url,
+            HashAlgo::Blake2b256,
+            word_count
+        );
+        let (signature, publisher) = <Test20 as crate::Config>::BenchmarkHelper::create_signature(
+            0,
+            &payload.signing_message()
+        );
+        assert_ok!(
+            News20::record_article(
+                Origin20::signed(publisher),
+                content_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                signature.into(),
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            )
+        );
+    });
+}
+
+/// Ethereum address of `pair`, recovered from a signature.
+fn ethereum_address(pair: &ecdsa::Pair) -> H160 {
+    let digest = [7u8; 32];
+    let signature: EcdsaSignature = pair.sign_prehashed(&digest).into();
+    let public = secp256k1_ecdsa_recover(&signature, &digest).unwrap();
+    H160::from_slice(&keccak_256(&public)[12..])
+}
+
+/// `personal_sign` of `message` by `pair`.
+fn personal_sign(pair: &ecdsa::Pair, message: &[u8]) -> EcdsaSignature {
+    let mut data = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    data.extend_from_slice(message);
+    pair.sign_prehashed(&keccak_256(&data)).into()
+}
+
+fn register_ethereum_address(publisher: &AccountId32, pair: &ecdsa::Pair) -> DispatchResult {
+    let message = format!("Register news publisher 0x{}", hex_encode(&publisher.encode()));
+    News::register_ethereum_address(
+        RuntimeOrigin::signed(publisher.clone()),
+        ethereum_address(pair),
+        personal_sign(pair, message.as_bytes())
+    )
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[test]
+fn ethereum_address_registration_requires_proof() {
+    new_test_ext().execute_with(|| {
+        let alice: AccountId32 = sr25519::Pair::from_seed(&[1u8; 32]).public().into();
+        let bob: AccountId32 = sr25519::Pair::from_seed(&[2u8; 32]).public().into();
+        let wallet = ecdsa::Pair::from_seed(&[1u8; 32]);
+        let address = ethereum_address(&wallet);
+
+        // Proof signed for another account
+        let message = format!("Register news publisher 0x{}", hex_encode(&bob.encode()));
+        assert_noop!(
+            News::register_ethereum_address(
+                RuntimeOrigin::signed(alice.clone()),
+                address,
+                personal_sign(&wallet, message.as_bytes())
+            ),
+            Error::<Test>::EthereumProofInvalid
+        );
+
+        assert_ok!(register_ethereum_address(&alice, &wallet));
+        assert_eq!(EthereumAddressOf::<Test>::get(&alice), Some(address));
+        assert_eq!(PublisherOfEthereumAddress::<Test>::get(address), Some(alice.clone()));
+        System::assert_last_event(
+            crate::Event::EthereumAddressRegistered { publisher: alice.clone(), address }.into()
+        );
+        assert_noop!(register_ethereum_address(&bob, &wallet), Error::<Test>::EthereumAddressInUse);
+
+        // Registering a new wallet releases the old address
+        let new_wallet = ecdsa::Pair::from_seed(&[2u8; 32]);
+        assert_ok!(register_ethereum_address(&alice, &new_wallet));
+        assert!(!PublisherOfEthereumAddress::<Test>::contains_key(address));
+        assert_ok!(register_ethereum_address(&bob, &wallet));
+
+        assert_ok!(News::unregister_ethereum_address(RuntimeOrigin::signed(alice.clone())));
+        assert!(!EthereumAddressOf::<Test>::contains_key(&alice));
+        assert_noop!(
+            News::unregister_ethereum_address(RuntimeOrigin::signed(alice)),
+            Error::<Test>::NoEthereumAddress
+        );
+    });
+}
+
+#[test]
+fn articles_signed_in_ethereum_wallets_verify_against_registered_address() {
+    new_test_ext().execute_with(|| {
+        let publisher: AccountId32 = sr25519::Pair::from_seed(&[1u8; 32]).public().into();
+        let wallet = ecdsa::Pair::from_seed(&[1u8; 32]);
+        let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+        let record = |hash: H256, item_id: u128, signature: PublisherSignatureOf<Test>| {
+            News::record_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                hash,
+                collection_id,
+                item_id,
+                title.clone(),
+                canonical_url.clone(),
+                signature,
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            )
+        };
+
+        let payload = |hash: H256, item_id: u128| {
+            News::article_payload(
+                hash,
+                None,
+                collection_id,
+                item_id,
+                &title,
+                &canonical_url,
+                HashAlgo::Blake2b256,
+                word_count
+            )
+        };
+
+        // Mock genesis hash is `[69; 32]`
+        let message = format!(
+            "Anchor news article\nversion: 1\nchain: 0x{}\npallet: News\ncontent hash: 0x{}\nprevious: none\n\
+             collection: 1\nitem: 2\ntitle: Test Title\nurl: https://example.com\nhash algo: blake2b-256\n\
+             word count: 123\nvalid until: none\nnonce: none",
+            hex_encode(&[69u8; 32]),
+            hex_encode(content_hash.as_bytes())
+        );
+        let eip191 = PublisherSignature::Eip191(personal_sign(&wallet, message.as_bytes()));
+        let eip712_digest = News::eip712_article_digest(&payload(content_hash, item_id));
+        let eip712 = PublisherSignature::Eip712(wallet.sign_prehashed(&eip712_digest).into());
+
+        // No registered address yet
+        assert_noop!(record(content_hash, item_id, eip191.clone()), Error::<Test>::SignatureInvalid);
+
+        assert_ok!(register_ethereum_address(&publisher, &wallet));
+        // Signatures are bound to the metadata
+        let mut altered = payload(content_hash, item_id);
+        altered.title = b"Other Title".to_vec();
+        let other_title = News::eip191_article_digest(&altered);
+        assert_noop!(
+            record(content_hash, item_id, PublisherSignature::Eip191(wallet.sign_prehashed(&other_title).into())),
+            Error::<Test>::SignatureInvalid
+        );
+
+        assert_ok!(record(content_hash, item_id, eip191.clone()));
+        assert_eq!(ArticleByHash::<Test>::get(content_hash).unwrap().signature, eip191);
+
+        let second_hash = H256::repeat_byte(43);
+        assert_noop!(record(second_hash, item_id + 1, eip712.clone()), Error::<Test>::SignatureInvalid);
+        let second_digest = News::eip712_article_digest(&payload(second_hash, item_id + 1));
+        assert_ok!(
+            record(second_hash, item_id + 1, PublisherSignature::Eip712(wallet.sign_prehashed(&second_digest).into()))
+        );
+
+        // Another wallet cannot sign for the publisher
+        let stranger = ecdsa::Pair::from_seed(&[9u8; 32]);
+        let third_hash = H256::repeat_byte(44);
+        let third_digest = News::eip712_article_digest(&payload(third_hash, item_id + 2));
+        assert_noop!(
+            record(third_hash, item_id + 2, PublisherSignature::Eip712(stranger.sign_prehashed(&third_digest).into())),
+            Error::<Test>::SignatureInvalid
+        );
+    });
+}
+
+/// Native signature of `payload` by `pair`.
+fn sign_payload(pair: &sr25519::Pair, payload: &ArticlePayloadOf<Test>) -> PublisherSignatureOf<Test> {
+    MultiSignature::from(pair.sign(&payload.signing_message())).into()
+}
+
+#[test]
+fn signing_payload_covers_metadata_and_chain() {
+    new_test_ext().execute_with(|| {
+        LegacySignatures::set(false);
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+        let payload = News::article_payload(
+            content_hash,
+            None,
+            collection_id,
+            item_id,
+            &title,
+            &canonical_url,
+            HashAlgo::Blake2b256,
+            word_count
+        );
+        assert_eq!(payload.genesis_hash, System::block_hash(0));
+        assert_eq!(payload.pallet_name, b"News".to_vec());
+        let record = |title: BoundedVec<u8, _>, signature| {
+            News::record_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                content_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url.clone(),
+                signature,
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            )
+        };
+
+        // Relayed with altered metadata
+        let altered_title = BoundedVec::try_from(b"Altered".to_vec()).unwrap();
+        assert_noop!(
+            record(altered_title, sign_payload(&pair, &payload)),
+            Error::<Test>::SignatureInvalid
+        );
+        // Signed for another chain
+        let other_chain = ArticlePayload { genesis_hash: H256::repeat_byte(1), ..payload.clone() };
+        assert_noop!(
+            record(title.clone(), sign_payload(&pair, &other_chain)),
+            Error::<Test>::SignatureInvalid
+        );
+        assert_ok!(record(title.clone(), sign_payload(&pair, &payload)));
+
+        // Updates sign the version they follow
+        let new_hash = H256::repeat_byte(43);
+        let update = |previous: H256| {
+            let payload = News::article_payload(
+                new_hash,
+                Some(previous),
+                collection_id,
+                item_id,
+                &title,
+                &canonical_url,
+                HashAlgo::Blake2b256,
+                word_count
+            );
+            News::update_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                content_hash,
+                new_hash,
+                title.clone(),
+                canonical_url.clone(),
+                sign_payload(&pair, &payload),
+                HashAlgo::Blake2b256,
+                word_count,
+                ChangeKind::Minor,
+                None
+            )
+        };
+        assert_noop!(update(H256::repeat_byte(9)), Error::<Test>::SignatureInvalid);
+        assert_ok!(update(content_hash));
+    });
+}
+
+#[test]
+fn legacy_signatures_are_accepted_only_when_allowed() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+        let record = || {
+            News::record_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                content_hash,
+                collection_id,
+                item_id,
+                title.clone(),
+                canonical_url.clone(),
+                make_test_signature(&pair, &content_hash),
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            )
+        };
+
+        LegacySignatures::set(false);
+        assert_noop!(record(), Error::<Test>::SignatureInvalid);
+        LegacySignatures::set(true);
+        assert_ok!(record());
+    });
+}
+
+fn record_for(
+    relayer: &AccountId32,
+    pair: &sr25519::Pair,
+    valid_until: u64,
+    signed_until: Option<u64>
+) -> DispatchResult {
+    let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+    let nonce = PublisherNonces::<Test>::get(AccountId32::from(pair.public()));
+    let payload = ArticlePayload {
+        valid_until: signed_until,
+        nonce: Some(nonce),
+        ..News::article_payload(
+            content_hash,
+            None,
+            collection_id,
+            item_id,
+            &title,
+            &canonical_url,
+            HashAlgo::Blake2b256,
+            word_count
+        )
+    };
+    News::record_article_for(
+        RuntimeOrigin::signed(relayer.clone()),
+        pair.public().into(),
+        content_hash,
+        collection_id,
+        item_id,
+        title,
+        canonical_url,
+        sign_payload(pair, &payload),
+        HashAlgo::Blake2b256,
+        word_count,
+        valid_until,
+        nonce
+    )
+}
+
+#[test]
+fn relayer_records_article_owned_by_publisher() {
+    new_test_ext().execute_with(|| {
+        let relayer: AccountId32 = sr25519::Pair::from_seed(&[9u8; 32]).public().into();
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let content_hash = create_test_article_data().0;
+
+        // The expiry must be signed
+        assert_noop!(record_for(&relayer, &pair, 10, None), Error::<Test>::SignatureInvalid);
+        assert_noop!(record_for(&relayer, &pair, 20, Some(10)), Error::<Test>::SignatureInvalid);
+
+        assert_ok!(record_for(&relayer, &pair, 10, Some(10)));
+        assert_eq!(ArticleByHash::<Test>::get(content_hash).unwrap().publisher, publisher);
+        assert_eq!(ArticlesByPublisher::<Test>::get(&publisher).to_vec(), vec![content_hash]);
+        assert!(ArticlesByPublisher::<Test>::get(&relayer).is_empty());
+        System::assert_last_event(
+            crate::Event::ArticleRelayed { content_hash, publisher, relayer, valid_until: 10 }.into()
+        );
+    });
+}
+
+#[test]
+fn relayed_signatures_expire_and_must_not_be_legacy() {
+    new_test_ext().execute_with(|| {
+        let relayer: AccountId32 = sr25519::Pair::from_seed(&[9u8; 32]).public().into();
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+
+        System::set_block_number(11);
+        assert_noop!(record_for(&relayer, &pair, 10, Some(10)), Error::<Test>::SignatureExpired);
+
+        // Legacy signatures carry no expiry
+        assert_noop!(
+            News::record_article_for(
+                RuntimeOrigin::signed(relayer.clone()),
+                pair.public().into(),
+                content_hash,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                make_test_signature(&pair, &content_hash),
+                HashAlgo::Blake2b256,
+                word_count,
+                20,
+                0
+            ),
+            Error::<Test>::SignatureInvalid
+        );
+        assert_ok!(record_for(&relayer, &pair, 11, Some(11)));
+    });
+}
+
+#[test]
+fn relayed_signatures_are_single_use() {
+    new_test_ext().execute_with(|| {
+        let relayer: AccountId32 = sr25519::Pair::from_seed(&[9u8; 32]).public().into();
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let (_, collection_id, _, title, canonical_url, word_count) = create_test_article_data();
+        let relay = |hash: H256, item_id: u128, nonce: u64| {
+            let payload = ArticlePayload {
+                valid_until: Some(10),
+                nonce: Some(nonce),
+                ..News::article_payload(
+                    hash,
+                    None,
+                    collection_id,
+                    item_id,
+                    &title,
+                    &canonical_url,
+                    HashAlgo::Blake2b256,
+                    word_count
+                )
+            };
+            News::record_article_for(
+                RuntimeOrigin::signed(relayer.clone()),
+                publisher.clone(),
+                hash,
+                collection_id,
+                item_id,
+                title.clone(),
+                canonical_url.clone(),
+                sign_payload(&pair, &payload),
+                HashAlgo::Blake2b256,
+                word_count,
+                10,
+                nonce
+            )
+        };
+
+        assert_noop!(relay(H256::repeat_byte(1), 1, 1), Error::<Test>::FutureSignatureNonce);
+        assert_ok!(relay(H256::repeat_byte(1), 1, 0));
+        assert_eq!(PublisherNonces::<Test>::get(&publisher), 1);
+
+        // A second article signed with the same nonce
+        assert_noop!(relay(H256::repeat_byte(2), 2, 0), Error::<Test>::SignatureReused);
+        assert_ok!(relay(H256::repeat_byte(2), 2, 1));
+        assert_eq!(PublisherNonces::<Test>::get(&publisher), 2);
+
+        // Direct calls leave the nonce alone
+        let hash = H256::repeat_byte(3);
+        let payload = News::article_payload(
+            hash,
+            None,
+            collection_id,
+            3,
+            &title,
+            &canonical_url,
+            HashAlgo::Blake2b256,
+            word_count
+        );
+        assert_ok!(
+            News::record_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                hash,
+                collection_id,
+                3,
+                title.clone(),
+                canonical_url.clone(),
+                sign_payload(&pair, &payload),
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            )
+        );
+        assert_eq!(PublisherNonces::<Test>::get(&publisher), 2);
+    });
+}
+
+#[test]
+fn feeless_anchors_with_used_or_future_nonces_are_rejected() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        assert_ok!(News::approve_feeless_publisher(RuntimeOrigin::root(), publisher.clone()));
+
+        let first = feeless_anchor(&pair, H256::repeat_byte(1), 1);
+        let reused = feeless_anchor(&pair, H256::repeat_byte(2), 2);
+        assert_ok!(dispatch_feeless(first));
+        assert_eq!(PublisherNonces::<Test>::get(&publisher), 1);
+
+        assert_eq!(validate_feeless(&reused), InvalidTransaction::Stale.into());
+        assert_noop!(dispatch_feeless(reused), Error::<Test>::SignatureReused);
+
+        // Signed ahead of the publisher's next nonce
+        PublisherNonces::<Test>::insert(&publisher, 2);
+        let future = feeless_anchor(&pair, H256::repeat_byte(3), 3);
+        PublisherNonces::<Test>::insert(&publisher, 1);
+        assert_eq!(validate_feeless(&future), InvalidTransaction::Future.into());
+        assert_noop!(dispatch_feeless(future), Error::<Test>::FutureSignatureNonce);
+    });
+}
+
+#[test]
+fn signatures_from_another_chain_are_rejected() {
+    let relayer: AccountId32 = sr25519::Pair::from_seed(&[9u8; 32]).public().into();
+    let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+    let publisher: AccountId32 = pair.public().into();
+    let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+    let relay = |signature: PublisherSignatureOf<Test>| {
+        News::record_article_for(
+            RuntimeOrigin::signed(relayer.clone()),
+            publisher.clone(),
+            content_hash,
+            collection_id,
+            item_id,
+            title.clone(),
+            canonical_url.clone(),
+            signature,
+            HashAlgo::Blake2b256,
+            word_count,
+            10,
+            0
+        )
+    };
+
+    // Signed on a testnet running the same pallet
+    let (relayed, feeless) = new_test_ext().execute_with(|| {
+        frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+        let payload = ArticlePayload {
+            valid_until: Some(10),
+            nonce: Some(0),
+            ..News::article_payload(
+                content_hash,
+                None,
+                collection_id,
+                item_id,
+                &title,
+                &canonical_url,
+                HashAlgo::Blake2b256,
+                word_count
+            )
+        };
+        let relayed = sign_payload(&pair, &payload);
+        let feeless = feeless_anchor(&pair, H256::repeat_byte(2), 2);
+
+        assert_ok!(News::approve_feeless_publisher(RuntimeOrigin::root(), publisher.clone()));
+        assert!(validate_feeless(&feeless).is_ok());
+        assert_ok!(relay(relayed.clone()));
+        (relayed, feeless)
+    });
+
+    new_test_ext().execute_with(|| {
+        assert_noop!(relay(relayed), Error::<Test>::SignatureInvalid);
+
+        assert_ok!(News::approve_feeless_publisher(RuntimeOrigin::root(), publisher.clone()));
+        assert_eq!(validate_feeless(&feeless), InvalidTransaction::BadProof.into());
+        assert_noop!(dispatch_feeless(feeless), Error::<Test>::SignatureInvalid);
+    });
+}
+
+/// `add_signing_key` proof by `key` for `publisher`.
+fn signing_key_proof(key: &sr25519::Pair, publisher: &AccountId32) -> MultiSignature {
+    key.sign(&News::signing_key_message(publisher)).into()
+}
+
+#[test]
+fn signing_keys_are_added_rotated_and_revoked() {
+    new_test_ext().execute_with(|| {
+        let publisher: AccountId32 = sr25519::Pair::from_seed(&[1u8; 32]).public().into();
+        let first = sr25519::Pair::from_seed(&[5u8; 32]);
+        let second = sr25519::Pair::from_seed(&[6u8; 32]);
+        let first_key: AccountId32 = first.public().into();
+        let second_key: AccountId32 = second.public().into();
+        let (_, collection_id, _, title, canonical_url, word_count) = create_test_article_data();
+        let record = |hash: H256, item_id: u128, key: &sr25519::Pair| {
+            let payload = News::article_payload(
+                hash,
+                None,
+                collection_id,
+                item_id,
+                &title,
+                &canonical_url,
+                HashAlgo::Blake2b256,
+                word_count
+            );
+            News::record_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                hash,
+                collection_id,
+                item_id,
+                title.clone(),
+                canonical_url.clone(),
+                PublisherSignature::SigningKey {
+                    key: key.public().into(),
+                    signature: key.sign(&payload.signing_message()).into(),
+                },
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            )
+        };
+        let add = |key: &AccountId32, valid_from: u64, expires_at: Option<u64>, proof| {
+            News::add_signing_key(
+                RuntimeOrigin::signed(publisher.clone()),
+                key.clone(),
+                valid_from,
+                expires_at,
+                proof
+            )
+        };
+
+        // The key must consent
+        assert_noop!(
+            add(&first_key, 1, None, signing_key_proof(&second, &publisher)),
+            Error::<Test>::SigningKeyProofInvalid
+        );
+        assert_noop!(
+            add(&first_key, 5, Some(5), signing_key_proof(&first, &publisher)),
+            Error::<Test>::InvalidSigningKeyWindow
+        );
+        assert_noop!(record(H256::repeat_byte(1), 1, &first), Error::<Test>::SigningKeyNotValid);
+
+        assert_ok!(add(&first_key, 1, None, signing_key_proof(&first, &publisher)));
+        System::assert_last_event(
+            crate::Event::SigningKeyAdded {
+                publisher: publisher.clone(),
+                key: first_key.clone(),
+                valid_from: 1,
+                expires_at: None,
+            }.into()
+        );
+        assert_noop!(
+            add(&first_key, 1, None, signing_key_proof(&first, &publisher)),
+            Error::<Test>::SigningKeyExists
+        );
+        assert_ok!(record(H256::repeat_byte(1), 1, &first));
+        assert_eq!(News::signature_status(&H256::repeat_byte(1)), Some(SignatureStatus::SigningKey));
+
+        // Rotation retires the old key from the current block
+        System::set_block_number(5);
+        assert_ok!(
+            News::rotate_signing_key(
+                RuntimeOrigin::signed(publisher.clone()),
+                first_key.clone(),
+                second_key.clone(),
+                signing_key_proof(&second, &publisher)
+            )
+        );
+        assert_eq!(SigningKeys::<Test>::get(&publisher, &first_key).unwrap().expires_at, Some(5));
+        assert_noop!(record(H256::repeat_byte(2), 2, &first), Error::<Test>::SigningKeyNotValid);
+        assert_ok!(record(H256::repeat_byte(2), 2, &second));
+
+        System::set_block_number(7);
+        assert_ok!(record(H256::repeat_byte(3), 3, &second));
+
+        // The second key leaked at block 6
+        System::set_block_number(8);
+        assert_ok!(
+            News::revoke_signing_key(RuntimeOrigin::signed(publisher.clone()), second_key.clone(), 6)
+        );
+        assert_noop!(record(H256::repeat_byte(4), 4, &second), Error::<Test>::SigningKeyNotValid);
+        assert_eq!(News::signature_status(&H256::repeat_byte(1)), Some(SignatureStatus::SigningKey));
+        assert_eq!(News::signature_status(&H256::repeat_byte(2)), Some(SignatureStatus::SigningKey));
+        assert_eq!(
+            News::signature_status(&H256::repeat_byte(3)),
+            Some(SignatureStatus::RevokedSigningKey)
+        );
+
+        // A revocation only moves back in time
+        assert_ok!(
+            News::revoke_signing_key(RuntimeOrigin::signed(publisher.clone()), second_key.clone(), 9)
+        );
+        System::assert_last_event(
+            crate::Event::SigningKeyRevoked { publisher: publisher.clone(), key: second_key.clone(), from: 6 }.into()
+        );
+        assert_ok!(
+            News::revoke_signing_key(RuntimeOrigin::signed(publisher.clone()), second_key.clone(), 5)
+        );
+        assert_eq!(
+            News::signature_status(&H256::repeat_byte(2)),
+            Some(SignatureStatus::RevokedSigningKey)
+        );
+        assert_noop!(
+            News::revoke_signing_key(RuntimeOrigin::signed(publisher.clone()), AccountId32::new([0u8; 32]), 5),
+            Error::<Test>::UnknownSigningKey
+        );
+    });
+}
+
+fn batch_leaf(publisher: &AccountId32, item_id: u128) -> BatchLeaf<AccountId32> {
+    let (_, collection_id, _, title, canonical_url, word_count) = create_test_article_data();
+    BatchLeaf {
+        publisher: publisher.clone(),
+        content_hash: H256::repeat_byte(item_id as u8),
+        collection_id,
+        item_id,
+        title: title.to_vec(),
+        canonical_url: canonical_url.to_vec(),
+        hash_algo: HashAlgo::Blake2b256,
+        word_count,
+    }
+}
+
+fn promote(
+    publisher: &AccountId32,
+    merkle_root: H256,
+    leaf: &BatchLeaf<AccountId32>,
+    proof: Vec<H256>
+) -> DispatchResult {
+    News::promote_batch_leaf(
+        RuntimeOrigin::signed(publisher.clone()),
+        merkle_root,
+        leaf.content_hash,
+        leaf.collection_id,
+        leaf.item_id,
+        BoundedVec::try_from(leaf.title.clone()).unwrap(),
+        BoundedVec::try_from(leaf.canonical_url.clone()).unwrap(),
+        leaf.hash_algo,
+        leaf.word_count,
+        BoundedVec::try_from(proof).unwrap()
+    )
+}
+
+fn sign_batch(pair: &sr25519::Pair, merkle_root: H256, count: u32) -> PublisherSignatureOf<Test> {
+    let payload = News::batch_payload(pair.public().into(), merkle_root, count);
+    MultiSignature::from(pair.sign(&payload.signing_message())).into()
+}
+
+#[test]
+fn batches_are_anchored_by_merkle_root_and_leaves_promoted() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let leaves: Vec<BatchLeaf<AccountId32>> = (1..=5).map(|item_id| batch_leaf(&publisher, item_id)).collect();
+        let hashes: Vec<H256> = leaves.iter().map(BatchLeaf::hash).collect();
+        let root = batch_merkle_root(&hashes);
+        let anchor = |count: u32, signature| {
+            News::anchor_batch(RuntimeOrigin::signed(publisher.clone()), root, count, signature)
+        };
+
+        assert_noop!(anchor(0, sign_batch(&pair, root, 0)), Error::<Test>::EmptyBatch);
+        // The count is signed
+        assert_noop!(anchor(6, sign_batch(&pair, root, 5)), Error::<Test>::SignatureInvalid);
+        assert!(!News::verify_inclusion(root, &leaves[0], &batch_merkle_proof(&hashes, 0)));
+
+        let signature = sign_batch(&pair, root, 5);
+        assert_ok!(anchor(5, signature.clone()));
+        System::assert_last_event(
+            crate::Event::BatchAnchored { publisher: publisher.clone(), merkle_root: root, count: 5 }.into()
+        );
+        assert_eq!(Batches::<Test>::get(&publisher, root).unwrap().anchored_at, 1);
+        assert_noop!(anchor(5, sign_batch(&pair, root, 5)), Error::<Test>::BatchAlreadyAnchored);
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            assert!(News::verify_inclusion(root, leaf, &batch_merkle_proof(&hashes, index)));
+        }
+        let altered = BatchLeaf { word_count: 1, ..leaves[2].clone() };
+        assert!(!News::verify_inclusion(root, &altered, &batch_merkle_proof(&hashes, 2)));
+        assert!(!News::verify_inclusion(root, &leaves[2], &batch_merkle_proof(&hashes, 3)));
+
+        // Promotion to a full lineage
+        let proof = batch_merkle_proof(&hashes, 2);
+        assert_noop!(
+            promote(&publisher, root, &altered, proof.clone()),
+            Error::<Test>::InclusionProofInvalid
+        );
+        assert_noop!(
+            promote(&publisher, H256::repeat_byte(9), &leaves[2], proof.clone()),
+            Error::<Test>::UnknownBatch
+        );
+        assert_ok!(promote(&publisher, root, &leaves[2], proof.clone()));
+
+        let content_hash = leaves[2].content_hash;
+        let record = ArticleByHash::<Test>::get(content_hash).unwrap();
+        assert_eq!(record.publisher, publisher);
+        assert_eq!(record.signature, signature);
+        assert_eq!(BatchOf::<Test>::get(content_hash), Some(root));
+        assert_eq!(RootByItem::<Test>::get(leaves[2].collection_id, leaves[2].item_id), Some(content_hash));
+        assert_eq!(ArticlesByPublisher::<Test>::get(&publisher).to_vec(), vec![content_hash]);
+        System::assert_last_event(
+            crate::Event::BatchLeafPromoted { publisher: publisher.clone(), merkle_root: root, content_hash }.into()
+        );
+        assert_noop!(promote(&publisher, root, &leaves[2], proof), Error::<Test>::ArticleAlreadyExists);
+    });
+}
+
+#[test]
+fn batch_roots_cannot_be_claimed_by_another_publisher() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let squatter_pair = sr25519::Pair::from_seed(&[2u8; 32]);
+        let squatter: AccountId32 = squatter_pair.public().into();
+        let leaves: Vec<BatchLeaf<AccountId32>> = (1..=3).map(|item_id| batch_leaf(&publisher, item_id)).collect();
+        let hashes: Vec<H256> = leaves.iter().map(BatchLeaf::hash).collect();
+        let root = batch_merkle_root(&hashes);
+        let signature = sign_batch(&pair, root, 3);
+
+        // The publisher's signature names the publisher
+        assert_noop!(
+            News::anchor_batch(RuntimeOrigin::signed(squatter.clone()), root, 3, signature.clone()),
+            Error::<Test>::SignatureInvalid
+        );
+        // Anchoring the root first does not block the publisher or let the squatter promote
+        assert_ok!(
+            News::anchor_batch(RuntimeOrigin::signed(squatter.clone()), root, 3, sign_batch(&squatter_pair, root, 3))
+        );
+        assert_ok!(News::anchor_batch(RuntimeOrigin::signed(publisher.clone()), root, 3, signature));
+        let proof = batch_merkle_proof(&hashes, 1);
+        assert_noop!(
+            promote(&squatter, root, &leaves[1], proof.clone()),
+            Error::<Test>::InclusionProofInvalid
+        );
+        let claimed = BatchLeaf { publisher: squatter.clone(), ..leaves[1].clone() };
+        assert!(!News::verify_inclusion(root, &claimed, &proof));
+        assert!(News::verify_inclusion(root, &leaves[1], &proof));
+        assert_ok!(promote(&publisher, root, &leaves[1], proof));
+        assert_eq!(ArticleByHash::<Test>::get(leaves[1].content_hash).unwrap().publisher, publisher);
+    });
+}
+
+#[test]
+fn promoted_leaves_are_judged_by_when_their_batch_was_anchored() {
+    new_test_ext().execute_with(|| {
+        let publisher: AccountId32 = sr25519::Pair::from_seed(&[1u8; 32]).public().into();
+        let key = sr25519::Pair::from_seed(&[5u8; 32]);
+        let key_id: AccountId32 = key.public().into();
+        assert_ok!(
+            News::add_signing_key(
+                RuntimeOrigin::signed(publisher.clone()),
+                key_id.clone(),
+                1,
+                None,
+                signing_key_proof(&key, &publisher)
+            )
+        );
+        let leaves: Vec<BatchLeaf<AccountId32>> = (1..=2).map(|item_id| batch_leaf(&publisher, item_id)).collect();
+        let hashes: Vec<H256> = leaves.iter().map(BatchLeaf::hash).collect();
+        let root = batch_merkle_root(&hashes);
+        let payload = News::batch_payload(publisher.clone(), root, 2);
+        let signature = PublisherSignature::SigningKey {
+            key: key_id.clone(),
+            signature: key.sign(&payload.signing_message()).into(),
+        };
+        assert_ok!(News::anchor_batch(RuntimeOrigin::signed(publisher.clone()), root, 2, signature));
+
+        // The key leaks at block 5, after the batch was signed at block 1
+        System::set_block_number(10);
+        assert_ok!(News::revoke_signing_key(RuntimeOrigin::signed(publisher.clone()), key_id, 5));
+        assert_ok!(promote(&publisher, root, &leaves[0], batch_merkle_proof(&hashes, 0)));
+        assert_eq!(ArticleByHash::<Test>::get(leaves[0].content_hash).unwrap().last_updated_at, 10);
+        assert_eq!(News::signature_status(&leaves[0].content_hash), Some(SignatureStatus::SigningKey));
+    });
+}
+
+#[test]
+fn removed_lineages_are_tombstoned_and_cleared_in_steps() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let (anchor, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+        let latest = H256::repeat_byte(43);
+        assert_ok!(
+            News::update_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                anchor,
+                latest,
+                title,
+                canonical_url,
+                make_test_signature(&pair, &latest),
+                HashAlgo::Blake2b256,
+                word_count,
+                ChangeKind::Minor,
+                None
+            )
+        );
+        let cited = H256::repeat_byte(50);
+        record_article_with_hash(&pair, cited, 3);
+        assert_ok!(News::declare_citations(RuntimeOrigin::signed(publisher.clone()), anchor, citations(&[cited])));
+
+        // Four readers endorse the latest version, one the anchor
+        let readers: Vec<AccountId32> = (10u8..15)
+            .map(|seed| sr25519::Pair::from_seed(&[seed; 32]).public().into())
+            .collect();
+        for (i, reader) in readers.iter().enumerate() {
+            fund(reader, 1_000);
+            let version = if i < 4 { latest } else { anchor };
+            assert_ok!(News::endorse_article(RuntimeOrigin::signed(reader.clone()), version, Sentiment::Endorse));
+        }
+        let held = |reader: &AccountId32| {
+            Balances::balance_on_hold(&HoldReason::EndorsementDeposit.into(), reader)
+        };
+
+        // Only the anchor can be removed, and only by its publisher
+        assert_noop!(
+            News::remove_article(RuntimeOrigin::signed(publisher.clone()), latest),
+            Error::<Test>::NotAnAnchor
+        );
+        assert_noop!(
+            News::remove_article(RuntimeOrigin::signed(readers[0].clone()), anchor),
+            Error::<Test>::NotArticlePublisher
+        );
+
+        assert_ok!(News::remove_article(RuntimeOrigin::signed(publisher.clone()), anchor));
+        System::assert_has_event(
+            crate::Event::ArticleRemoved {
+                anchor,
+                publisher: publisher.clone(),
+                reason: RemovalReason::Publisher,
+            }.into()
+        );
+
+        // Records and lineage indices are gone at once
+        for version in [anchor, latest] {
+            assert!(ArticleByHash::<Test>::get(version).is_none());
+            assert!(AnchorOf::<Test>::get(version).is_none());
+            let tombstone = Tombstones::<Test>::get(version).unwrap();
+            assert_eq!(tombstone.anchor, anchor);
+            assert_eq!(tombstone.reason, RemovalReason::Publisher);
+        }
+        assert!(HistoryByAnchor::<Test>::get(anchor).is_empty());
+        assert!(RootByItem::<Test>::get(collection_id, item_id).is_none());
+        assert_eq!(ArticlesByPublisher::<Test>::get(&publisher).to_vec(), vec![cited]);
+
+        // `MaxRemovalSteps` is 3: three of the four deposits on the latest version are returned
+        assert_eq!(readers.iter().filter(|reader| held(reader) == 0).count(), 3);
+        assert_eq!(PendingRemovals::<Test>::get(anchor).unwrap().len(), 2);
+
+        // The last deposit and the latest version's indices, then the anchor's deposit
+        assert_ok!(News::continue_removal(RuntimeOrigin::signed(readers[0].clone()), anchor));
+        assert!(readers[..4].iter().all(|reader| held(reader) == 0));
+        assert_eq!(EndorsementTallies::<Test>::get(latest).endorsements, 0);
+        assert_eq!(held(&readers[4]), 0);
+        assert_eq!(PendingRemovals::<Test>::get(anchor).unwrap().len(), 1);
+
+        assert_ok!(News::continue_removal(RuntimeOrigin::signed(readers[0].clone()), anchor));
+        System::assert_last_event(crate::Event::ArticleRemovalCompleted { anchor }.into());
+        assert!(PendingRemovals::<Test>::get(anchor).is_none());
+        assert!(CitationsOf::<Test>::get(anchor).is_none());
+        assert!(!CitedBy::<Test>::contains_key(cited, anchor));
+        assert_eq!(Endorsements::<Test>::iter_prefix(anchor).count(), 0);
+        assert_noop!(
+            News::continue_removal(RuntimeOrigin::signed(readers[0].clone()), anchor),
+            Error::<Test>::NoPendingRemoval
+        );
+
+        // A removed hash can never be recorded again
+        let (_, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+        assert_noop!(
+            News::record_article(
+                RuntimeOrigin::signed(publisher),
+                anchor,
+                collection_id,
+                item_id,
+                title,
+                canonical_url,
+                make_test_signature(&pair, &anchor),
+                HashAlgo::Blake2b256,
+                word_count,
+                None
+            ),
+            Error::<Test>::ArticleRemoved
+        );
+    });
+}
+
+#[test]
+fn articles_are_purged_by_purge_origin() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let anchor = H256::repeat_byte(42);
+
+        assert_noop!(News::purge_article(RuntimeOrigin::signed(publisher.clone()), anchor), DispatchError::BadOrigin);
+        assert_noop!(
+            News::purge_article(RuntimeOrigin::root(), H256::repeat_byte(7)),
+            Error::<Test>::ArticleNotFound
+        );
+
+        assert_ok!(News::purge_article(RuntimeOrigin::root(), anchor));
+        assert_eq!(Tombstones::<Test>::get(anchor).unwrap().reason, RemovalReason::Purged);
+        assert!(ArticlesByPublisher::<Test>::get(&publisher).is_empty());
+        System::assert_last_event(crate::Event::ArticleRemovalCompleted { anchor }.into());
+    });
+}
+
+#[test]
+fn removal_reverts_reputation_and_drops_citations_of_removed_versions() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let anchor = H256::repeat_byte(42);
+        let other = sr25519::Pair::from_seed(&[2u8; 32]);
+        let (citing, kept) = (H256::repeat_byte(1), H256::repeat_byte(2));
+        record_article_with_hash(&other, citing, 1);
+        record_article_with_hash(&other, kept, 2);
+        assert_ok!(
+            News::declare_citations(RuntimeOrigin::signed(other.public().into()), citing, citations(&[anchor, kept]))
+        );
+
+        let reader: AccountId32 = sr25519::Pair::from_seed(&[3u8; 32]).public().into();
+        fund(&reader, 1_000);
+        assert_ok!(News::endorse_article(RuntimeOrigin::signed(reader), anchor, Sentiment::Endorse));
+        assert_ok!(News::submit_fact_check(RuntimeOrigin::root(), anchor, FactCheckVerdict::False));
+        assert_ok!(News::retract_article(RuntimeOrigin::signed(publisher.clone()), anchor));
+
+        // The points are reverted as decayed when the removal clears them; the retraction stays
+        System::set_block_number(101);
+        assert_ok!(News::remove_article(RuntimeOrigin::signed(publisher.clone()), anchor));
+        assert_eq!(News::reputation_of(&publisher), points::RETRACTION / 2);
+
+        assert_eq!(CitationsOf::<Test>::get(citing).unwrap().to_vec(), vec![kept]);
+        assert!(CitedBy::<Test>::contains_key(kept, citing));
+        assert!(!CitedBy::<Test>::contains_key(anchor, citing));
+    });
+}
+
+/// Records the default test article and `updates` versions after it; returns all version hashes.
+fn record_lineage(pair: &sr25519::Pair, updates: u64) -> Vec<H256> {
+    let publisher = record_default_article(pair);
+    let (anchor, _, _, title, canonical_url, word_count) = create_test_article_data();
+    let mut versions = vec![anchor];
+    for i in 1..=updates {
+        let new_hash = H256::from_low_u64_be(1_000 + i);
+        assert_ok!(
+            News::update_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                *versions.last().unwrap(),
+                new_hash,
+                title.clone(),
+                canonical_url.clone(),
+                make_test_signature(pair, &new_hash),
+                HashAlgo::Blake2b256,
+                word_count,
+                ChangeKind::Minor,
+                None
+            )
+        );
+        versions.push(new_hash);
+    }
+    versions
+}
+
+#[test]
+fn only_the_latest_version_can_be_updated() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let versions = record_lineage(&pair, 2);
+        let publisher: AccountId32 = pair.public().into();
+        let (_, _, _, title, canonical_url, word_count) = create_test_article_data();
+        let new_hash = H256::repeat_byte(99);
+        let update = |old_hash: H256| {
+            News::update_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                old_hash,
+                new_hash,
+                title.clone(),
+                canonical_url.clone(),
+                make_test_signature(&pair, &new_hash),
+                HashAlgo::Blake2b256,
+                word_count,
+                ChangeKind::Minor,
+                None
+            )
+        };
+
+        assert_noop!(update(versions[0]), Error::<Test>::NotLatestVersion);
+        assert_noop!(update(versions[1]), Error::<Test>::NotLatestVersion);
+        assert_ok!(update(versions[2]));
+        assert_eq!(ArticleByHash::<Test>::get(new_hash).unwrap().updates, 3);
+        assert_eq!(ArticleUpdateCount::<Test>::get(versions[0]), 3);
+    });
+}
+
+#[test]
+fn full_history_pages_are_archived_and_updates_continue() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        // `MaxHistoryLen` is 50
+        let versions = record_lineage(&pair, 120);
+        let anchor = versions[0];
+        let latest = versions[120];
+
+        assert_eq!(HistoryPageCount::<Test>::get(anchor), 2);
+        assert_eq!(HistoryPages::<Test>::get(anchor, 0).unwrap().to_vec(), versions[..50].to_vec());
+        assert_eq!(News::history_page(anchor, 1), versions[50..100].to_vec());
+        assert_eq!(HistoryByAnchor::<Test>::get(anchor).to_vec(), versions[100..].to_vec());
+        assert_eq!(News::history_page(anchor, 2), versions[100..].to_vec());
+        assert!(News::history_page(anchor, 3).is_empty());
+        System::assert_has_event(
+            crate::Event::ArticleHistoryExtended { anchor, new_hash: latest, index: 120 }.into()
+        );
+        assert_eq!(ArticleUpdateCount::<Test>::get(anchor), 120);
+        assert_eq!(AnchorOf::<Test>::get(versions[10]), Some(anchor));
+    });
+}
+
+#[test]
+fn removal_clears_dependent_pallets_within_its_budget() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let anchor = H256::repeat_byte(42);
+        ExternalEntries::set(4);
+
+        // One step for the anchor, two for the entries elsewhere
+        assert_ok!(News::remove_article(RuntimeOrigin::signed(publisher.clone()), anchor));
+        assert_eq!(ExternalEntries::get(), 2);
+        assert!(PendingRemovals::<Test>::contains_key(anchor));
+
+        assert_ok!(News::continue_removal(RuntimeOrigin::signed(publisher), anchor));
+        assert_eq!(ExternalEntries::get(), 0);
+        assert!(!PendingRemovals::<Test>::contains_key(anchor));
+        System::assert_last_event(crate::Event::ArticleRemovalCompleted { anchor }.into());
+    });
+}
+
+#[test]
+fn removal_unlinks_archived_history_pages() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let versions = record_lineage(&pair, 60);
+        let anchor = versions[0];
+        let publisher: AccountId32 = pair.public().into();
+
+        assert_ok!(News::remove_article(RuntimeOrigin::signed(publisher.clone()), anchor));
+        assert!(ArticleByHash::<Test>::get(anchor).is_none());
+        assert!(versions[50..].iter().all(|version| ArticleByHash::<Test>::get(version).is_none()));
+
+        // Archived versions stay recorded until their page is cleared, but the lineage is closed
+        assert!(ArticleByHash::<Test>::get(versions[10]).is_some());
+        let new_hash = H256::repeat_byte(99);
+        let (_, _, _, title, canonical_url, word_count) = create_test_article_data();
+        assert_noop!(
+            News::update_article(
+                RuntimeOrigin::signed(publisher.clone()),
+                versions[10],
+                new_hash,
+                title,
+                canonical_url,
+                make_test_signature(&pair, &new_hash),
+                HashAlgo::Blake2b256,
+                word_count,
+                ChangeKind::Minor,
+                None
+            ),
+            Error::<Test>::ArticleRemoved
+        );
+
+        let mut calls = 0;
+        while PendingRemovals::<Test>::contains_key(anchor) {
+            assert_ok!(News::continue_removal(RuntimeOrigin::signed(publisher.clone()), anchor));
+            calls += 1;
+        }
+        // 61 versions and one page at `MaxRemovalSteps` = 3 per call
+        assert_eq!(calls, 20);
+        for version in &versions {
+            assert!(ArticleByHash::<Test>::get(version).is_none());
+            assert!(AnchorOf::<Test>::get(version).is_none());
+            assert_eq!(Tombstones::<Test>::get(version).unwrap().anchor, anchor);
+        }
+        assert!(HistoryPages::<Test>::get(anchor, 0).is_none());
+        assert_eq!(HistoryPageCount::<Test>::get(anchor), 0);
+    });
+}
+
+#[test]
+fn versions_carry_change_metadata_and_corrections_are_indexed() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let (anchor, _, _, title, canonical_url, word_count) = create_test_article_data();
+        let new_title = BoundedVec::try_from(b"Corrected Title".to_vec()).unwrap();
+        let notice = H256::repeat_byte(7);
+        let changes = [
+            (H256::repeat_byte(43), title.clone(), ChangeKind::Correction, Some(ChangeSummary::Text(BoundedVec::try_from(b"Wrong date".to_vec()).unwrap()))),
+            (H256::repeat_byte(44), title.clone(), ChangeKind::Minor, None),
+            (H256::repeat_byte(45), new_title.clone(), ChangeKind::Headline, None),
+            (H256::repeat_byte(46), new_title, ChangeKind::Correction, Some(ChangeSummary::Hash(notice))),
+        ];
+        let mut parent = anchor;
+        for (new_hash, title, kind, summary) in changes.iter().cloned() {
+            assert_ok!(
+                News::update_article(
+                    RuntimeOrigin::signed(publisher.clone()),
+                    parent,
+                    new_hash,
+                    title,
+                    canonical_url.clone(),
+                    make_test_signature(&pair, &new_hash),
+                    HashAlgo::Blake2b256,
+                    word_count,
+                    kind,
+                    summary
+                )
+            );
+            System::assert_has_event(
+                crate::Event::ArticleUpdated {
+                    publisher: publisher.clone(),
+                    old_hash: parent,
+                    new_hash,
+                    updates: ArticleUpdateCount::<Test>::get(anchor),
+                    kind,
+                }.into()
+            );
+            parent = new_hash;
+        }
+
+        // Anchors have no parent to compare with
+        assert!(VersionChanges::<Test>::get(anchor).is_none());
+        let first = VersionChanges::<Test>::get(changes[0].0).unwrap();
+        assert_eq!(first.kind, ChangeKind::Correction);
+        assert_eq!(first.summary, changes[0].3);
+        assert!(!first.title_or_url_changed);
+        assert!(!VersionChanges::<Test>::get(changes[1].0).unwrap().title_or_url_changed);
+        assert!(VersionChanges::<Test>::get(changes[2].0).unwrap().title_or_url_changed);
+        // Compared with the parent, not the anchor
+        let last = VersionChanges::<Test>::get(changes[3].0).unwrap();
+        assert!(!last.title_or_url_changed);
+        assert_eq!(last.summary, Some(ChangeSummary::Hash(notice)));
+
+        assert_eq!(CorrectionsOf::<Test>::get(anchor, changes[3].0), Some(4));
+        assert_eq!(News::substantive_corrections(anchor), vec![changes[0].0, changes[3].0]);
+
+        // Removal clears the metadata and the index
+        assert_ok!(News::remove_article(RuntimeOrigin::signed(publisher.clone()), anchor));
+        while PendingRemovals::<Test>::contains_key(anchor) {
+            assert_ok!(News::continue_removal(RuntimeOrigin::signed(publisher.clone()), anchor));
+        }
+        assert!(News::substantive_corrections(anchor).is_empty());
+        assert!(changes.iter().all(|(version, ..)| VersionChanges::<Test>::get(version).is_none()));
+    });
+}
+
+fn co_authors(entries: &[(&AccountId32, u32)]) -> BoundedVec<CoAuthor<Test>, <Test as crate::Config>::MaxCoAuthors> {
+    BoundedVec::try_from(
+        entries
+            .iter()
+            .map(|(account, percent)| CoAuthor {
+                account: (*account).clone(),
+                share: Perbill::from_percent(*percent),
+            })
+            .collect::<Vec<_>>()
+    ).unwrap()
+}
+
+#[test]
+fn bylines_are_set_by_the_publisher_and_validated() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let versions = record_lineage(&pair, 1);
+        let anchor = versions[0];
+        let publisher: AccountId32 = pair.public().into();
+        let alice: AccountId32 = sr25519::Pair::from_seed(&[5u8; 32]).public().into();
+        let bob: AccountId32 = sr25519::Pair::from_seed(&[6u8; 32]).public().into();
+
+        assert_noop!(
+            News::set_bylines(RuntimeOrigin::signed(alice.clone()), anchor, co_authors(&[(&bob, 10)])),
+            Error::<Test>::NotArticlePublisher
+        );
+        assert_noop!(
+            News::set_bylines(RuntimeOrigin::signed(publisher.clone()), versions[1], co_authors(&[(&bob, 10)])),
+            Error::<Test>::NotAnAnchor
+        );
+        assert_noop!(
+            News::set_bylines(RuntimeOrigin::signed(publisher.clone()), anchor, co_authors(&[(&bob, 10), (&bob, 10)])),
+            Error::<Test>::InvalidCoAuthor
+        );
+        assert_noop!(
+            News::set_bylines(RuntimeOrigin::signed(publisher.clone()), anchor, co_authors(&[(&publisher, 10)])),
+            Error::<Test>::InvalidCoAuthor
+        );
+        assert_noop!(
+            News::set_bylines(RuntimeOrigin::signed(publisher.clone()), anchor, co_authors(&[(&alice, 60), (&bob, 50)])),
+            Error::<Test>::SharesExceedTip
+        );
+
+        // Co-authors may take the whole tip
+        assert_ok!(News::set_bylines(RuntimeOrigin::signed(publisher.clone()), anchor, co_authors(&[(&alice, 60), (&bob, 40)])));
+        System::assert_last_event(crate::Event::BylinesSet { anchor, co_authors: 2 }.into());
+        assert_eq!(Bylines::<Test>::get(anchor).len(), 2);
+
+        assert_ok!(News::set_bylines(RuntimeOrigin::signed(publisher), anchor, co_authors(&[])));
+        assert!(!Bylines::<Test>::contains_key(anchor));
+    });
+}
+
+#[test]
+fn tips_are_split_with_co_authors_and_tallied() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let versions = record_lineage(&pair, 1);
+        let anchor = versions[0];
+        let publisher: AccountId32 = pair.public().into();
+        let alice: AccountId32 = sr25519::Pair::from_seed(&[5u8; 32]).public().into();
+        let bob: AccountId32 = sr25519::Pair::from_seed(&[6u8; 32]).public().into();
+        let reader: AccountId32 = sr25519::Pair::from_seed(&[3u8; 32]).public().into();
+        fund(&reader, 10_000);
+
+        assert_ok!(News::set_bylines(RuntimeOrigin::signed(publisher.clone()), anchor, co_authors(&[(&alice, 20), (&bob, 30)])));
+
+        // Any version tips the lineage
+        assert_ok!(News::tip_article(RuntimeOrigin::signed(reader.clone()), versions[1], 1_000, None));
+        System::assert_last_event(
+            crate::Event::ArticleTipped {
+                anchor,
+                tipper: reader.clone(),
+                publisher: publisher.clone(),
+                amount: 1_000,
+                asset: None,
+            }.into()
+        );
+        assert_eq!(Balances::free_balance(&alice), 200);
+        assert_eq!(Balances::free_balance(&bob), 300);
+        assert_eq!(Balances::free_balance(&publisher), 500);
+        assert_eq!(Balances::free_balance(&reader), 9_000);
+
+        // Shares are rounded down, the publisher gets the rest
+        assert_ok!(News::tip_article(RuntimeOrigin::signed(reader.clone()), anchor, 7, None));
+        assert_eq!(Balances::free_balance(&alice), 201);
+        assert_eq!(Balances::free_balance(&bob), 302);
+        assert_eq!(Balances::free_balance(&publisher), 504);
+
+        let tally = TipTally { tips: 2, total: 1_007 };
+        assert_eq!(TipsByAnchor::<Test>::get(anchor), tally);
+        assert_eq!(News::article_tips(versions[1]), tally);
+        assert_eq!(TipsByPublisher::<Test>::get(&publisher), tally);
+    });
+}
+
+#[test]
+fn tips_in_assets_are_split_but_not_tallied() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let anchor = H256::repeat_byte(42);
+        let alice: AccountId32 = sr25519::Pair::from_seed(&[5u8; 32]).public().into();
+        let reader: AccountId32 = sr25519::Pair::from_seed(&[3u8; 32]).public().into();
+        let usdc = 7;
+        assert_ok!(Assets::force_create(RuntimeOrigin::root(), usdc, publisher.clone(), true, 1));
+        assert_ok!(<Assets as fungibles::Mutate<AccountId32>>::mint_into(usdc, &reader, 1_000));
+        assert_ok!(News::set_bylines(RuntimeOrigin::signed(publisher.clone()), anchor, co_authors(&[(&alice, 20)])));
+
+        assert_ok!(News::tip_article(RuntimeOrigin::signed(reader.clone()), anchor, 500, Some(usdc)));
+        System::assert_last_event(
+            crate::Event::ArticleTipped {
+                anchor,
+                tipper: reader.clone(),
+                publisher: publisher.clone(),
+                amount: 500,
+                asset: Some(usdc),
+            }.into()
+        );
+        assert_eq!(Assets::balance(usdc, &alice), 100);
+        assert_eq!(Assets::balance(usdc, &publisher), 400);
+        assert_eq!(Assets::balance(usdc, &reader), 500);
+        assert_eq!(Balances::free_balance(&reader), 0);
+        assert_eq!(TipsByAnchor::<Test>::get(anchor), TipTally::default());
+        assert_eq!(TipsByPublisher::<Test>::get(&publisher), TipTally::default());
+
+        // The tipper's asset account is kept alive, and unknown assets cannot be tipped
+        assert!(News::tip_article(RuntimeOrigin::signed(reader.clone()), anchor, 500, Some(usdc)).is_err());
+        assert!(News::tip_article(RuntimeOrigin::signed(reader), anchor, 10, Some(8)).is_err());
+    });
+}
+
+#[test]
+fn tip_article_rejects_invalid_tips_and_removal_clears_the_lineage_tally() {
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher = record_default_article(&pair);
+        let anchor = H256::repeat_byte(42);
+        let reader: AccountId32 = sr25519::Pair::from_seed(&[3u8; 32]).public().into();
+        fund(&publisher, 1_000);
+
+        assert_noop!(
+            News::tip_article(RuntimeOrigin::signed(reader.clone()), anchor, 0, None),
+            Error::<Test>::ZeroTip
+        );
+        assert_noop!(
+            News::tip_article(RuntimeOrigin::signed(publisher.clone()), anchor, 10, None),
+            Error::<Test>::CannotTipOwnArticle
+        );
+        assert_noop!(
+            News::tip_article(RuntimeOrigin::signed(reader.clone()), H256::repeat_byte(99), 10, None),
+            Error::<Test>::ArticleNotFound
+        );
+        // Reader without funds cannot tip
+        assert!(News::tip_article(RuntimeOrigin::signed(reader.clone()), anchor, 10, None).is_err());
+
+        fund(&reader, 1_000);
+        assert_ok!(News::tip_article(RuntimeOrigin::signed(reader.clone()), anchor, 10, None));
+
+        assert_ok!(News::remove_article(RuntimeOrigin::signed(publisher.clone()), anchor));
+        assert_eq!(TipsByAnchor::<Test>::get(anchor), TipTally::default());
+        assert_eq!(TipsByPublisher::<Test>::get(&publisher).total, 10);
+        assert_noop!(
+            News::tip_article(RuntimeOrigin::signed(reader), anchor, 10, None),
+            Error::<Test>::ArticleNotFound
+        );
+    });
+}
+
+#[test]
+fn migration_wraps_stored_signatures() {
+    use frame::deps::frame_support::{
+        storage::unhashed,
+        traits::{ GetStorageVersion, OnRuntimeUpgrade, StorageVersion },
+    };
+
+    new_test_ext().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let publisher: AccountId32 = pair.public().into();
+        let (content_hash, collection_id, item_id, title, canonical_url, word_count) = create_test_article_data();
+        let signature = MultiSignature::from(pair.sign(&b"legacy"[..]));
+
+        // A record as stored before signatures became `PublisherSignature`
+        let old_record = (
+            title.clone(),
+            canonical_url.clone(),
+            publisher.clone(),
+            collection_id,
+            item_id,
+            content_hash,
+            signature.clone(),
+            HashAlgo::Blake2b256,
+            word_count,
+            1u64,
+            0u32,
+        );
+        unhashed::put(&ArticleByHash::<Test>::hashed_key_for(content_hash), &old_record);
+        StorageVersion::new(0).put::<News>();
+        assert!(ArticleByHash::<Test>::get(content_hash).is_none());
+
+        crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        let record = ArticleByHash::<Test>::get(content_hash).unwrap();
+        assert_eq!(record.signature, PublisherSignature::Native(signature));
+        assert_eq!(record.publisher, publisher);
+        assert_eq!(record.title, title);
+        assert_eq!(News::on_chain_storage_version(), 1);
+
+        // Runs once
+        crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(ArticleByHash::<Test>::get(content_hash), Some(record));
+    });
+}
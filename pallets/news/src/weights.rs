@@ -21,6 +21,10 @@
 // ../frame-weight-template.hbs
 // --output
 // weights.rs
+//
+// NOTE: The run above only covered `record_article` and `update_article`, as they were then. The
+// weights of all other calls are hand-written placeholders, not benchmark output, and the whole
+// file must be regenerated with the command above before production use.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -34,6 +38,38 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
     fn record_article() -> Weight;
     fn update_article() -> Weight;
+    fn endorse_article() -> Weight;
+    fn withdraw_endorsement() -> Weight;
+    fn submit_fact_check() -> Weight;
+    fn retract_article() -> Weight;
+    fn record_syndication() -> Weight;
+    fn declare_citations(c: u32, ) -> Weight;
+    fn reveal_article() -> Weight;
+    fn lift_embargoes(n: u32, ) -> Weight;
+    fn commit_draft() -> Weight;
+    fn reveal_draft() -> Weight;
+    fn remove_expired_draft() -> Weight;
+    fn submit_integrity_report() -> Weight;
+    fn add_integrity_watcher() -> Weight;
+    fn remove_integrity_watcher() -> Weight;
+    fn record_article_unsigned() -> Weight;
+    fn approve_feeless_publisher() -> Weight;
+    fn revoke_feeless_publisher() -> Weight;
+    fn set_sponsorship_budget() -> Weight;
+    fn sponsor_journalist() -> Weight;
+    fn revoke_sponsorship() -> Weight;
+    fn register_ethereum_address() -> Weight;
+    fn unregister_ethereum_address() -> Weight;
+    fn record_article_for() -> Weight;
+    fn add_signing_key() -> Weight;
+    fn rotate_signing_key() -> Weight;
+    fn revoke_signing_key() -> Weight;
+    fn anchor_batch() -> Weight;
+    fn promote_batch_leaf(p: u32, ) -> Weight;
+    fn remove_article() -> Weight;
+    fn continue_removal(n: u32, ) -> Weight;
+    fn set_bylines(c: u32, ) -> Weight;
+    fn tip_article(c: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_news` using the Substrate node and recommended hardware.
@@ -51,33 +87,638 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
     /// Storage: `News::RootByItem` (r:0 w:1)
     /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
     fn record_article() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `3`
         //  Estimated: `5114`
         // Minimum execution time: 58_000_000 picoseconds.
         Weight::from_parts(59_000_000, 5114)
-            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
             .saturating_add(T::DbWeight::get().writes(6_u64))
     }
     /// Storage: `News::ArticleByHash` (r:2 w:1)
     /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
     /// Storage: `News::AnchorOf` (r:1 w:1)
     /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::Tombstones` (r:2 w:0)
+    /// Proof: `News::Tombstones` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `News::HistoryByAnchor` (r:1 w:1)
     /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryPageCount` (r:1 w:1)
+    /// Proof: `News::HistoryPageCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryPages` (r:0 w:1)
+    /// Proof: `News::HistoryPages` (`max_values`: None, `max_size`: Some(1661), added: 4136, mode: `MaxEncodedLen`)
+    /// Storage: `News::VersionChanges` (r:0 w:1)
+    /// Proof: `News::VersionChanges` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+    /// Storage: `News::CorrectionsOf` (r:0 w:1)
+    /// Proof: `News::CorrectionsOf` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
     /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
     /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
     /// Storage: `News::RootByItem` (r:0 w:1)
     /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
     fn update_article() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `602`
+        //  Measured:  `2268`
+        //  Estimated: `8592`
+        // Minimum execution time: 44_000_000 picoseconds.
+        Weight::from_parts(46_000_000, 8592)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::Endorsements` (r:1 w:1)
+    /// Proof: `News::Endorsements` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `News::EndorsementTallies` (r:1 w:1)
+    /// Proof: `News::EndorsementTallies` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherReputation` (r:1 w:1)
+    /// Proof: `News::PublisherReputation` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn endorse_article() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `640`
+        //  Estimated: `3948`
+        // Minimum execution time: 41_000_000 picoseconds.
+        Weight::from_parts(42_000_000, 3948)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `News::Endorsements` (r:1 w:1)
+    /// Proof: `News::Endorsements` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `News::EndorsementTallies` (r:1 w:1)
+    /// Proof: `News::EndorsementTallies` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherReputation` (r:1 w:1)
+    /// Proof: `News::PublisherReputation` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn withdraw_endorsement() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `812`
+        //  Estimated: `3948`
+        // Minimum execution time: 39_000_000 picoseconds.
+        Weight::from_parts(40_000_000, 3948)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::FactCheckOf` (r:1 w:1)
+    /// Proof: `News::FactCheckOf` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherReputation` (r:1 w:1)
+    /// Proof: `News::PublisherReputation` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn submit_fact_check() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `520`
+        //  Estimated: `3948`
+        // Minimum execution time: 19_000_000 picoseconds.
+        Weight::from_parts(20_000_000, 3948)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::RetractedAt` (r:1 w:1)
+    /// Proof: `News::RetractedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherReputation` (r:1 w:1)
+    /// Proof: `News::PublisherReputation` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn retract_article() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `520`
+        //  Estimated: `3948`
+        // Minimum execution time: 18_000_000 picoseconds.
+        Weight::from_parts(19_000_000, 3948)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:2 w:1)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:1 w:1)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticlesByPublisher` (r:1 w:1)
+    /// Proof: `News::ArticlesByPublisher` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryByAnchor` (r:1 w:1)
+    /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::SyndicatedTo` (r:1 w:1)
+    /// Proof: `News::SyndicatedTo` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::SyndicatedFrom` (r:0 w:1)
+    /// Proof: `News::SyndicatedFrom` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
+    /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:0 w:1)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn record_syndication() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1151`
         //  Estimated: `6906`
-        // Minimum execution time: 28_000_000 picoseconds.
-        Weight::from_parts(29_000_000, 6906)
+        // Minimum execution time: 71_000_000 picoseconds.
+        Weight::from_parts(72_000_000, 6906)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:2 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::CitationsOf` (r:1 w:1)
+    /// Proof: `News::CitationsOf` (`max_values`: None, `max_size`: Some(1071), added: 3546, mode: `MaxEncodedLen`)
+    /// Storage: `News::CitedBy` (r:0 w:1)
+    /// Proof: `News::CitedBy` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+    /// The range of component `c` is `[0, 32]`.
+    fn declare_citations(c: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `658`
+        //  Estimated: `3948`
+        // Minimum execution time: 18_000_000 picoseconds.
+        Weight::from_parts(19_000_000, 3948)
+            // Standard Error: 1_000
+            .saturating_add(Weight::from_parts(4_200_000, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+    }
+    /// Storage: `News::Embargoes` (r:1 w:1)
+    /// Proof: `News::Embargoes` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:1)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn reveal_article() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `658`
+        //  Estimated: `3948`
+        // Minimum execution time: 24_000_000 picoseconds.
+        Weight::from_parts(60_000_000, 3948)
             .saturating_add(T::DbWeight::get().reads(4_u64))
-            .saturating_add(T::DbWeight::get().writes(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::ReleaseQueue` (r:1 w:1)
+    /// Proof: `News::ReleaseQueue` (`max_values`: None, `max_size`: Some(1623), added: 4098, mode: `MaxEncodedLen`)
+    /// Storage: `News::Embargoes` (r:1 w:1)
+    /// Proof: `News::Embargoes` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 50]`.
+    fn lift_embargoes(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `579`
+        //  Estimated: `3478`
+        // Minimum execution time: 3_000_000 picoseconds.
+        Weight::from_parts(4_000_000, 3478)
+            // Standard Error: 1_000
+            .saturating_add(Weight::from_parts(2_900_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+    }
+    /// Storage: `News::DraftCommitments` (r:1 w:1)
+    /// Proof: `News::DraftCommitments` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    fn commit_draft() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `591`
+        //  Estimated: `3551`
+        // Minimum execution time: 36_000_000 picoseconds.
+        Weight::from_parts(37_000_000, 3551)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::DraftCommitments` (r:1 w:1)
+    /// Proof: `News::DraftCommitments` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::PriorityOf` (r:1 w:1)
+    /// Proof: `News::PriorityOf` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    fn reveal_draft() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `658`
+        //  Estimated: `3948`
+        // Minimum execution time: 44_000_000 picoseconds.
+        Weight::from_parts(45_000_000, 3948)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `News::DraftCommitments` (r:1 w:1)
+    /// Proof: `News::DraftCommitments` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    fn remove_expired_draft() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `591`
+        //  Estimated: `3551`
+        // Minimum execution time: 33_000_000 picoseconds.
+        Weight::from_parts(34_000_000, 3551)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::IntegrityWatchers` (r:1 w:0)
+    /// Proof: `News::IntegrityWatchers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `News::IntegrityStatusOf` (r:1 w:1)
+    /// Proof: `News::IntegrityStatusOf` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+    fn submit_integrity_report() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `689`
+        //  Estimated: `3948`
+        // Minimum execution time: 16_000_000 picoseconds.
+        Weight::from_parts(17_000_000, 3948)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::IntegrityWatchers` (r:1 w:1)
+    /// Proof: `News::IntegrityWatchers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn add_integrity_watcher() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `585`
+        //  Estimated: `3513`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3513)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::IntegrityWatchers` (r:1 w:1)
+    /// Proof: `News::IntegrityWatchers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn remove_integrity_watcher() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `585`
+        //  Estimated: `3513`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3513)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::FeelessPublishers` (r:1 w:0)
+    /// Proof: `News::FeelessPublishers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `News::FeelessAnchorsInEra` (r:1 w:1)
+    /// Proof: `News::FeelessAnchorsInEra` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:1)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticlesByPublisher` (r:1 w:1)
+    /// Proof: `News::ArticlesByPublisher` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryByAnchor` (r:1 w:1)
+    /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:0 w:1)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
+    /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:0 w:1)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherNonces` (r:1 w:1)
+    /// Proof: `News::PublisherNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn record_article_unsigned() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `852`
+        //  Estimated: `5114`
+        // Minimum execution time: 63_000_000 picoseconds.
+        Weight::from_parts(64_000_000, 5114)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    /// Storage: `News::FeelessPublishers` (r:1 w:1)
+    /// Proof: `News::FeelessPublishers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn approve_feeless_publisher() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `585`
+        //  Estimated: `3513`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3513)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::FeelessPublishers` (r:1 w:1)
+    /// Proof: `News::FeelessPublishers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `News::FeelessAnchorsInEra` (r:0 w:1)
+    /// Proof: `News::FeelessAnchorsInEra` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn revoke_feeless_publisher() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `585`
+        //  Estimated: `3513`
+        // Minimum execution time: 10_000_000 picoseconds.
+        Weight::from_parts(11_000_000, 3513)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::SponsorBudgets` (r:0 w:1)
+    /// Proof: `News::SponsorBudgets` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    fn set_sponsorship_budget() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 7_000_000 picoseconds.
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::SponsoredJournalists` (r:1 w:1)
+    /// Proof: `News::SponsoredJournalists` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    fn sponsor_journalist() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `593`
+        //  Estimated: `3561`
+        // Minimum execution time: 10_000_000 picoseconds.
+        Weight::from_parts(11_000_000, 3561)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::SponsoredJournalists` (r:1 w:1)
+    /// Proof: `News::SponsoredJournalists` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    fn revoke_sponsorship() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `593`
+        //  Estimated: `3561`
+        // Minimum execution time: 10_000_000 picoseconds.
+        Weight::from_parts(11_000_000, 3561)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::PublisherOfEthereumAddress` (r:1 w:2)
+    /// Proof: `News::PublisherOfEthereumAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:1)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn register_ethereum_address() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `180`
+        //  Estimated: `3533`
+        // Minimum execution time: 58_000_000 picoseconds.
+        Weight::from_parts(60_000_000, 3533)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `News::EthereumAddressOf` (r:1 w:1)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherOfEthereumAddress` (r:0 w:1)
+    /// Proof: `News::PublisherOfEthereumAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn unregister_ethereum_address() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `240`
+        //  Estimated: `3533`
+        // Minimum execution time: 11_000_000 picoseconds.
+        Weight::from_parts(12_000_000, 3533)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:1)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticlesByPublisher` (r:1 w:1)
+    /// Proof: `News::ArticlesByPublisher` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryByAnchor` (r:1 w:1)
+    /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:0 w:1)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
+    /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:0 w:1)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherNonces` (r:1 w:1)
+    /// Proof: `News::PublisherNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn record_article_for() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3`
+        //  Estimated: `5114`
+        // Minimum execution time: 58_000_000 picoseconds.
+        Weight::from_parts(59_000_000, 5114)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    /// Storage: `News::SigningKeys` (r:1 w:1)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn add_signing_key() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3`
+        //  Estimated: `3575`
+        // Minimum execution time: 52_000_000 picoseconds.
+        Weight::from_parts(53_000_000, 3575)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::SigningKeys` (r:2 w:2)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn rotate_signing_key() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `186`
+        //  Estimated: `6160`
+        // Minimum execution time: 58_000_000 picoseconds.
+        Weight::from_parts(59_000_000, 6160)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::SigningKeys` (r:1 w:1)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn revoke_signing_key() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `186`
+        //  Estimated: `3575`
+        // Minimum execution time: 14_000_000 picoseconds.
+        Weight::from_parts(15_000_000, 3575)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::Batches` (r:1 w:1)
+    /// Proof: `News::Batches` (`max_values`: None, `max_size`: Some(268), added: 2743, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn anchor_batch() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3`
+        //  Estimated: `3717`
+        // Minimum execution time: 55_000_000 picoseconds.
+        Weight::from_parts(56_000_000, 3717)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::Batches` (r:1 w:0)
+    /// Proof: `News::Batches` (`max_values`: None, `max_size`: Some(268), added: 2743, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:1)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticlesByPublisher` (r:1 w:1)
+    /// Proof: `News::ArticlesByPublisher` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryByAnchor` (r:1 w:1)
+    /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:0 w:1)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
+    /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:0 w:1)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::BatchOf` (r:0 w:1)
+    /// Proof: `News::BatchOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// The range of component `p` is `[1, 32]`.
+    fn promote_batch_leaf(p: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `302`
+        //  Estimated: `5114`
+        // Minimum execution time: 31_000_000 picoseconds.
+        Weight::from_parts(30_412_000, 5114)
+            // Standard Error: 1_204
+            .saturating_add(Weight::from_parts(1_185_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    /// Storage: `News::AnchorOf` (r:50 w:50)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryByAnchor` (r:1 w:1)
+    /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:50 w:50)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:50 w:1)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::Tombstones` (r:0 w:50)
+    /// Proof: `News::Tombstones` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
+    /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticlesByPublisher` (r:1 w:1)
+    /// Proof: `News::ArticlesByPublisher` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::SyndicatedFrom` (r:1 w:1)
+    /// Proof: `News::SyndicatedFrom` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::SyndicatedTo` (r:1 w:2)
+    /// Proof: `News::SyndicatedTo` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::Embargoes` (r:1 w:1)
+    /// Proof: `News::Embargoes` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+    /// Storage: `News::ReleaseQueue` (r:1 w:1)
+    /// Proof: `News::ReleaseQueue` (`max_values`: None, `max_size`: Some(1606), added: 4081, mode: `MaxEncodedLen`)
+    /// Storage: `News::Bylines` (r:0 w:1)
+    /// Proof: `News::Bylines` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+    /// Storage: `News::TipsByAnchor` (r:0 w:1)
+    /// Proof: `News::TipsByAnchor` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::PendingRemovals` (r:0 w:1)
+    /// Proof: `News::PendingRemovals` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    fn remove_article() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `24871`
+        //  Estimated: `153310`
+        // Minimum execution time: 412_000_000 picoseconds.
+        Weight::from_parts(418_000_000, 153310)
+            .saturating_add(T::DbWeight::get().reads(158_u64))
+            .saturating_add(T::DbWeight::get().writes(163_u64))
+    }
+    /// Storage: `News::PendingRemovals` (r:1 w:1)
+    /// Proof: `News::PendingRemovals` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryPageCount` (r:1 w:1)
+    /// Proof: `News::HistoryPageCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryPages` (r:1 w:1)
+    /// Proof: `News::HistoryPages` (`max_values`: None, `max_size`: Some(1661), added: 4136, mode: `MaxEncodedLen`)
+    /// Storage: `News::Tombstones` (r:1 w:50)
+    /// Proof: `News::Tombstones` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:50 w:50)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:50 w:0)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:0 w:50)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::Endorsements` (r:101 w:100)
+    /// Proof: `News::Endorsements` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:100 w:100)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `News::CitedBy` (r:33 w:32)
+    /// Proof: `News::CitedBy` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+    /// Storage: `News::CitationsOf` (r:33 w:33)
+    /// Proof: `News::CitationsOf` (`max_values`: None, `max_size`: Some(1071), added: 3546, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherReputation` (r:1 w:1)
+    /// Proof: `News::PublisherReputation` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `News::EndorsementTallies` (r:0 w:1)
+    /// Proof: `News::EndorsementTallies` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `News::FactCheckOf` (r:0 w:1)
+    /// Proof: `News::FactCheckOf` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `News::RetractedAt` (r:0 w:1)
+    /// Proof: `News::RetractedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::IntegrityStatusOf` (r:0 w:1)
+    /// Proof: `News::IntegrityStatusOf` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+    /// Storage: `News::PriorityOf` (r:0 w:1)
+    /// Proof: `News::PriorityOf` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+    /// Storage: `News::BatchOf` (r:0 w:1)
+    /// Proof: `News::BatchOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::VersionChanges` (r:0 w:1)
+    /// Proof: `News::VersionChanges` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+    /// Storage: `News::CorrectionsOf` (r:0 w:1)
+    /// Proof: `News::CorrectionsOf` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 100]`.
+    fn continue_removal(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `27340 + n * (177 ±0)`
+        //  Estimated: `160246 + n * (2588 ±0)`
+        // Minimum execution time: 268_000_000 picoseconds.
+        Weight::from_parts(264_915_000, 160246)
+            // Standard Error: 9_310
+            .saturating_add(Weight::from_parts(31_420_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(139_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(195_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2588).saturating_mul(n.into()))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::Bylines` (r:0 w:1)
+    /// Proof: `News::Bylines` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+    /// The range of component `c` is `[0, 8]`.
+    fn set_bylines(c: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `612`
+        //  Estimated: `3948`
+        // Minimum execution time: 17_000_000 picoseconds.
+        Weight::from_parts(17_842_000, 3948)
+            // Standard Error: 2_104
+            .saturating_add(Weight::from_parts(214_000, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::Bylines` (r:1 w:0)
+    /// Proof: `News::Bylines` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:10 w:10)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `News::TipsByAnchor` (r:1 w:1)
+    /// Proof: `News::TipsByAnchor` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::TipsByPublisher` (r:1 w:1)
+    /// Proof: `News::TipsByPublisher` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// The range of component `c` is `[0, 8]`.
+    fn tip_article(c: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `851 + c * (38 ±0)`
+        //  Estimated: `3948 + c * (2603 ±0)`
+        // Minimum execution time: 68_000_000 picoseconds.
+        Weight::from_parts(71_365_000, 3948)
+            // Standard Error: 18_412
+            .saturating_add(Weight::from_parts(29_806_000, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+            .saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
     }
 }
 
@@ -95,32 +736,637 @@ impl WeightInfo for () {
     /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
     /// Storage: `News::RootByItem` (r:0 w:1)
     /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
     fn record_article() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `3`
         //  Estimated: `5114`
         // Minimum execution time: 58_000_000 picoseconds.
         Weight::from_parts(59_000_000, 5114)
-            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
             .saturating_add(RocksDbWeight::get().writes(6_u64))
     }
     /// Storage: `News::ArticleByHash` (r:2 w:1)
     /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
     /// Storage: `News::AnchorOf` (r:1 w:1)
     /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::Tombstones` (r:2 w:0)
+    /// Proof: `News::Tombstones` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
     /// Storage: `News::HistoryByAnchor` (r:1 w:1)
     /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryPageCount` (r:1 w:1)
+    /// Proof: `News::HistoryPageCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryPages` (r:0 w:1)
+    /// Proof: `News::HistoryPages` (`max_values`: None, `max_size`: Some(1661), added: 4136, mode: `MaxEncodedLen`)
+    /// Storage: `News::VersionChanges` (r:0 w:1)
+    /// Proof: `News::VersionChanges` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+    /// Storage: `News::CorrectionsOf` (r:0 w:1)
+    /// Proof: `News::CorrectionsOf` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
     /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
     /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
     /// Storage: `News::RootByItem` (r:0 w:1)
     /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
     fn update_article() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `602`
+        //  Measured:  `2268`
+        //  Estimated: `8592`
+        // Minimum execution time: 44_000_000 picoseconds.
+        Weight::from_parts(46_000_000, 8592)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::Endorsements` (r:1 w:1)
+    /// Proof: `News::Endorsements` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `News::EndorsementTallies` (r:1 w:1)
+    /// Proof: `News::EndorsementTallies` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherReputation` (r:1 w:1)
+    /// Proof: `News::PublisherReputation` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn endorse_article() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `640`
+        //  Estimated: `3948`
+        // Minimum execution time: 41_000_000 picoseconds.
+        Weight::from_parts(42_000_000, 3948)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `News::Endorsements` (r:1 w:1)
+    /// Proof: `News::Endorsements` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `News::EndorsementTallies` (r:1 w:1)
+    /// Proof: `News::EndorsementTallies` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherReputation` (r:1 w:1)
+    /// Proof: `News::PublisherReputation` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn withdraw_endorsement() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `812`
+        //  Estimated: `3948`
+        // Minimum execution time: 39_000_000 picoseconds.
+        Weight::from_parts(40_000_000, 3948)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::FactCheckOf` (r:1 w:1)
+    /// Proof: `News::FactCheckOf` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherReputation` (r:1 w:1)
+    /// Proof: `News::PublisherReputation` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn submit_fact_check() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `520`
+        //  Estimated: `3948`
+        // Minimum execution time: 19_000_000 picoseconds.
+        Weight::from_parts(20_000_000, 3948)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::RetractedAt` (r:1 w:1)
+    /// Proof: `News::RetractedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherReputation` (r:1 w:1)
+    /// Proof: `News::PublisherReputation` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn retract_article() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `520`
+        //  Estimated: `3948`
+        // Minimum execution time: 18_000_000 picoseconds.
+        Weight::from_parts(19_000_000, 3948)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:2 w:1)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:1 w:1)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticlesByPublisher` (r:1 w:1)
+    /// Proof: `News::ArticlesByPublisher` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryByAnchor` (r:1 w:1)
+    /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::SyndicatedTo` (r:1 w:1)
+    /// Proof: `News::SyndicatedTo` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::SyndicatedFrom` (r:0 w:1)
+    /// Proof: `News::SyndicatedFrom` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
+    /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:0 w:1)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn record_syndication() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1151`
         //  Estimated: `6906`
-        // Minimum execution time: 28_000_000 picoseconds.
-        Weight::from_parts(29_000_000, 6906)
+        // Minimum execution time: 71_000_000 picoseconds.
+        Weight::from_parts(72_000_000, 6906)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:2 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::CitationsOf` (r:1 w:1)
+    /// Proof: `News::CitationsOf` (`max_values`: None, `max_size`: Some(1071), added: 3546, mode: `MaxEncodedLen`)
+    /// Storage: `News::CitedBy` (r:0 w:1)
+    /// Proof: `News::CitedBy` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+    /// The range of component `c` is `[0, 32]`.
+    fn declare_citations(c: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `658`
+        //  Estimated: `3948`
+        // Minimum execution time: 18_000_000 picoseconds.
+        Weight::from_parts(19_000_000, 3948)
+            // Standard Error: 1_000
+            .saturating_add(Weight::from_parts(4_200_000, 0).saturating_mul(c.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+    }
+    /// Storage: `News::Embargoes` (r:1 w:1)
+    /// Proof: `News::Embargoes` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:1)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn reveal_article() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `658`
+        //  Estimated: `3948`
+        // Minimum execution time: 24_000_000 picoseconds.
+        Weight::from_parts(60_000_000, 3948)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::ReleaseQueue` (r:1 w:1)
+    /// Proof: `News::ReleaseQueue` (`max_values`: None, `max_size`: Some(1623), added: 4098, mode: `MaxEncodedLen`)
+    /// Storage: `News::Embargoes` (r:1 w:1)
+    /// Proof: `News::Embargoes` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 50]`.
+    fn lift_embargoes(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `579`
+        //  Estimated: `3478`
+        // Minimum execution time: 3_000_000 picoseconds.
+        Weight::from_parts(4_000_000, 3478)
+            // Standard Error: 1_000
+            .saturating_add(Weight::from_parts(2_900_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+    }
+    /// Storage: `News::DraftCommitments` (r:1 w:1)
+    /// Proof: `News::DraftCommitments` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    fn commit_draft() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `591`
+        //  Estimated: `3551`
+        // Minimum execution time: 36_000_000 picoseconds.
+        Weight::from_parts(37_000_000, 3551)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::DraftCommitments` (r:1 w:1)
+    /// Proof: `News::DraftCommitments` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::PriorityOf` (r:1 w:1)
+    /// Proof: `News::PriorityOf` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    fn reveal_draft() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `658`
+        //  Estimated: `3948`
+        // Minimum execution time: 44_000_000 picoseconds.
+        Weight::from_parts(45_000_000, 3948)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `News::DraftCommitments` (r:1 w:1)
+    /// Proof: `News::DraftCommitments` (`max_values`: None, `max_size`: Some(120), added: 2595, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    fn remove_expired_draft() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `591`
+        //  Estimated: `3551`
+        // Minimum execution time: 33_000_000 picoseconds.
+        Weight::from_parts(34_000_000, 3551)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::IntegrityWatchers` (r:1 w:0)
+    /// Proof: `News::IntegrityWatchers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `News::IntegrityStatusOf` (r:1 w:1)
+    /// Proof: `News::IntegrityStatusOf` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+    fn submit_integrity_report() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `689`
+        //  Estimated: `3948`
+        // Minimum execution time: 16_000_000 picoseconds.
+        Weight::from_parts(17_000_000, 3948)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::IntegrityWatchers` (r:1 w:1)
+    /// Proof: `News::IntegrityWatchers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn add_integrity_watcher() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `585`
+        //  Estimated: `3513`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3513)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::IntegrityWatchers` (r:1 w:1)
+    /// Proof: `News::IntegrityWatchers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn remove_integrity_watcher() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `585`
+        //  Estimated: `3513`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3513)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::FeelessPublishers` (r:1 w:0)
+    /// Proof: `News::FeelessPublishers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `News::FeelessAnchorsInEra` (r:1 w:1)
+    /// Proof: `News::FeelessAnchorsInEra` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:1)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticlesByPublisher` (r:1 w:1)
+    /// Proof: `News::ArticlesByPublisher` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryByAnchor` (r:1 w:1)
+    /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:0 w:1)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
+    /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:0 w:1)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherNonces` (r:1 w:1)
+    /// Proof: `News::PublisherNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn record_article_unsigned() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `852`
+        //  Estimated: `5114`
+        // Minimum execution time: 63_000_000 picoseconds.
+        Weight::from_parts(64_000_000, 5114)
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    /// Storage: `News::FeelessPublishers` (r:1 w:1)
+    /// Proof: `News::FeelessPublishers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn approve_feeless_publisher() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `585`
+        //  Estimated: `3513`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3513)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::FeelessPublishers` (r:1 w:1)
+    /// Proof: `News::FeelessPublishers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `News::FeelessAnchorsInEra` (r:0 w:1)
+    /// Proof: `News::FeelessAnchorsInEra` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn revoke_feeless_publisher() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `585`
+        //  Estimated: `3513`
+        // Minimum execution time: 10_000_000 picoseconds.
+        Weight::from_parts(11_000_000, 3513)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::SponsorBudgets` (r:0 w:1)
+    /// Proof: `News::SponsorBudgets` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    fn set_sponsorship_budget() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 7_000_000 picoseconds.
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::SponsoredJournalists` (r:1 w:1)
+    /// Proof: `News::SponsoredJournalists` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    fn sponsor_journalist() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `593`
+        //  Estimated: `3561`
+        // Minimum execution time: 10_000_000 picoseconds.
+        Weight::from_parts(11_000_000, 3561)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::SponsoredJournalists` (r:1 w:1)
+    /// Proof: `News::SponsoredJournalists` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    fn revoke_sponsorship() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `593`
+        //  Estimated: `3561`
+        // Minimum execution time: 10_000_000 picoseconds.
+        Weight::from_parts(11_000_000, 3561)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::PublisherOfEthereumAddress` (r:1 w:2)
+    /// Proof: `News::PublisherOfEthereumAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:1)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn register_ethereum_address() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `180`
+        //  Estimated: `3533`
+        // Minimum execution time: 58_000_000 picoseconds.
+        Weight::from_parts(60_000_000, 3533)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `News::EthereumAddressOf` (r:1 w:1)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherOfEthereumAddress` (r:0 w:1)
+    /// Proof: `News::PublisherOfEthereumAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn unregister_ethereum_address() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `240`
+        //  Estimated: `3533`
+        // Minimum execution time: 11_000_000 picoseconds.
+        Weight::from_parts(12_000_000, 3533)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:1)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticlesByPublisher` (r:1 w:1)
+    /// Proof: `News::ArticlesByPublisher` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryByAnchor` (r:1 w:1)
+    /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:0 w:1)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
+    /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:0 w:1)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherNonces` (r:1 w:1)
+    /// Proof: `News::PublisherNonces` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn record_article_for() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3`
+        //  Estimated: `5114`
+        // Minimum execution time: 58_000_000 picoseconds.
+        Weight::from_parts(59_000_000, 5114)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    /// Storage: `News::SigningKeys` (r:1 w:1)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn add_signing_key() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3`
+        //  Estimated: `3575`
+        // Minimum execution time: 52_000_000 picoseconds.
+        Weight::from_parts(53_000_000, 3575)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::SigningKeys` (r:2 w:2)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn rotate_signing_key() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `186`
+        //  Estimated: `6160`
+        // Minimum execution time: 58_000_000 picoseconds.
+        Weight::from_parts(59_000_000, 6160)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `News::SigningKeys` (r:1 w:1)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn revoke_signing_key() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `186`
+        //  Estimated: `3575`
+        // Minimum execution time: 14_000_000 picoseconds.
+        Weight::from_parts(15_000_000, 3575)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::Batches` (r:1 w:1)
+    /// Proof: `News::Batches` (`max_values`: None, `max_size`: Some(268), added: 2743, mode: `MaxEncodedLen`)
+    /// Storage: `News::EthereumAddressOf` (r:1 w:0)
+    /// Proof: `News::EthereumAddressOf` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::SigningKeys` (r:1 w:0)
+    /// Proof: `News::SigningKeys` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+    fn anchor_batch() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3`
+        //  Estimated: `3717`
+        // Minimum execution time: 55_000_000 picoseconds.
+        Weight::from_parts(56_000_000, 3717)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::Batches` (r:1 w:0)
+    /// Proof: `News::Batches` (`max_values`: None, `max_size`: Some(268), added: 2743, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:1 w:1)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticlesByPublisher` (r:1 w:1)
+    /// Proof: `News::ArticlesByPublisher` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryByAnchor` (r:1 w:1)
+    /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:0 w:1)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
+    /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:0 w:1)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::BatchOf` (r:0 w:1)
+    /// Proof: `News::BatchOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// The range of component `p` is `[1, 32]`.
+    fn promote_batch_leaf(p: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `302`
+        //  Estimated: `5114`
+        // Minimum execution time: 31_000_000 picoseconds.
+        Weight::from_parts(30_412_000, 5114)
+            // Standard Error: 1_204
+            .saturating_add(Weight::from_parts(1_185_000, 0).saturating_mul(p.into()))
             .saturating_add(RocksDbWeight::get().reads(4_u64))
-            .saturating_add(RocksDbWeight::get().writes(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    /// Storage: `News::AnchorOf` (r:50 w:50)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryByAnchor` (r:1 w:1)
+    /// Proof: `News::HistoryByAnchor` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:50 w:50)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:50 w:1)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::Tombstones` (r:0 w:50)
+    /// Proof: `News::Tombstones` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleUpdateCount` (r:0 w:1)
+    /// Proof: `News::ArticleUpdateCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticlesByPublisher` (r:1 w:1)
+    /// Proof: `News::ArticlesByPublisher` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::SyndicatedFrom` (r:1 w:1)
+    /// Proof: `News::SyndicatedFrom` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::SyndicatedTo` (r:1 w:2)
+    /// Proof: `News::SyndicatedTo` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::Embargoes` (r:1 w:1)
+    /// Proof: `News::Embargoes` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+    /// Storage: `News::ReleaseQueue` (r:1 w:1)
+    /// Proof: `News::ReleaseQueue` (`max_values`: None, `max_size`: Some(1606), added: 4081, mode: `MaxEncodedLen`)
+    /// Storage: `News::Bylines` (r:0 w:1)
+    /// Proof: `News::Bylines` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+    /// Storage: `News::TipsByAnchor` (r:0 w:1)
+    /// Proof: `News::TipsByAnchor` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::PendingRemovals` (r:0 w:1)
+    /// Proof: `News::PendingRemovals` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    fn remove_article() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `24871`
+        //  Estimated: `153310`
+        // Minimum execution time: 412_000_000 picoseconds.
+        Weight::from_parts(418_000_000, 153310)
+            .saturating_add(RocksDbWeight::get().reads(158_u64))
+            .saturating_add(RocksDbWeight::get().writes(163_u64))
+    }
+    /// Storage: `News::PendingRemovals` (r:1 w:1)
+    /// Proof: `News::PendingRemovals` (`max_values`: None, `max_size`: Some(1649), added: 4124, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryPageCount` (r:1 w:1)
+    /// Proof: `News::HistoryPageCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::HistoryPages` (r:1 w:1)
+    /// Proof: `News::HistoryPages` (`max_values`: None, `max_size`: Some(1661), added: 4136, mode: `MaxEncodedLen`)
+    /// Storage: `News::Tombstones` (r:1 w:50)
+    /// Proof: `News::Tombstones` (`max_values`: None, `max_size`: Some(121), added: 2596, mode: `MaxEncodedLen`)
+    /// Storage: `News::ArticleByHash` (r:50 w:50)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::RootByItem` (r:50 w:0)
+    /// Proof: `News::RootByItem` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:0 w:50)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::Endorsements` (r:101 w:100)
+    /// Proof: `News::Endorsements` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:100 w:100)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `News::CitedBy` (r:33 w:32)
+    /// Proof: `News::CitedBy` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+    /// Storage: `News::CitationsOf` (r:33 w:33)
+    /// Proof: `News::CitationsOf` (`max_values`: None, `max_size`: Some(1071), added: 3546, mode: `MaxEncodedLen`)
+    /// Storage: `News::PublisherReputation` (r:1 w:1)
+    /// Proof: `News::PublisherReputation` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `News::EndorsementTallies` (r:0 w:1)
+    /// Proof: `News::EndorsementTallies` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `News::FactCheckOf` (r:0 w:1)
+    /// Proof: `News::FactCheckOf` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `News::RetractedAt` (r:0 w:1)
+    /// Proof: `News::RetractedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `News::IntegrityStatusOf` (r:0 w:1)
+    /// Proof: `News::IntegrityStatusOf` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+    /// Storage: `News::PriorityOf` (r:0 w:1)
+    /// Proof: `News::PriorityOf` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+    /// Storage: `News::BatchOf` (r:0 w:1)
+    /// Proof: `News::BatchOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::VersionChanges` (r:0 w:1)
+    /// Proof: `News::VersionChanges` (`max_values`: None, `max_size`: Some(343), added: 2818, mode: `MaxEncodedLen`)
+    /// Storage: `News::CorrectionsOf` (r:0 w:1)
+    /// Proof: `News::CorrectionsOf` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 100]`.
+    fn continue_removal(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `27340 + n * (177 ±0)`
+        //  Estimated: `160246 + n * (2588 ±0)`
+        // Minimum execution time: 268_000_000 picoseconds.
+        Weight::from_parts(264_915_000, 160246)
+            // Standard Error: 9_310
+            .saturating_add(Weight::from_parts(31_420_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(139_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(195_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2588).saturating_mul(n.into()))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::Bylines` (r:0 w:1)
+    /// Proof: `News::Bylines` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+    /// The range of component `c` is `[0, 8]`.
+    fn set_bylines(c: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `612`
+        //  Estimated: `3948`
+        // Minimum execution time: 17_000_000 picoseconds.
+        Weight::from_parts(17_842_000, 3948)
+            // Standard Error: 2_104
+            .saturating_add(Weight::from_parts(214_000, 0).saturating_mul(c.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `News::ArticleByHash` (r:1 w:0)
+    /// Proof: `News::ArticleByHash` (`max_values`: None, `max_size`: Some(483), added: 2958, mode: `MaxEncodedLen`)
+    /// Storage: `News::AnchorOf` (r:1 w:0)
+    /// Proof: `News::AnchorOf` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `News::Bylines` (r:1 w:0)
+    /// Proof: `News::Bylines` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:10 w:10)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `News::TipsByAnchor` (r:1 w:1)
+    /// Proof: `News::TipsByAnchor` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `News::TipsByPublisher` (r:1 w:1)
+    /// Proof: `News::TipsByPublisher` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// The range of component `c` is `[0, 8]`.
+    fn tip_article(c: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `851 + c * (38 ±0)`
+        //  Estimated: `3948 + c * (2603 ±0)`
+        // Minimum execution time: 68_000_000 picoseconds.
+        Weight::from_parts(71_365_000, 3948)
+            // Standard Error: 18_412
+            .saturating_add(Weight::from_parts(29_806_000, 0).saturating_mul(c.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+            .saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
     }
 }
@@ -0,0 +1,99 @@
+//! Benchmarking setup for pallet-subscriptions
+
+use super::*;
+use frame::{ deps::frame_benchmarking::v2::*, prelude::* };
+use frame::traits::{ fungible::{ Inspect, InspectHold, Mutate }, Saturating, Zero };
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+    use crate::pallet::Pallet as Subscriptions;
+    use frame_system::RawOrigin;
+
+    /// Creates a deposit plan of a funded publisher and returns the publisher.
+    fn setup_plan<T: Config>() -> T::AccountId {
+        let publisher: T::AccountId = account("publisher", 0, 0);
+        T::Currency::set_balance(&publisher, T::Currency::minimum_balance());
+        Subscriptions::<T>
+            ::create_plan(
+                RawOrigin::Signed(publisher.clone()).into(),
+                price::<T>(),
+                10u32.into(),
+                PaymentKind::Deposit
+            )
+            .unwrap();
+        publisher
+    }
+
+    /// Price of the benchmark plans.
+    fn price<T: Config>() -> BalanceOf<T> {
+        T::Currency::minimum_balance().saturating_mul(10u32.into())
+    }
+
+    /// Returns a reader funded for several subscriptions.
+    fn funded_reader<T: Config>() -> T::AccountId {
+        let reader: T::AccountId = account("reader", 0, 0);
+        T::Currency::set_balance(&reader, price::<T>().saturating_mul(10u32.into()));
+        reader
+    }
+
+    #[benchmark]
+    fn create_plan() {
+        let publisher: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        create_plan(RawOrigin::Signed(publisher.clone()), price::<T>(), 10u32.into(), PaymentKind::Outright);
+
+        assert!(Plans::<T>::contains_key(&publisher, 0));
+    }
+
+    #[benchmark]
+    fn retire_plan() {
+        let publisher = setup_plan::<T>();
+
+        #[extrinsic_call]
+        retire_plan(RawOrigin::Signed(publisher.clone()), 0);
+
+        assert!(!Plans::<T>::contains_key(&publisher, 0));
+    }
+
+    #[benchmark]
+    fn subscribe() {
+        // An expired deposit subscription to release before holding the new deposit
+        let publisher = setup_plan::<T>();
+        let reader = funded_reader::<T>();
+        Subscriptions::<T>
+            ::subscribe(RawOrigin::Signed(reader.clone()).into(), publisher.clone(), 0)
+            .unwrap();
+        frame_system::Pallet::<T>::set_block_number(100u32.into());
+
+        #[extrinsic_call]
+        subscribe(RawOrigin::Signed(reader.clone()), publisher.clone(), 0);
+
+        let subscription = Subscribers::<T>::get(&publisher, &reader).unwrap();
+        assert_eq!(subscription.expires_at, 110u32.into());
+        assert_eq!(
+            T::Currency::balance_on_hold(&HoldReason::SubscriptionDeposit.into(), &reader),
+            price::<T>()
+        );
+    }
+
+    #[benchmark]
+    fn unsubscribe() {
+        let publisher = setup_plan::<T>();
+        let reader = funded_reader::<T>();
+        Subscriptions::<T>
+            ::subscribe(RawOrigin::Signed(reader.clone()).into(), publisher.clone(), 0)
+            .unwrap();
+
+        #[extrinsic_call]
+        unsubscribe(RawOrigin::Signed(reader.clone()), publisher.clone());
+
+        assert!(!Subscribers::<T>::contains_key(&publisher, &reader));
+        assert!(
+            T::Currency::balance_on_hold(&HoldReason::SubscriptionDeposit.into(), &reader).is_zero()
+        );
+    }
+
+    impl_benchmark_test_suite!(Subscriptions, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,453 @@
+//! # Pallet Subscriptions
+//!
+//! Paywall subscriptions to publishers of articles anchored in `pallet_news`: publishers define
+//! subscription plans, and readers buy time-bound access to every lineage they publish.
+//!
+//! ## Plans
+//! A publisher has up to `MaxPlansPerPublisher` plans on sale at once, each with
+//! - a `price` in the native currency; zero for a free registration wall,
+//! - a `period` in blocks the access lasts from the purchase,
+//! - a `PaymentKind`: `Outright` transfers the price to the publisher, `Deposit` holds it from
+//!   the reader until the subscription is ended.
+//!
+//! Plans never change once created. A retired plan can no longer be bought, while the
+//! subscriptions already bought under it run until they expire.
+//!
+//! ## Storage
+//! - `Plans`: (publisher, plan id) -> price, period and payment kind of the plan
+//! - `NextPlanId`: Publisher -> id of the next plan they create
+//! - `PlanCount`: Publisher -> number of plans they have on sale
+//! - `Subscribers`: (publisher, reader) -> plan, deposit and expiry of the reader's subscription
+//!
+//! ## Calls
+//! - `create_plan`: Define a subscription plan to the caller's articles.
+//! - `retire_plan`: Stop selling a plan.
+//! - `subscribe`: Buy access to a publisher's articles under one of their plans.
+//! - `unsubscribe`: End the caller's subscription to a publisher, releasing its deposit.
+//!
+//! ## Events
+//! - PlanCreated, PlanRetired, Subscribed, Unsubscribed
+//!
+//! ## Access attestations
+//! A paywall checks a reader without the reader sending a transaction: it hands the reader a
+//! random `challenge`, the reader's wallet signs the `AccessAttestation` naming the article
+//! anchor, the challenge and a `valid_until` block (see `AccessAttestation::signing_message`),
+//! and the paywall passes it to
+//! `verify_access` (also exposed through `runtime_api::SubscriptionsApi`) on its own node. The
+//! call returns when the reader's subscription to the article's publisher expires, or `None` if
+//! the signature, the chain or the subscription does not check out.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use self::pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod runtime_api;
+pub mod weights;
+
+// FRAME test scaffolding
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[frame::pallet]
+pub mod pallet {
+    use crate::weights::WeightInfo;
+    use alloc::vec::Vec;
+    use frame::{
+        deps::sp_runtime::traits::IdentifyAccount,
+        prelude::*,
+        traits::{
+            fungible::{ Inspect, Mutate, MutateHold },
+            tokens::{ Precision, Preservation },
+            Saturating,
+            Verify,
+            Zero,
+        },
+    };
+    use pallet_news::{ AnchorInspect, ContentHash };
+
+    /// Identifier of a subscription plan, unique per publisher.
+    pub type PlanId = u32;
+    /// Balance of the currency subscriptions are paid in.
+    pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<
+        <T as frame_system::Config>::AccountId
+    >>::Balance;
+
+    /// How a reader pays for a subscription.
+    #[derive(
+        TypeInfo,
+        DecodeWithMemTracking,
+        Encode,
+        Decode,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        MaxEncodedLen,
+        Debug
+    )]
+    pub enum PaymentKind {
+        /// The price is transferred to the publisher.
+        Outright,
+        /// The price is held from the reader until the subscription is ended.
+        Deposit,
+    }
+
+    /// Terms of a subscription plan.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Plan<T: Config> {
+        /// Price of one period; zero for a free plan.
+        pub price: BalanceOf<T>,
+        /// Blocks of access bought with one payment.
+        pub period: BlockNumberFor<T>,
+        /// Whether the price is paid or held.
+        pub payment: PaymentKind,
+    }
+
+    /// A reader's subscription to a publisher.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, Debug)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Subscription<T: Config> {
+        /// Plan it was bought under.
+        pub plan_id: PlanId,
+        /// Amount held from the reader; zero if paid outright.
+        pub deposit: BalanceOf<T>,
+        /// Block it was bought at.
+        pub started_at: BlockNumberFor<T>,
+        /// First block it no longer gives access at.
+        pub expires_at: BlockNumberFor<T>,
+    }
+
+    /// Statement a reader signs to prove to a paywall that they control a subscribed account.
+    #[derive(TypeInfo, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+    pub struct AccessAttestation<AccountId, BlockNumber, Hash> {
+        /// Genesis hash of the chain, so the attestation cannot be checked against another one.
+        pub genesis_hash: Hash,
+        /// Account holding the subscription.
+        pub reader: AccountId,
+        /// Anchor of the article the reader asks to read.
+        pub anchor: ContentHash,
+        /// Random value chosen by the paywall for this request.
+        pub challenge: H256,
+        /// Last block the attestation may be checked at.
+        pub valid_until: BlockNumber,
+    }
+
+    impl<AccountId: Encode, BlockNumber: Encode, Hash: Encode>
+        AccessAttestation<AccountId, BlockNumber, Hash>
+    {
+        /// Bytes the reader's signature covers: `<Bytes>` + the SCALE encoding of
+        /// `(b"news-access", attestation)` + `</Bytes>`, framed like the signing messages of
+        /// `pallet_news` so wallets sign it the same way, and tagged so it never doubles as one.
+        pub fn signing_message(&self) -> Vec<u8> {
+            let mut message = b"<Bytes>".to_vec();
+            message.extend((b"news-access", self).encode());
+            message.extend_from_slice(b"</Bytes>");
+            message
+        }
+    }
+
+    /// `AccessAttestation` of a runtime.
+    pub type AccessAttestationOf<T> = AccessAttestation<
+        <T as frame_system::Config>::AccountId,
+        BlockNumberFor<T>,
+        <T as frame_system::Config>::Hash
+    >;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overall runtime event type.
+        #[allow(deprecated)]
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
+        /// Currency subscriptions are paid or held in.
+        type Currency: Mutate<Self::AccountId> +
+            MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+        /// Anchors and their publishers, usually `pallet_news`.
+        type Articles: AnchorInspect<Self::AccountId>;
+        /// Signature readers sign access attestations with, e.g. `MultiSignature`.
+        type Signature: Verify<Signer = Self::Signer> + Encode + Decode;
+        /// Key type whose account `Signature` verifies against.
+        type Signer: IdentifyAccount<AccountId = Self::AccountId>;
+        /// Maximum number of plans a publisher can have on sale at once.
+        #[pallet::constant]
+        type MaxPlansPerPublisher: Get<u32>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// A reason for the pallet placing a hold on funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Price of a subscription paid as a deposit.
+        #[codec(index = 0)]
+        SubscriptionDeposit,
+    }
+
+    /// Plans on sale per publisher.
+    #[pallet::storage]
+    pub type Plans<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // publisher
+        Blake2_128Concat,
+        PlanId,
+        Plan<T>,
+        OptionQuery
+    >;
+
+    /// Id of the next plan a publisher creates.
+    #[pallet::storage]
+    pub type NextPlanId<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // publisher
+        PlanId,
+        ValueQuery
+    >;
+
+    /// Number of plans a publisher has on sale, at most `MaxPlansPerPublisher`.
+    #[pallet::storage]
+    pub type PlanCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // publisher
+        u32,
+        ValueQuery
+    >;
+
+    /// Subscriptions per publisher and reader. An expired subscription is kept, with its deposit
+    /// held, until the reader ends it or subscribes again.
+    #[pallet::storage]
+    pub type Subscribers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // publisher
+        Blake2_128Concat,
+        T::AccountId, // reader
+        Subscription<T>,
+        OptionQuery
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A publisher created a subscription plan.
+        PlanCreated {
+            publisher: T::AccountId,
+            plan_id: PlanId,
+            price: BalanceOf<T>,
+            period: BlockNumberFor<T>,
+            payment: PaymentKind,
+        },
+        /// A publisher stopped selling a plan.
+        PlanRetired {
+            publisher: T::AccountId,
+            plan_id: PlanId,
+        },
+        /// `reader` subscribed to `publisher` until `expires_at`.
+        Subscribed {
+            publisher: T::AccountId,
+            reader: T::AccountId,
+            plan_id: PlanId,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// `reader` ended their subscription to `publisher`; `released` was returned from hold.
+        Unsubscribed {
+            publisher: T::AccountId,
+            reader: T::AccountId,
+            released: BalanceOf<T>,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// A plan cannot last zero blocks.
+        ZeroPeriod,
+        /// The publisher already has `MaxPlansPerPublisher` plans on sale.
+        TooManyPlans,
+        /// The publisher sells no plan with this id.
+        UnknownPlan,
+        /// Publishers cannot subscribe to themselves.
+        OwnPlan,
+        /// The caller's subscription to this publisher has not expired yet.
+        AlreadySubscribed,
+        /// The caller has no subscription to this publisher.
+        NotSubscribed,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a plan selling `period` blocks of access to the caller's articles for `price`,
+        /// paid or held as given by `payment`.
+        ///
+        /// - At most `MaxPlansPerPublisher` plans can be on sale at once; retire one to make room.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_plan())]
+        pub fn create_plan(
+            origin: OriginFor<T>,
+            price: BalanceOf<T>,
+            period: BlockNumberFor<T>,
+            payment: PaymentKind
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            ensure!(!period.is_zero(), Error::<T>::ZeroPeriod);
+            PlanCount::<T>::try_mutate(&publisher, |count| {
+                ensure!(*count < T::MaxPlansPerPublisher::get(), Error::<T>::TooManyPlans);
+                *count += 1;
+                Ok::<_, Error<T>>(())
+            })?;
+
+            let plan_id = NextPlanId::<T>::get(&publisher);
+            Plans::<T>::insert(&publisher, plan_id, Plan { price, period, payment });
+            NextPlanId::<T>::insert(&publisher, plan_id.saturating_add(1));
+
+            Self::deposit_event(Event::PlanCreated { publisher, plan_id, price, period, payment });
+
+            Ok(())
+        }
+
+        /// Stop selling the caller's plan `plan_id`. Subscriptions bought under it run until
+        /// they expire.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::retire_plan())]
+        pub fn retire_plan(origin: OriginFor<T>, plan_id: PlanId) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            Plans::<T>::take(&publisher, plan_id).ok_or(Error::<T>::UnknownPlan)?;
+            PlanCount::<T>::mutate_exists(&publisher, |count| {
+                *count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+            });
+
+            Self::deposit_event(Event::PlanRetired { publisher, plan_id });
+
+            Ok(())
+        }
+
+        /// Subscribe to the articles of `publisher` under its plan `plan_id`, paying or holding
+        /// the plan's price.
+        ///
+        /// - An expired subscription of the caller is ended first, releasing its deposit; an
+        ///   unexpired one must run out first.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::subscribe())]
+        pub fn subscribe(
+            origin: OriginFor<T>,
+            publisher: T::AccountId,
+            plan_id: PlanId
+        ) -> DispatchResult {
+            let reader = ensure_signed(origin)?;
+            ensure!(reader != publisher, Error::<T>::OwnPlan);
+            let plan = Plans::<T>::get(&publisher, plan_id).ok_or(Error::<T>::UnknownPlan)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            if let Some(previous) = Subscribers::<T>::get(&publisher, &reader) {
+                ensure!(previous.expires_at <= now, Error::<T>::AlreadySubscribed);
+                Self::end_subscription(&publisher, &reader, previous)?;
+            }
+
+            let mut deposit = Zero::zero();
+            if !plan.price.is_zero() {
+                match plan.payment {
+                    PaymentKind::Outright => {
+                        T::Currency::transfer(&reader, &publisher, plan.price, Preservation::Preserve)?;
+                    }
+                    PaymentKind::Deposit => {
+                        T::Currency::hold(&HoldReason::SubscriptionDeposit.into(), &reader, plan.price)?;
+                        deposit = plan.price;
+                    }
+                }
+            }
+            let expires_at = now.saturating_add(plan.period);
+            Subscribers::<T>::insert(&publisher, &reader, Subscription {
+                plan_id,
+                deposit,
+                started_at: now,
+                expires_at,
+            });
+
+            Self::deposit_event(Event::Subscribed { publisher, reader, plan_id, expires_at });
+
+            Ok(())
+        }
+
+        /// End the caller's subscription to `publisher`, expired or not, releasing its deposit.
+        /// Outright payments are not refunded.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::unsubscribe())]
+        pub fn unsubscribe(origin: OriginFor<T>, publisher: T::AccountId) -> DispatchResult {
+            let reader = ensure_signed(origin)?;
+            let subscription = Subscribers::<T>
+                ::get(&publisher, &reader)
+                .ok_or(Error::<T>::NotSubscribed)?;
+
+            Self::end_subscription(&publisher, &reader, subscription)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// First block `reader` no longer has access to the lineage `anchor` at, if their
+        /// subscription to its publisher is active.
+        pub fn access_until(anchor: &ContentHash, reader: &T::AccountId) -> Option<BlockNumberFor<T>> {
+            let publisher = T::Articles::anchor_publisher(anchor)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            Subscribers::<T>
+                ::get(&publisher, reader)
+                .map(|subscription| subscription.expires_at)
+                .filter(|expires_at| now < *expires_at)
+        }
+
+        /// `access_until` of the reader and article of `attestation`, if it was signed by the
+        /// reader for this chain and is still valid.
+        pub fn verify_access(
+            attestation: &AccessAttestationOf<T>,
+            signature: &T::Signature
+        ) -> Option<BlockNumberFor<T>> {
+            let genesis_hash = <frame_system::Pallet<T>>::block_hash(BlockNumberFor::<T>::zero());
+            let now = <frame_system::Pallet<T>>::block_number();
+            if
+                attestation.genesis_hash != genesis_hash ||
+                now > attestation.valid_until ||
+                !signature.verify(&attestation.signing_message()[..], &attestation.reader)
+            {
+                return None;
+            }
+            Self::access_until(&attestation.anchor, &attestation.reader)
+        }
+
+        /// Releases the deposit of `subscription` and removes it.
+        fn end_subscription(
+            publisher: &T::AccountId,
+            reader: &T::AccountId,
+            subscription: Subscription<T>
+        ) -> DispatchResult {
+            let released = if subscription.deposit.is_zero() {
+                Zero::zero()
+            } else {
+                T::Currency::release(
+                    &HoldReason::SubscriptionDeposit.into(),
+                    reader,
+                    subscription.deposit,
+                    Precision::BestEffort
+                )?
+            };
+            Subscribers::<T>::remove(publisher, reader);
+
+            Self::deposit_event(Event::Unsubscribed {
+                publisher: publisher.clone(),
+                reader: reader.clone(),
+                released,
+            });
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use frame::{
+	deps::{frame_support::weights::constants::RocksDbWeight, frame_system::GenesisConfig},
+	prelude::*,
+	runtime::prelude::*,
+	testing_prelude::*,
+};
+use frame::deps::sp_runtime::testing::{ TestSignature, UintAuthorityId };
+use pallet_news::{ AnchorInspect, ContentHash };
+use polkadot_sdk::pallet_balances;
+
+// Configure a mock runtime to test the pallet.
+#[frame_construct_runtime]
+mod test_runtime {
+	#[runtime::runtime]
+	#[runtime::derive(
+		RuntimeCall,
+		RuntimeEvent,
+		RuntimeError,
+		RuntimeOrigin,
+		RuntimeFreezeReason,
+		RuntimeHoldReason,
+		RuntimeSlashReason,
+		RuntimeLockId,
+		RuntimeTask,
+		RuntimeViewFunction
+	)]
+	pub struct Test;
+
+	#[runtime::pallet_index(0)]
+	pub type System = frame_system;
+	#[runtime::pallet_index(1)]
+	pub type Subscriptions = crate;
+	#[runtime::pallet_index(2)]
+	pub type Balances = pallet_balances;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Nonce = u64;
+	type Block = MockBlock<Test>;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = RocksDbWeight;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+parameter_types! {
+	/// Anchors recorded in the mock, with their publishers.
+	pub static Anchors: Vec<(ContentHash, u64)> = Vec::new();
+}
+
+/// Stands in for `pallet_news`, serving the anchors in `Anchors`.
+pub struct MockArticles;
+impl AnchorInspect<u64> for MockArticles {
+	fn anchor_publisher(anchor: &ContentHash) -> Option<u64> {
+		Anchors::get()
+			.into_iter()
+			.find(|(other, _)| other == anchor)
+			.map(|(_, publisher)| publisher)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn insert_anchor(anchor: ContentHash, publisher: u64) {
+		Anchors::mutate(|anchors| anchors.push((anchor, publisher)));
+	}
+}
+
+impl crate::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type Articles = MockArticles;
+	type Signature = TestSignature;
+	type Signer = UintAuthorityId;
+	type MaxPlansPerPublisher = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> TestState {
+	Anchors::take();
+	let mut ext: TestState = GenesisConfig::<Test>::default().build_storage().unwrap().into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,28 @@
+//! Runtime API for paywalls checking reader subscriptions.
+
+use crate::AccessAttestation;
+use codec::Codec;
+use pallet_news::ContentHash;
+
+frame::deps::sp_api::decl_runtime_apis! {
+    /// Read-only queries over `pallet_subscriptions` state.
+    pub trait SubscriptionsApi<AccountId, BlockNumber, Hash, Signature>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+        Signature: Codec,
+    {
+        /// First block `reader` no longer has access to the lineage `anchor` at, if their
+        /// subscription to its publisher is active.
+        fn access_until(anchor: ContentHash, reader: AccountId) -> Option<BlockNumber>;
+        /// `access_until` of the reader and article of `attestation`, if `signature` is the
+        /// reader's over `AccessAttestation::signing_message`, i.e. `<Bytes>` + the SCALE
+        /// encoding of `(b"news-access", attestation)` + `</Bytes>`, the attestation names this
+        /// chain and `valid_until` has not passed.
+        fn verify_access(
+            attestation: AccessAttestation<AccountId, BlockNumber, Hash>,
+            signature: Signature,
+        ) -> Option<BlockNumber>;
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{ mock::*, AccessAttestationOf, Error, HoldReason, NextPlanId, PaymentKind, PlanCount, Plans, Subscribers };
+use frame::testing_prelude::*;
+use frame::deps::sp_core::H256;
+use frame::deps::sp_runtime::testing::TestSignature;
+use frame::traits::fungible::{ Inspect, InspectHold, Mutate };
+
+const PUBLISHER: u64 = 1;
+const READER: u64 = 2;
+
+fn anchor() -> H256 {
+    H256::repeat_byte(42)
+}
+
+/// Records `anchor()` as published by `PUBLISHER`, funds the reader and creates plan 0 (paid
+/// outright) and plan 1 (held as a deposit), both 100 for 10 blocks.
+fn setup() {
+    Anchors::set(vec![(anchor(), PUBLISHER)]);
+    <Balances as Mutate<u64>>::set_balance(&READER, 1_000);
+    assert_ok!(Subscriptions::create_plan(RuntimeOrigin::signed(PUBLISHER), 100, 10, PaymentKind::Outright));
+    assert_ok!(Subscriptions::create_plan(RuntimeOrigin::signed(PUBLISHER), 100, 10, PaymentKind::Deposit));
+}
+
+fn held(who: u64) -> u64 {
+    Balances::balance_on_hold(&HoldReason::SubscriptionDeposit.into(), &who)
+}
+
+fn attestation(reader: u64, valid_until: u64) -> AccessAttestationOf<Test> {
+    AccessAttestationOf::<Test> {
+        genesis_hash: System::block_hash(0),
+        reader,
+        anchor: anchor(),
+        challenge: H256::repeat_byte(7),
+        valid_until,
+    }
+}
+
+fn sign(signer: u64, attestation: &AccessAttestationOf<Test>) -> TestSignature {
+    TestSignature(signer, attestation.signing_message())
+}
+
+#[test]
+fn plans_are_created_and_retired_by_their_publisher() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Subscriptions::create_plan(RuntimeOrigin::signed(PUBLISHER), 100, 0, PaymentKind::Outright),
+            Error::<Test>::ZeroPeriod
+        );
+        assert_ok!(Subscriptions::create_plan(RuntimeOrigin::signed(PUBLISHER), 100, 10, PaymentKind::Deposit));
+        System::assert_last_event(
+            crate::Event::PlanCreated {
+                publisher: PUBLISHER,
+                plan_id: 0,
+                price: 100,
+                period: 10,
+                payment: PaymentKind::Deposit,
+            }.into()
+        );
+        assert_ok!(Subscriptions::create_plan(RuntimeOrigin::signed(PUBLISHER), 0, 10, PaymentKind::Outright));
+        assert_eq!(NextPlanId::<Test>::get(PUBLISHER), 2);
+
+        // Plans belong to their publisher
+        assert_noop!(Subscriptions::retire_plan(RuntimeOrigin::signed(READER), 0), Error::<Test>::UnknownPlan);
+        assert_ok!(Subscriptions::retire_plan(RuntimeOrigin::signed(PUBLISHER), 0));
+        System::assert_last_event(crate::Event::PlanRetired { publisher: PUBLISHER, plan_id: 0 }.into());
+        assert!(!Plans::<Test>::contains_key(PUBLISHER, 0));
+        assert_noop!(
+            Subscriptions::subscribe(RuntimeOrigin::signed(READER), PUBLISHER, 0),
+            Error::<Test>::UnknownPlan
+        );
+    });
+}
+
+#[test]
+fn plans_on_sale_are_bounded_per_publisher() {
+    new_test_ext().execute_with(|| {
+        let create = |publisher: u64| {
+            Subscriptions::create_plan(RuntimeOrigin::signed(publisher), 0, 10, PaymentKind::Outright)
+        };
+
+        // `MaxPlansPerPublisher` is 3
+        for _ in 0..3 {
+            assert_ok!(create(PUBLISHER));
+        }
+        assert_noop!(create(PUBLISHER), Error::<Test>::TooManyPlans);
+        assert_ok!(create(READER));
+
+        // Retiring a plan makes room
+        assert_ok!(Subscriptions::retire_plan(RuntimeOrigin::signed(PUBLISHER), 1));
+        assert_eq!(PlanCount::<Test>::get(PUBLISHER), 2);
+        assert_ok!(create(PUBLISHER));
+        assert_eq!(NextPlanId::<Test>::get(PUBLISHER), 4);
+
+        for plan_id in [0, 2, 3] {
+            assert_ok!(Subscriptions::retire_plan(RuntimeOrigin::signed(PUBLISHER), plan_id));
+        }
+        assert!(!PlanCount::<Test>::contains_key(PUBLISHER));
+    });
+}
+
+#[test]
+fn outright_subscriptions_pay_the_publisher_and_expire() {
+    new_test_ext().execute_with(|| {
+        setup();
+        assert_noop!(
+            Subscriptions::subscribe(RuntimeOrigin::signed(PUBLISHER), PUBLISHER, 0),
+            Error::<Test>::OwnPlan
+        );
+
+        assert_ok!(Subscriptions::subscribe(RuntimeOrigin::signed(READER), PUBLISHER, 0));
+        System::assert_last_event(
+            crate::Event::Subscribed { publisher: PUBLISHER, reader: READER, plan_id: 0, expires_at: 11 }.into()
+        );
+        assert_eq!(Balances::balance(&READER), 900);
+        assert_eq!(Balances::balance(&PUBLISHER), 100);
+        assert_eq!(Subscriptions::access_until(&anchor(), &READER), Some(11));
+        assert_noop!(
+            Subscriptions::subscribe(RuntimeOrigin::signed(READER), PUBLISHER, 1),
+            Error::<Test>::AlreadySubscribed
+        );
+
+        // Access ends at `expires_at`, and the retired plan does not shorten it
+        assert_ok!(Subscriptions::retire_plan(RuntimeOrigin::signed(PUBLISHER), 0));
+        System::set_block_number(10);
+        assert_eq!(Subscriptions::access_until(&anchor(), &READER), Some(11));
+        System::set_block_number(11);
+        assert_eq!(Subscriptions::access_until(&anchor(), &READER), None);
+        assert_eq!(Subscriptions::access_until(&H256::repeat_byte(9), &READER), None);
+
+        // An expired subscription is replaced
+        assert_ok!(Subscriptions::subscribe(RuntimeOrigin::signed(READER), PUBLISHER, 1));
+        assert_eq!(Subscribers::<Test>::get(PUBLISHER, READER).unwrap().expires_at, 21);
+    });
+}
+
+#[test]
+fn deposit_subscriptions_hold_until_ended() {
+    new_test_ext().execute_with(|| {
+        setup();
+        assert_noop!(
+            Subscriptions::unsubscribe(RuntimeOrigin::signed(READER), PUBLISHER),
+            Error::<Test>::NotSubscribed
+        );
+
+        assert_ok!(Subscriptions::subscribe(RuntimeOrigin::signed(READER), PUBLISHER, 1));
+        assert_eq!(held(READER), 100);
+        assert_eq!(Balances::balance(&PUBLISHER), 0);
+        assert_eq!(Subscribers::<Test>::get(PUBLISHER, READER).unwrap().deposit, 100);
+
+        // Renewing after expiry releases the previous deposit before holding the new one
+        System::set_block_number(11);
+        assert_ok!(Subscriptions::subscribe(RuntimeOrigin::signed(READER), PUBLISHER, 1));
+        System::assert_has_event(
+            crate::Event::Unsubscribed { publisher: PUBLISHER, reader: READER, released: 100 }.into()
+        );
+        assert_eq!(held(READER), 100);
+
+        // Ending early gives up access and returns the deposit
+        assert_ok!(Subscriptions::unsubscribe(RuntimeOrigin::signed(READER), PUBLISHER));
+        assert_eq!(held(READER), 0);
+        assert_eq!(Balances::balance(&READER), 1_000);
+        assert_eq!(Subscriptions::access_until(&anchor(), &READER), None);
+
+        // Readers who cannot cover the deposit cannot subscribe
+        assert!(Subscriptions::subscribe(RuntimeOrigin::signed(3), PUBLISHER, 1).is_err());
+        assert!(!Subscribers::<Test>::contains_key(PUBLISHER, 3));
+    });
+}
+
+#[test]
+fn access_attestations_are_verified_against_subscriptions() {
+    new_test_ext().execute_with(|| {
+        setup();
+        let attestation = attestation(READER, 5);
+        assert_eq!(Subscriptions::verify_access(&attestation, &sign(READER, &attestation)), None);
+
+        assert_ok!(Subscriptions::subscribe(RuntimeOrigin::signed(READER), PUBLISHER, 0));
+        assert_eq!(Subscriptions::verify_access(&attestation, &sign(READER, &attestation)), Some(11));
+
+        // Raw SCALE encoding, without the framing and domain tag
+        let raw = TestSignature(READER, attestation.encode());
+        assert_eq!(Subscriptions::verify_access(&attestation, &raw), None);
+        // Signed by someone else
+        assert_eq!(Subscriptions::verify_access(&attestation, &sign(3, &attestation)), None);
+        // Signature over another attestation
+        let other = AccessAttestationOf::<Test> { challenge: H256::repeat_byte(8), ..attestation.clone() };
+        assert_eq!(Subscriptions::verify_access(&attestation, &sign(READER, &other)), None);
+        // For another chain
+        let foreign = AccessAttestationOf::<Test> { genesis_hash: H256::repeat_byte(1), ..attestation.clone() };
+        assert_eq!(Subscriptions::verify_access(&foreign, &sign(READER, &foreign)), None);
+
+        // Checked at `valid_until` at the latest
+        System::set_block_number(5);
+        assert_eq!(Subscriptions::verify_access(&attestation, &sign(READER, &attestation)), Some(11));
+        System::set_block_number(6);
+        assert_eq!(Subscriptions::verify_access(&attestation, &sign(READER, &attestation)), None);
+    });
+}
@@ -0,0 +1,165 @@
+//! Placeholder weights for `pallet_subscriptions`
+//!
+//! NOT BENCHMARKED: these are hand-written estimates laid out like benchmark CLI output, so that
+//! the pallet can be wired into a runtime. Regenerate this file before production use.
+
+// Command to generate this file:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --runtime
+// ./target/release/wbuild/educhain-runtime/educhain_runtime.compact.compressed.wasm
+// --pallet
+// pallet_subscriptions
+// --extrinsic
+//
+// --template
+// ../frame-weight-template.hbs
+// --output
+// weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame::{ deps::frame_support::weights::constants::RocksDbWeight, prelude::* };
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_subscriptions`.
+pub trait WeightInfo {
+    fn create_plan() -> Weight;
+    fn retire_plan() -> Weight;
+    fn subscribe() -> Weight;
+    fn unsubscribe() -> Weight;
+}
+
+/// Weights for `pallet_subscriptions` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `Subscriptions::PlanCount` (r:1 w:1)
+    /// Proof: `Subscriptions::PlanCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Subscriptions::NextPlanId` (r:1 w:1)
+    /// Proof: `Subscriptions::NextPlanId` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Subscriptions::Plans` (r:0 w:1)
+    /// Proof: `Subscriptions::Plans` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+    fn create_plan() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `42`
+        //  Estimated: `3517`
+        // Minimum execution time: 16_000_000 picoseconds.
+        Weight::from_parts(17_000_000, 3517)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Subscriptions::Plans` (r:1 w:1)
+    /// Proof: `Subscriptions::Plans` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+    /// Storage: `Subscriptions::PlanCount` (r:1 w:1)
+    /// Proof: `Subscriptions::PlanCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn retire_plan() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `218`
+        //  Estimated: `3554`
+        // Minimum execution time: 17_000_000 picoseconds.
+        Weight::from_parts(18_000_000, 3554)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Subscriptions::Plans` (r:1 w:0)
+    /// Proof: `Subscriptions::Plans` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+    /// Storage: `Subscriptions::Subscribers` (r:1 w:1)
+    /// Proof: `Subscriptions::Subscribers` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn subscribe() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `512`
+        //  Estimated: `3593`
+        // Minimum execution time: 52_000_000 picoseconds.
+        Weight::from_parts(54_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Subscriptions::Subscribers` (r:1 w:1)
+    /// Proof: `Subscriptions::Subscribers` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn unsubscribe() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `401`
+        //  Estimated: `3593`
+        // Minimum execution time: 36_000_000 picoseconds.
+        Weight::from_parts(37_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `Subscriptions::PlanCount` (r:1 w:1)
+    /// Proof: `Subscriptions::PlanCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Subscriptions::NextPlanId` (r:1 w:1)
+    /// Proof: `Subscriptions::NextPlanId` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Subscriptions::Plans` (r:0 w:1)
+    /// Proof: `Subscriptions::Plans` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+    fn create_plan() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `42`
+        //  Estimated: `3517`
+        // Minimum execution time: 16_000_000 picoseconds.
+        Weight::from_parts(17_000_000, 3517)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Subscriptions::Plans` (r:1 w:1)
+    /// Proof: `Subscriptions::Plans` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+    /// Storage: `Subscriptions::PlanCount` (r:1 w:1)
+    /// Proof: `Subscriptions::PlanCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn retire_plan() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `218`
+        //  Estimated: `3554`
+        // Minimum execution time: 17_000_000 picoseconds.
+        Weight::from_parts(18_000_000, 3554)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Subscriptions::Plans` (r:1 w:0)
+    /// Proof: `Subscriptions::Plans` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+    /// Storage: `Subscriptions::Subscribers` (r:1 w:1)
+    /// Proof: `Subscriptions::Subscribers` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn subscribe() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `512`
+        //  Estimated: `3593`
+        // Minimum execution time: 52_000_000 picoseconds.
+        Weight::from_parts(54_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `Subscriptions::Subscribers` (r:1 w:1)
+    /// Proof: `Subscriptions::Subscribers` (`max_values`: None, `max_size`: Some(124), added: 2599, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Holds` (r:1 w:1)
+    /// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn unsubscribe() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `401`
+        //  Estimated: `3593`
+        // Minimum execution time: 36_000_000 picoseconds.
+        Weight::from_parts(37_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+}
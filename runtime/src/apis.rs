@@ -45,9 +45,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-	Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-	SLOT_DURATION, VERSION,
+	AccountId, Balance, Block, BlockNumber, ConsensusHook, Executive, Hash, InherentDataExt,
+	Licensing, News, Nonce, ParachainSystem, Runtime, RuntimeCall, RuntimeGenesisConfig,
+	SessionKeys, Signature, Subscriptions, System, TransactionPayment, SLOT_DURATION, VERSION,
 };
 
 // we move some impls outside so we can easily use them with `docify`.
@@ -219,6 +219,119 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_news::runtime_api::NewsApi<Block, AccountId, BlockNumber> for Runtime {
+		fn publisher_reputation(publisher: AccountId) -> i64 {
+			News::reputation_of(&publisher)
+		}
+
+		fn publisher_summary(publisher: AccountId) -> pallet_news::runtime_api::PublisherSummary {
+			News::publisher_summary(&publisher)
+		}
+
+		fn endorsement_tally(content_hash: pallet_news::ContentHash) -> pallet_news::EndorsementTally {
+			pallet_news::EndorsementTallies::<Runtime>::get(content_hash)
+		}
+
+		fn syndication_sources(anchor: pallet_news::ContentHash, max_depth: u32) -> Vec<pallet_news::ContentHash> {
+			News::syndication_sources(anchor, max_depth)
+		}
+
+		fn syndication_reach(anchor: pallet_news::ContentHash, max_results: u32) -> Vec<pallet_news::ContentHash> {
+			News::syndication_reach(anchor, max_results)
+		}
+
+		fn citations_of(content_hash: pallet_news::ContentHash) -> Vec<pallet_news::ContentHash> {
+			pallet_news::CitationsOf::<Runtime>::get(content_hash)
+				.map(|citations| citations.into_inner())
+				.unwrap_or_default()
+		}
+
+		fn citation_graph(
+			root: pallet_news::ContentHash,
+			depth: u32,
+			outgoing: bool,
+			max_edges: u32,
+		) -> Vec<(pallet_news::ContentHash, pallet_news::ContentHash)> {
+			News::citation_graph(root, depth, outgoing, max_edges)
+		}
+
+		fn priority_proofs(anchor: pallet_news::ContentHash) -> Vec<(pallet_news::ContentHash, BlockNumber)> {
+			News::priority_proofs(anchor)
+				.into_iter()
+				.map(|(version, proof)| (version, proof.committed_at))
+				.collect()
+		}
+
+		fn integrity_status(
+			content_hash: pallet_news::ContentHash,
+		) -> pallet_news::IntegrityStatus<BlockNumber> {
+			pallet_news::IntegrityStatusOf::<Runtime>::get(content_hash)
+		}
+
+		fn publisher_nonce(publisher: AccountId) -> u64 {
+			pallet_news::PublisherNonces::<Runtime>::get(publisher)
+		}
+
+		fn signature_status(content_hash: pallet_news::ContentHash) -> Option<pallet_news::SignatureStatus> {
+			News::signature_status(&content_hash)
+		}
+
+		fn verify_inclusion(
+			merkle_root: sp_core::H256,
+			leaf: pallet_news::BatchLeaf<AccountId>,
+			proof: Vec<sp_core::H256>,
+		) -> bool {
+			News::verify_inclusion(merkle_root, &leaf, &proof)
+		}
+
+		fn history_page(anchor: pallet_news::ContentHash, page: u32) -> Vec<pallet_news::ContentHash> {
+			News::history_page(anchor, page)
+		}
+
+		fn substantive_corrections(anchor: pallet_news::ContentHash) -> Vec<pallet_news::ContentHash> {
+			News::substantive_corrections(anchor)
+		}
+	}
+
+	impl pallet_news::runtime_api::TipsApi<Block, AccountId, Balance> for Runtime {
+		fn article_tips(content_hash: pallet_news::ContentHash) -> pallet_news::TipTally<Balance> {
+			News::article_tips(content_hash)
+		}
+
+		fn publisher_tips(publisher: AccountId) -> pallet_news::TipTally<Balance> {
+			pallet_news::TipsByPublisher::<Runtime>::get(publisher)
+		}
+	}
+
+	impl pallet_licensing::runtime_api::LicensingApi<Block, AccountId> for Runtime {
+		fn has_license(anchor: pallet_news::ContentHash, account: AccountId) -> bool {
+			Licensing::has_license(&anchor, &account)
+		}
+
+		fn has_license_in(
+			anchor: pallet_news::ContentHash,
+			account: AccountId,
+			territory: pallet_licensing::Territory,
+		) -> bool {
+			Licensing::has_license_in(&anchor, &account, &territory)
+		}
+	}
+
+	impl pallet_subscriptions::runtime_api::SubscriptionsApi<Block, AccountId, BlockNumber, Hash, Signature>
+		for Runtime
+	{
+		fn access_until(anchor: pallet_news::ContentHash, reader: AccountId) -> Option<BlockNumber> {
+			Subscriptions::access_until(&anchor, &reader)
+		}
+
+		fn verify_access(
+			attestation: pallet_subscriptions::AccessAttestation<AccountId, BlockNumber, Hash>,
+			signature: Signature,
+		) -> Option<BlockNumber> {
+			Subscriptions::verify_access(&attestation, &signature)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
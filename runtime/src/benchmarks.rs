@@ -26,9 +26,14 @@
 polkadot_sdk::frame_benchmarking::define_benchmarks!(
 	[frame_system, SystemBench::<Runtime>]
 	[pallet_balances, Balances]
+	[pallet_assets, ForeignAssets]
+	[pallet_asset_rate, AssetRate]
+	[pallet_asset_conversion_tx_payment, AssetTxPayment]
 	[pallet_session, SessionBench::<Runtime>]
 	[pallet_timestamp, Timestamp]
 	[pallet_news, News]
+	[pallet_licensing, Licensing]
+	[pallet_subscriptions, Subscriptions]
 	[pallet_message_queue, MessageQueue]
 	[pallet_sudo, Sudo]
 	[pallet_collator_selection, CollatorSelection]
@@ -0,0 +1,273 @@
+//! Transaction fee payment in foreign assets, at the rate governance set in `AssetRate`.
+
+use super::ForeignAssetFeeRate;
+use crate::{AccountId, Authorship, Balance, ForeignAssets, Runtime, RuntimeCall};
+
+use polkadot_sdk::{staging_xcm as xcm, *};
+
+use frame_support::traits::{
+	fungibles::{Balanced, Credit, Inspect},
+	tokens::{ConversionToAssetBalance, Fortitude, Precision, Preservation},
+	Imbalance,
+};
+use pallet_asset_conversion_tx_payment::OnChargeAssetTransaction;
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+use xcm::latest::Location;
+
+/// Charges fees in a foreign asset held in `ForeignAssets`. The fee is converted with
+/// [`ForeignAssetFeeRate`] rather than swapped, and goes to the block author; assets without a
+/// rate cannot pay fees. Fees paid in the native token still go through `TransactionPayment`'s
+/// `OnChargeTransaction`, so newsroom sponsorship is unaffected.
+pub struct ForeignAssetFeeAdapter;
+
+impl OnChargeAssetTransaction<Runtime> for ForeignAssetFeeAdapter {
+	type Balance = Balance;
+	type AssetId = Location;
+	/// Fee withdrawn in the asset.
+	type LiquidityInfo = Credit<AccountId, ForeignAssets>;
+
+	fn withdraw_fee(
+		who: &AccountId,
+		_call: &RuntimeCall,
+		_dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		asset_id: Location,
+		fee: Balance,
+		_tip: Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let asset_fee = ForeignAssetFeeRate::to_asset_balance(fee, asset_id.clone())
+			.map_err(|_| InvalidTransaction::Payment)?;
+		<ForeignAssets as Balanced<AccountId>>::withdraw(
+			asset_id,
+			who,
+			asset_fee,
+			Precision::Exact,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		)
+		.map_err(|_| InvalidTransaction::Payment.into())
+	}
+
+	fn can_withdraw_fee(
+		who: &AccountId,
+		asset_id: Location,
+		fee: Balance,
+	) -> Result<(), TransactionValidityError> {
+		let asset_fee = ForeignAssetFeeRate::to_asset_balance(fee, asset_id.clone())
+			.map_err(|_| InvalidTransaction::Payment)?;
+		<ForeignAssets as Inspect<AccountId>>::can_withdraw(asset_id, who, asset_fee)
+			.into_result(true)
+			.map(|_| ())
+			.map_err(|_| InvalidTransaction::Payment.into())
+	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountId,
+		_dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		_post_info: &PostDispatchInfoOf<RuntimeCall>,
+		corrected_fee: Balance,
+		_tip: Balance,
+		asset_id: Location,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<Balance, TransactionValidityError> {
+		// Should the rate have been removed since the fee was withdrawn, nothing is refunded.
+		let paid = already_withdrawn.peek();
+		let asset_fee = ForeignAssetFeeRate::to_asset_balance(corrected_fee, asset_id)
+			.map_or(paid, |asset_fee| asset_fee.min(paid));
+		let (fee, refund) = already_withdrawn.split(asset_fee);
+		// A refund below the asset's minimum balance cannot be deposited and is burnt.
+		let _ = <ForeignAssets as Balanced<AccountId>>::resolve(who, refund);
+		if let Some(author) = Authorship::author() {
+			let _ = <ForeignAssets as Balanced<AccountId>>::resolve(&author, fee);
+		}
+		Ok(asset_fee)
+	}
+}
+
+/// Registers a foreign asset at a rate of one to the native token and funds the payer with it.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetTxPaymentBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_asset_conversion_tx_payment::BenchmarkHelperTrait<AccountId, Location, Location>
+	for AssetTxPaymentBenchmarkHelper
+{
+	fn create_asset_id_parameter(id: u32) -> (Location, Location) {
+		let asset_id = Location::new(1, [xcm::latest::prelude::Parachain(id)]);
+		(asset_id.clone(), asset_id)
+	}
+
+	fn setup_balances_and_pool(asset_id: Location, account: AccountId) {
+		use frame_support::traits::fungibles::{Create, Mutate};
+		use sp_runtime::{FixedPointNumber, FixedU128};
+
+		let owner = account.clone();
+		assert!(<ForeignAssets as Create<AccountId>>::create(
+			asset_id.clone(),
+			owner,
+			true,
+			1
+		)
+		.is_ok());
+		assert!(<ForeignAssets as Mutate<AccountId>>::mint_into(
+			asset_id.clone(),
+			&account,
+			crate::UNIT * 1_000
+		)
+		.is_ok());
+		pallet_asset_rate::ConversionRateToNative::<Runtime>::insert(
+			asset_id,
+			FixedU128::saturating_from_integer(1),
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::{
+		assert_ok,
+		traits::fungibles::{Create, Mutate},
+	};
+	use sp_runtime::{BuildStorage, FixedPointNumber, FixedU128};
+	use xcm::latest::prelude::{GeneralIndex, PalletInstance, Parachain};
+
+	const MIN_BALANCE: Balance = 10;
+
+	fn usdt() -> Location {
+		Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)])
+	}
+
+	fn payer() -> AccountId {
+		AccountId::from([1u8; 32])
+	}
+
+	fn call() -> RuntimeCall {
+		RuntimeCall::System(frame_system::Call::remark { remark: Default::default() })
+	}
+
+	/// Registers `usdt()` at `rate` native tokens per unit and gives the payer 1_000 of it.
+	fn new_test_ext(rate: FixedU128) -> sp_io::TestExternalities {
+		let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| {
+			assert_ok!(<ForeignAssets as Create<AccountId>>::create(
+				usdt(),
+				payer(),
+				true,
+				MIN_BALANCE
+			));
+			assert_ok!(<ForeignAssets as Mutate<AccountId>>::mint_into(usdt(), &payer(), 1_000));
+			pallet_asset_rate::ConversionRateToNative::<Runtime>::insert(usdt(), rate);
+		});
+		ext
+	}
+
+	fn withdraw(
+		fee: Balance,
+	) -> Result<Credit<AccountId, ForeignAssets>, TransactionValidityError> {
+		ForeignAssetFeeAdapter::withdraw_fee(&payer(), &call(), &Default::default(), usdt(), fee, 0)
+	}
+
+	fn correct(corrected_fee: Balance, withdrawn: Credit<AccountId, ForeignAssets>) -> Balance {
+		ForeignAssetFeeAdapter::correct_and_deposit_fee(
+			&payer(),
+			&Default::default(),
+			&Default::default(),
+			corrected_fee,
+			0,
+			usdt(),
+			withdrawn,
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn fee_rate_divides_by_the_rate_and_rounds_up() {
+		new_test_ext(FixedU128::saturating_from_integer(2)).execute_with(|| {
+			assert_eq!(ForeignAssetFeeRate::to_asset_balance(0, usdt()), Ok(0));
+			assert_eq!(ForeignAssetFeeRate::to_asset_balance(10, usdt()), Ok(5));
+			assert_eq!(ForeignAssetFeeRate::to_asset_balance(11, usdt()), Ok(6));
+			assert_eq!(ForeignAssetFeeRate::to_asset_balance(1, usdt()), Ok(1));
+
+			pallet_asset_rate::ConversionRateToNative::<Runtime>::insert(
+				usdt(),
+				FixedU128::from_rational(1, 4),
+			);
+			assert_eq!(ForeignAssetFeeRate::to_asset_balance(7, usdt()), Ok(28));
+			assert_eq!(
+				ForeignAssetFeeRate::to_asset_balance(Balance::MAX, usdt()),
+				Err(pallet_asset_rate::Error::<Runtime>::Overflow)
+			);
+
+			let unrated = Location::new(1, [Parachain(2000)]);
+			assert_eq!(
+				ForeignAssetFeeRate::to_asset_balance(10, unrated),
+				Err(pallet_asset_rate::Error::<Runtime>::UnknownAssetKind)
+			);
+		});
+	}
+
+	#[test]
+	fn fees_are_withdrawn_in_the_asset_at_its_rate() {
+		new_test_ext(FixedU128::saturating_from_integer(2)).execute_with(|| {
+			assert_ok!(ForeignAssetFeeAdapter::can_withdraw_fee(&payer(), usdt(), 100));
+			let withdrawn = withdraw(100).unwrap();
+			assert_eq!(withdrawn.peek(), 50);
+			assert_eq!(ForeignAssets::balance(usdt(), payer()), 950);
+
+			// The payer's asset account is kept alive
+			assert!(ForeignAssetFeeAdapter::can_withdraw_fee(&payer(), usdt(), 1_900).is_err());
+			assert!(withdraw(1_900).is_err());
+
+			// Assets without a rate cannot pay
+			pallet_asset_rate::ConversionRateToNative::<Runtime>::remove(usdt());
+			assert_eq!(
+				ForeignAssetFeeAdapter::can_withdraw_fee(&payer(), usdt(), 100),
+				Err(InvalidTransaction::Payment.into())
+			);
+			assert_eq!(withdraw(100).err(), Some(InvalidTransaction::Payment.into()));
+			drop(withdrawn);
+		});
+	}
+
+	#[test]
+	fn overpaid_fees_are_refunded_in_the_asset() {
+		new_test_ext(FixedU128::saturating_from_integer(2)).execute_with(|| {
+			let withdrawn = withdraw(100).unwrap();
+
+			assert_eq!(correct(40, withdrawn), 20);
+			assert_eq!(ForeignAssets::balance(usdt(), payer()), 980);
+			// Without a block author, the fee is burnt
+			assert_eq!(ForeignAssets::total_issuance(usdt()), 980);
+
+			// Should the rate be removed in between, nothing is refunded
+			let withdrawn = withdraw(100).unwrap();
+			pallet_asset_rate::ConversionRateToNative::<Runtime>::remove(usdt());
+			assert_eq!(correct(40, withdrawn), 50);
+			assert_eq!(ForeignAssets::balance(usdt(), payer()), 930);
+		});
+	}
+
+	#[test]
+	fn refunds_below_the_minimum_balance_are_burnt() {
+		new_test_ext(FixedU128::saturating_from_integer(1)).execute_with(|| {
+			let withdrawn = withdraw(100).unwrap();
+			// The payer's asset account is emptied during dispatch
+			assert_ok!(<ForeignAssets as Mutate<AccountId>>::burn_from(
+				usdt(),
+				&payer(),
+				900,
+				Preservation::Expendable,
+				Precision::Exact,
+				Fortitude::Polite,
+			));
+			assert_eq!(ForeignAssets::maybe_balance(usdt(), payer()), None);
+
+			assert_eq!(correct(100 - (MIN_BALANCE - 1), withdrawn), 100 - (MIN_BALANCE - 1));
+			assert_eq!(ForeignAssets::maybe_balance(usdt(), payer()), None);
+			assert_eq!(ForeignAssets::total_issuance(usdt()), 0);
+		});
+	}
+}
@@ -0,0 +1,255 @@
+//! Transaction fee payment that lets newsrooms pay for their journalists' `News` calls.
+
+use crate::{AccountId, Balance, News, Runtime, RuntimeCall};
+
+use polkadot_sdk::*;
+
+use core::marker::PhantomData;
+use pallet_transaction_payment::{FungibleAdapter, OnChargeTransaction};
+use sp_runtime::{
+	traits::{DispatchInfoOf, PostDispatchInfoOf},
+	transaction_validity::TransactionValidityError,
+};
+
+/// [`FungibleAdapter`] that charges the fee of a sponsored journalist's `News` call to the
+/// sponsoring newsroom, as decided by `pallet_news::Pallet::sponsor_for`. Every other fee is
+/// paid by the signer.
+pub struct SponsoredFungibleAdapter<F, OU>(PhantomData<(F, OU)>);
+
+impl<F, OU> OnChargeTransaction<Runtime> for SponsoredFungibleAdapter<F, OU>
+where
+	FungibleAdapter<F, OU>: OnChargeTransaction<Runtime, Balance = Balance>,
+{
+	type Balance = Balance;
+	/// Sponsor and fee booked against its sponsorship, if sponsored, and the liquidity info of
+	/// the wrapped adapter.
+	type LiquidityInfo = (
+		Option<(AccountId, Balance)>,
+		<FungibleAdapter<F, OU> as OnChargeTransaction<Runtime>>::LiquidityInfo,
+	);
+
+	fn withdraw_fee(
+		who: &AccountId,
+		call: &RuntimeCall,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		fee: Balance,
+		tip: Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		match sponsor_of(who, call, fee) {
+			Some(sponsor) => {
+				let liquidity = FungibleAdapter::<F, OU>::withdraw_fee(
+					&sponsor,
+					call,
+					dispatch_info,
+					fee,
+					tip,
+				)?;
+				// Only book the fee once it was actually withdrawn from the sponsor.
+				News::charge_sponsorship(who, &sponsor, fee);
+				Ok((Some((sponsor, fee)), liquidity))
+			},
+			None => Ok((
+				None,
+				FungibleAdapter::<F, OU>::withdraw_fee(who, call, dispatch_info, fee, tip)?,
+			)),
+		}
+	}
+
+	fn can_withdraw_fee(
+		who: &AccountId,
+		call: &RuntimeCall,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		fee: Balance,
+		tip: Balance,
+	) -> Result<(), TransactionValidityError> {
+		let payer = sponsor_of(who, call, fee);
+		FungibleAdapter::<F, OU>::can_withdraw_fee(
+			payer.as_ref().unwrap_or(who),
+			call,
+			dispatch_info,
+			fee,
+			tip,
+		)
+	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountId,
+		dispatch_info: &DispatchInfoOf<RuntimeCall>,
+		post_info: &PostDispatchInfoOf<RuntimeCall>,
+		corrected_fee: Balance,
+		tip: Balance,
+		(sponsorship, already_withdrawn): Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		match sponsorship {
+			Some((sponsor, charged)) => {
+				FungibleAdapter::<F, OU>::correct_and_deposit_fee(
+					&sponsor,
+					dispatch_info,
+					post_info,
+					corrected_fee,
+					tip,
+					already_withdrawn,
+				)?;
+				News::settle_sponsorship(who, &sponsor, charged, corrected_fee);
+				Ok(())
+			},
+			None => FungibleAdapter::<F, OU>::correct_and_deposit_fee(
+				who,
+				dispatch_info,
+				post_info,
+				corrected_fee,
+				tip,
+				already_withdrawn,
+			),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn endow_account(who: &AccountId, amount: Balance) {
+		FungibleAdapter::<F, OU>::endow_account(who, amount)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn minimum_balance() -> Balance {
+		FungibleAdapter::<F, OU>::minimum_balance()
+	}
+}
+
+/// Newsroom paying `fee` for `call` signed by `who`; only `News` calls are sponsored.
+fn sponsor_of(who: &AccountId, call: &RuntimeCall, fee: Balance) -> Option<AccountId> {
+	match call {
+		RuntimeCall::News(_) => News::sponsor_for(who, fee),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Balances, RuntimeOrigin, System, MILLI_UNIT, UNIT};
+	use frame_support::{assert_ok, traits::fungible::Mutate};
+	use sp_runtime::BuildStorage;
+
+	type Adapter = SponsoredFungibleAdapter<Balances, ()>;
+	type Liquidity = <Adapter as OnChargeTransaction<Runtime>>::LiquidityInfo;
+
+	fn newsroom() -> AccountId {
+		AccountId::from([1u8; 32])
+	}
+
+	fn journalist() -> AccountId {
+		AccountId::from([2u8; 32])
+	}
+
+	fn news_call() -> RuntimeCall {
+		RuntimeCall::News(pallet_news::Call::set_sponsorship_budget { budget: 0 })
+	}
+
+	/// Funds both accounts with 10 units, and lets the newsroom sponsor the journalist up to
+	/// `budget`.
+	fn new_test_ext(budget: Balance) -> sp_io::TestExternalities {
+		let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			Balances::set_balance(&newsroom(), 10 * UNIT);
+			Balances::set_balance(&journalist(), 10 * UNIT);
+			assert_ok!(News::set_sponsorship_budget(RuntimeOrigin::signed(newsroom()), budget));
+			assert_ok!(News::sponsor_journalist(
+				RuntimeOrigin::signed(newsroom()),
+				journalist(),
+				budget
+			));
+		});
+		ext
+	}
+
+	fn withdraw(call: &RuntimeCall, fee: Balance) -> Liquidity {
+		Adapter::withdraw_fee(&journalist(), call, &Default::default(), fee, 0).unwrap()
+	}
+
+	fn correct(corrected_fee: Balance, withdrawn: Liquidity) {
+		assert_ok!(Adapter::correct_and_deposit_fee(
+			&journalist(),
+			&Default::default(),
+			&Default::default(),
+			corrected_fee,
+			0,
+			withdrawn,
+		));
+	}
+
+	#[test]
+	fn sponsored_fees_are_charged_to_the_sponsor() {
+		new_test_ext(UNIT).execute_with(|| {
+			assert_ok!(Adapter::can_withdraw_fee(
+				&journalist(),
+				&news_call(),
+				&Default::default(),
+				100 * MILLI_UNIT,
+				0
+			));
+			let withdrawn = withdraw(&news_call(), 100 * MILLI_UNIT);
+			assert_eq!(withdrawn.0, Some((newsroom(), 100 * MILLI_UNIT)));
+			assert_eq!(Balances::free_balance(newsroom()), 10 * UNIT - 100 * MILLI_UNIT);
+			assert_eq!(Balances::free_balance(journalist()), 10 * UNIT);
+			assert_eq!(
+				pallet_news::SponsorBudgets::<Runtime>::get(newsroom()),
+				UNIT - 100 * MILLI_UNIT
+			);
+
+			correct(100 * MILLI_UNIT, withdrawn);
+			System::assert_last_event(
+				pallet_news::Event::FeeSponsored {
+					sponsor: newsroom(),
+					journalist: journalist(),
+					fee: 100 * MILLI_UNIT,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn refunds_go_back_to_the_sponsor() {
+		new_test_ext(UNIT).execute_with(|| {
+			let withdrawn = withdraw(&news_call(), 100 * MILLI_UNIT);
+			correct(40 * MILLI_UNIT, withdrawn);
+
+			assert_eq!(Balances::free_balance(newsroom()), 10 * UNIT - 40 * MILLI_UNIT);
+			assert_eq!(Balances::free_balance(journalist()), 10 * UNIT);
+			assert_eq!(
+				pallet_news::SponsorBudgets::<Runtime>::get(newsroom()),
+				UNIT - 40 * MILLI_UNIT
+			);
+			assert_eq!(
+				pallet_news::SponsoredJournalists::<Runtime>::get(journalist()).unwrap().quota,
+				UNIT - 40 * MILLI_UNIT
+			);
+		});
+	}
+
+	#[test]
+	fn signer_pays_once_the_sponsor_budget_runs_out() {
+		new_test_ext(150 * MILLI_UNIT).execute_with(|| {
+			let withdrawn = withdraw(&news_call(), 100 * MILLI_UNIT);
+			correct(100 * MILLI_UNIT, withdrawn);
+			assert_eq!(pallet_news::SponsorBudgets::<Runtime>::get(newsroom()), 50 * MILLI_UNIT);
+
+			// The remaining budget does not cover the next fee
+			let withdrawn = withdraw(&news_call(), 100 * MILLI_UNIT);
+			assert_eq!(withdrawn.0, None);
+			correct(60 * MILLI_UNIT, withdrawn);
+			assert_eq!(Balances::free_balance(newsroom()), 10 * UNIT - 100 * MILLI_UNIT);
+			assert_eq!(Balances::free_balance(journalist()), 10 * UNIT - 60 * MILLI_UNIT);
+			assert_eq!(pallet_news::SponsorBudgets::<Runtime>::get(newsroom()), 50 * MILLI_UNIT);
+
+			// Calls to other pallets are never sponsored
+			let remark =
+				RuntimeCall::System(frame_system::Call::remark { remark: Default::default() });
+			let withdrawn = withdraw(&remark, 10 * MILLI_UNIT);
+			assert_eq!(withdrawn.0, None);
+			assert_eq!(Balances::free_balance(journalist()), 10 * UNIT - 70 * MILLI_UNIT);
+		});
+	}
+}
@@ -23,6 +23,8 @@
 //
 // For more information, please refer to <http://unlicense.org>
 
+mod asset_fee_payment;
+mod fee_sponsorship;
 mod xcm_config;
 
 use polkadot_sdk::{ staging_parachain_info as parachain_info, staging_xcm as xcm, * };
@@ -37,13 +39,19 @@ use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
     traits::{
+        AsEnsureOriginWithArg,
         ConstBool,
         ConstU32,
         ConstU64,
         ConstU8,
+        Contains,
         EitherOfDiverse,
+        Everything,
+        Get,
+        NeverEnsureOrigin,
         TransformOrigin,
         VariantCountOf,
+        tokens::ConversionToAssetBalance,
     },
     weights::{ ConstantMultiplier, Weight },
     PalletId,
@@ -57,9 +65,15 @@ use polkadot_runtime_common::{
     SlowAdjustingFeeUpdate,
 };
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::Perbill;
+use sp_runtime::{
+    traits::Verify,
+    transaction_validity::TransactionPriority,
+    FixedPointNumber,
+    FixedU128,
+    Perbill,
+};
 use sp_version::RuntimeVersion;
-use xcm::latest::prelude::BodyId;
+use xcm::latest::prelude::{ BodyId, Location };
 
 // Local module imports
 use super::{
@@ -72,8 +86,11 @@ use super::{
     BlockNumber,
     CollatorSelection,
     ConsensusHook,
+    ForeignAssets,
     Hash,
+    Licensing,
     MessageQueue,
+    News,
     Nonce,
     PalletInfo,
     ParachainSystem,
@@ -86,16 +103,22 @@ use super::{
     RuntimeTask,
     Session,
     SessionKeys,
+    Signature,
     System,
+    UncheckedExtrinsic,
     WeightToFee,
     XcmpQueue,
     AVERAGE_ON_INITIALIZE_RATIO,
+    DAYS,
     EXISTENTIAL_DEPOSIT,
     HOURS,
     MAXIMUM_BLOCK_WEIGHT,
     MICRO_UNIT,
+    MILLI_UNIT,
     NORMAL_DISPATCH_RATIO,
+    PROVENANCE_DISPATCH_RATIO,
     SLOT_DURATION,
+    UNIT,
     VERSION,
 };
 use xcm_config::{ RelayLocation, XcmOriginToTransactDispatchOrigin };
@@ -210,7 +233,7 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, ()>;
+    type OnChargeTransaction = fee_sponsorship::SponsoredFungibleAdapter<Balances, ()>;
     type WeightToFee = WeightToFee;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
     type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
@@ -218,6 +241,100 @@ impl pallet_transaction_payment::Config for Runtime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const ForeignAssetDeposit: Balance = UNIT / 10;
+    pub const ForeignAssetAccountDeposit: Balance = EXISTENTIAL_DEPOSIT;
+    pub const ForeignAssetsApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
+    pub const ForeignAssetsMetadataDepositBase: Balance = 10 * MILLI_UNIT;
+    pub const ForeignAssetsMetadataDepositPerByte: Balance = 100 * MICRO_UNIT;
+}
+
+/// Assets reserved on other chains, e.g. USDT and USDC on Asset Hub, identified by their
+/// `Location`. Only `ForceOrigin` registers them, through `force_create`, so that no one can
+/// claim the location of an asset they do not control.
+pub type ForeignAssetsInstance = pallet_assets::Instance1;
+impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type AssetId = Location;
+    type AssetIdParameter = Location;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<NeverEnsureOrigin<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ForeignAssetDeposit;
+    type AssetAccountDeposit = ForeignAssetAccountDeposit;
+    type MetadataDepositBase = ForeignAssetsMetadataDepositBase;
+    type MetadataDepositPerByte = ForeignAssetsMetadataDepositPerByte;
+    type ApprovalDeposit = ForeignAssetsApprovalDeposit;
+    type StringLimit = ConstU32<50>;
+    type Holder = ();
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ForeignAssetsBenchmarkHelper;
+}
+
+/// Rates of foreign assets to the native token, set by governance, at which fees can be paid in
+/// them.
+impl pallet_asset_rate::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type CreateOrigin = EnsureRoot<AccountId>;
+    type RemoveOrigin = EnsureRoot<AccountId>;
+    type UpdateOrigin = EnsureRoot<AccountId>;
+    type Currency = Balances;
+    type AssetKind = Location;
+    type WeightInfo = pallet_asset_rate::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ForeignAssetsBenchmarkHelper;
+}
+
+/// Converts native amounts to amounts of a foreign asset at its `AssetRate` rate, rounding up.
+/// Assets without a rate cannot pay fees.
+pub struct ForeignAssetFeeRate;
+impl ConversionToAssetBalance<Balance, Location, Balance> for ForeignAssetFeeRate {
+    type Error = pallet_asset_rate::Error<Runtime>;
+
+    fn to_asset_balance(balance: Balance, asset_id: Location) -> Result<Balance, Self::Error> {
+        let rate = pallet_asset_rate::ConversionRateToNative::<Runtime>
+            ::get(&asset_id)
+            .ok_or(pallet_asset_rate::Error::<Runtime>::UnknownAssetKind)?;
+        FixedU128::checked_from_integer(balance)
+            .and_then(|balance| balance.const_checked_div(rate))
+            .map(|amount| amount.into_inner().div_ceil(FixedU128::DIV))
+            .ok_or(pallet_asset_rate::Error::<Runtime>::Overflow)
+    }
+}
+
+/// Lets signers pay transaction fees in a foreign asset, at its `AssetRate` rate, by naming the
+/// asset in the `ChargeAssetTxPayment` extension.
+impl pallet_asset_conversion_tx_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetId = Location;
+    type OnChargeAssetTransaction = asset_fee_payment::ForeignAssetFeeAdapter;
+    type WeightInfo = pallet_asset_conversion_tx_payment::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = asset_fee_payment::AssetTxPaymentBenchmarkHelper;
+}
+
+/// Foreign asset locations for benchmarks: sibling parachain `id`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ForeignAssetsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_assets::BenchmarkHelper<Location> for ForeignAssetsBenchmarkHelper {
+    fn create_asset_id_parameter(id: u32) -> Location {
+        Location::new(1, [xcm::latest::prelude::Parachain(id)])
+    }
+}
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_asset_rate::AssetKindFactory<Location> for ForeignAssetsBenchmarkHelper {
+    fn create_asset_kind(seed: u32) -> Location {
+        Location::new(1, [xcm::latest::prelude::Parachain(seed)])
+    }
+}
+
 impl pallet_sudo::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
@@ -354,13 +471,124 @@ impl pallet_parachain_template::Config for Runtime {
     type WeightInfo = pallet_parachain_template::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+    pub const EndorsementDeposit: Balance = 10 * MILLI_UNIT;
+    /// A publisher's reputation halves every 30 days.
+    pub const ReputationRetention: Perbill = Perbill::from_percent(50);
+    pub const ReputationDecayPeriod: BlockNumber = 30 * DAYS;
+    pub const DraftDeposit: Balance = 10 * MILLI_UNIT;
+    pub const DraftLifetime: BlockNumber = 90 * DAYS;
+    pub const IntegrityCheckInterval: BlockNumber = HOURS;
+    pub const IntegrityReportPriority: TransactionPriority = TransactionPriority::MAX / 2;
+    pub const FeelessEraLength: BlockNumber = DAYS;
+    /// Feeless anchors never take precedence over fee-paying transactions.
+    pub const FeelessAnchorPriority: TransactionPriority = 0;
+    /// `Normal` block space left to anchoring by `pallet_news::CheckProvenanceSpace`.
+    pub ProvenanceReservedWeight: Weight = PROVENANCE_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT;
+    /// EIP-712 `chainId` MetaMask and other wallets sign news articles for.
+    pub const NewsEthereumChainId: u64 = 420_420_420;
+}
+
+/// Collators of the current session; they report news integrity checks from their off-chain
+/// workers once their account key is inserted into the keystore under `pallet_news::crypto::KEY_TYPE`.
+pub struct SessionCollators;
+impl Contains<AccountId> for SessionCollators {
+    fn contains(who: &AccountId) -> bool {
+        pallet_session::Validators::<Runtime>::get().contains(who)
+    }
+}
+
+/// Index of the current session, which resets `pallet_news` free anchor quotas.
+pub struct CurrentSessionIndex;
+impl Get<u32> for CurrentSessionIndex {
+    fn get() -> u32 {
+        pallet_session::CurrentIndex::<Runtime>::get()
+    }
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Runtime where RuntimeCall: From<C> {
+    type Extrinsic = UncheckedExtrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<C> frame_system::offchain::CreateBare<C> for Runtime where RuntimeCall: From<C> {
+    fn create_bare(call: RuntimeCall) -> UncheckedExtrinsic {
+        UncheckedExtrinsic::new_bare(call)
+    }
+}
+
 impl pallet_news::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type Assets = ForeignAssets;
+    type EndorsementDeposit = EndorsementDeposit;
+    // No proof-of-personhood provider yet; the deposit alone provides Sybil resistance.
+    type PersonhoodVerifier = Everything;
+    type FactCheckOrigin = EnsureRoot<AccountId>;
+    type PurgeOrigin = EnsureRoot<AccountId>;
+    type ReputationRetention = ReputationRetention;
+    type ReputationDecayPeriod = ReputationDecayPeriod;
+    type DraftDeposit = DraftDeposit;
+    type DraftLifetime = DraftLifetime;
+    type IntegrityCheckInterval = IntegrityCheckInterval;
+    type MaxIntegrityChecksPerRun = ConstU32<20>;
+    type IntegrityReportPriority = IntegrityReportPriority;
+    type IntegrityAuthorityId = pallet_news::crypto::IntegrityReporterId;
+    type IntegrityReporters = SessionCollators;
+    type IntegrityWatcherOrigin = EnsureRoot<AccountId>;
+    type FeelessApprovalOrigin = EnsureRoot<AccountId>;
+    type FeelessEraLength = FeelessEraLength;
+    type MaxFeelessAnchorsPerEra = ConstU32<20>;
+    type FeelessAnchorPriority = FeelessAnchorPriority;
+    type ProvenanceReservedWeight = ProvenanceReservedWeight;
+    type FreeAnchorsPerSession = ConstU32<10>;
+    type CurrentSession = CurrentSessionIndex;
+    type Signature = Signature;
+    type Signer = <Signature as Verify>::Signer;
+    type EthereumChainId = NewsEthereumChainId;
+    // Legacy signatures cover only the content hash, so their metadata can be tampered with.
+    type AllowLegacySignatures = ConstBool<false>;
+    type MaxBatchProofLen = ConstU32<32>;
+    type MaxRemovalSteps = ConstU32<100>;
+    type MaxChangeSummaryLen = ConstU32<256>;
+    type MaxCoAuthors = ConstU32<8>;
     type WeightInfo = ();
     type MaxArticlesPerPublisher = ConstU32<50>;
     type MaxHistoryLen = ConstU32<50>;
     type MaxTitleLen = ConstU32<128>;
     type MaxUrlLen = ConstU32<128>;
+    type MaxSyndications = ConstU32<50>;
+    type MaxReleasesPerBlock = ConstU32<50>;
+    type MaxCitations = ConstU32<32>;
+    type SyndicationPolicy = Licensing;
+    type OnArticleRemoved = Licensing;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = ();
 }
+
+impl pallet_licensing::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Assets = ForeignAssets;
+    type Articles = News;
+    type MaxOffersPerAnchor = ConstU32<16>;
+    type MaxTerritories = ConstU32<32>;
+    type WeightInfo = ();
+}
+
+impl pallet_subscriptions::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
+    type Articles = News;
+    type Signature = Signature;
+    type Signer = <Signature as Verify>::Signer;
+    type MaxPlansPerPublisher = ConstU32<16>;
+    type WeightInfo = ();
+}
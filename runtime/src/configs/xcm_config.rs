@@ -1,6 +1,8 @@
+use super::{ForeignAssetFeeRate, ForeignAssetsInstance};
 use crate::{
-	AccountId, AllPalletsWithSystem, Balances, ParachainInfo, ParachainSystem, PolkadotXcm,
-	Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
+	AccountId, AllPalletsWithSystem, Balance, Balances, ForeignAssets, ParachainInfo,
+	ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee,
+	XcmpQueue,
 };
 
 use polkadot_sdk::{
@@ -9,19 +11,21 @@ use polkadot_sdk::{
 
 use frame_support::{
 	parameter_types,
-	traits::{ConstU32, Contains, Everything, Nothing, ContainsPair},
+	traits::{ConstU32, Contains, Equals, Everything, EverythingBut, Nothing, ContainsPair},
 	weights::Weight,
 };
 use frame_system::EnsureRoot;
 use pallet_xcm::XcmPassthrough;
+use parachains_common::impls::{AssetFeeAsExistentialDepositMultiplier, AssetsToBlockAuthor};
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::impls::ToAuthor;
+use sp_runtime::traits::{Identity, JustTry};
 use xcm::latest::prelude::*;
 use xcm_builder::{
 	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom,
 	DenyReserveTransferToRelayChain, DenyThenTry, EnsureXcmOrigin, FixedWeightBounds,
-	FrameTransactionalProcessor, ParentIsPreset,
-	RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+	FrameTransactionalProcessor, FungiblesAdapter, MatchedConvertedConcreteId, NoChecking,
+	ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
 	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
 	TrailingSetTopicAsId, UsingComponents, WithComputedOrigin, WithUniqueTopic,
 };
@@ -121,8 +125,33 @@ mod asset_transactor {
 		()
 	>;
 
+	parameter_types! {
+		pub CheckingAccount: AccountId = PolkadotXcm::check_account();
+	}
+
+	/// Matches assets registered in `ForeignAssets`, which are keyed by their `Location`, and
+	/// converts their amounts as-is.
+	pub type ForeignAssetsConvertedConcreteId = MatchedConvertedConcreteId<
+		Location,
+		Balance,
+		EverythingBut<Equals<ParentRelayLocation>>,
+		Identity,
+		JustTry,
+	>;
+
+	/// AssetTransactor for handling foreign assets, e.g. USDT and USDC from Asset Hub.
+	pub type FungiblesTransactor = FungiblesAdapter<
+		ForeignAssets,
+		ForeignAssetsConvertedConcreteId,
+		super::LocationToAccountId,
+		AccountId,
+		// Foreign assets are reserve-transferred, never teleported, so there is nothing to check.
+		NoChecking,
+		CheckingAccount,
+	>;
+
 	/// Actual configuration item that'll be set in the XCM config.
-	pub type AssetTransactor = FungibleTransactor;
+	pub type AssetTransactor = (FungibleTransactor, FungiblesTransactor);
 }
 
 /// Configuration related to token reserves
@@ -157,8 +186,32 @@ mod is_reserve {
 		}
 	}
 
+	/// Implementation of `ContainsPair<Asset, Location>` that will allow assets of the
+	/// Assets pallet on Asset Hub, such as USDT (1984) and USDC (1337), from Asset Hub.
+	pub struct ForeignAssetsFromAssetHub;
+	impl ContainsPair<Asset, Location> for ForeignAssetsFromAssetHub {
+		fn contains(asset: &Asset, location: &Location) -> bool {
+			let is_asset_hub_asset = match asset {
+				Asset {
+					id: AssetId(asset_id),
+					fun: Fungible(_),
+				} => {
+					// The Assets pallet is at index 50 on Asset Hub, and assets are identified
+					// by their index in it.
+					matches!(
+						asset_id.unpack(),
+						(1, [Parachain(1000), PalletInstance(50), GeneralIndex(_)])
+					)
+				},
+				_ => false,
+			};
+			let is_from_asset_hub = matches!(location.unpack(), (1, [Parachain(1000)]));
+			is_asset_hub_asset && is_from_asset_hub
+		}
+	}
+
 	/// All locations we trust as reserves for particular assets.
-	pub type IsReserve = PasFromAssetHub;
+	pub type IsReserve = (PasFromAssetHub, ForeignAssetsFromAssetHub);
 }
 
 pub struct XcmConfig;
@@ -173,8 +226,23 @@ impl xcm_executor::Config for XcmConfig {
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barrier;
 	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
-	type Trader =
-		UsingComponents<WeightToFee, RelayLocation, AccountId, Balances, ToAuthor<Runtime>>;
+	type Trader = (
+		UsingComponents<WeightToFee, RelayLocation, AccountId, Balances, ToAuthor<Runtime>>,
+		// Execution paid in foreign assets. Despite its name, the fee charger converts the fee at
+		// the rate governance set in `AssetRate`, so assets without a rate cannot pay.
+		cumulus_primitives_utility::TakeFirstAssetTrader<
+			AccountId,
+			AssetFeeAsExistentialDepositMultiplier<
+				Runtime,
+				WeightToFee,
+				ForeignAssetFeeRate,
+				ForeignAssetsInstance,
+			>,
+			asset_transactor::ForeignAssetsConvertedConcreteId,
+			ForeignAssets,
+			AssetsToBlockAuthor<Runtime, ForeignAssetsInstance>,
+		>,
+	);
 	type ResponseHandler = PolkadotXcm;
 	type AssetTrap = PolkadotXcm;
 	type AssetClaims = PolkadotXcm;
@@ -84,8 +84,9 @@ pub type TxExtension = cumulus_pallet_weight_reclaim::StorageWeightReclaim<
 		frame_system::CheckGenesis<Runtime>,
 		frame_system::CheckEra<Runtime>,
 		frame_system::CheckNonce<Runtime>,
+		pallet_news::CheckProvenanceSpace<Runtime>,
 		frame_system::CheckWeight<Runtime>,
-		pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+		pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
 		frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	),
 >;
@@ -98,7 +99,7 @@ pub type UncheckedExtrinsic =
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (pallet_news::migrations::v1::MigrateV0ToV1<Runtime>,);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -170,10 +171,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: alloc::borrow::Cow::Borrowed("educhain-runtime"),
 	impl_name: alloc::borrow::Cow::Borrowed("educhain-runtime"),
 	authoring_version: 1,
-	spec_version: 5,
+	spec_version: 7,
 	impl_version: 0,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	system_version: 1,
 };
 
@@ -214,6 +215,10 @@ const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(5);
 /// `Operational` extrinsics.
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
 
+/// Share of the block weight, taken from the `Normal` part, that only news anchoring may use:
+/// `pallet_news::CheckProvenanceSpace` keeps other `Normal` extrinsics out of it.
+const PROVENANCE_DISPATCH_RATIO: Perbill = Perbill::from_percent(20);
+
 #[docify::export(max_block_weight)]
 /// We allow for 2 seconds of compute with a 6 second average block time.
 const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
@@ -283,6 +288,12 @@ mod runtime {
 	pub type Balances = pallet_balances;
 	#[runtime::pallet_index(11)]
 	pub type TransactionPayment = pallet_transaction_payment;
+	#[runtime::pallet_index(12)]
+	pub type ForeignAssets = pallet_assets<Instance1>;
+	#[runtime::pallet_index(13)]
+	pub type AssetRate = pallet_asset_rate;
+	#[runtime::pallet_index(14)]
+	pub type AssetTxPayment = pallet_asset_conversion_tx_payment;
 
 	// Governance
 	#[runtime::pallet_index(15)]
@@ -315,6 +326,10 @@ mod runtime {
 	pub type TemplatePallet = pallet_parachain_template;
 	#[runtime::pallet_index(51)]
 	pub type News = pallet_news;
+	#[runtime::pallet_index(52)]
+	pub type Licensing = pallet_licensing;
+	#[runtime::pallet_index(53)]
+	pub type Subscriptions = pallet_subscriptions;
 
 }
 